@@ -0,0 +1,87 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+use between::Between;
+
+/// Appends keys to the end of a list, one after the other.
+fn append_only(c: &mut Criterion) {
+    let between = Between::init();
+    c.bench_function("append_only/1000", |b| {
+        b.iter(|| {
+            let mut last = String::new();
+            for _ in 0..1000 {
+                last = between.after(&last).unwrap();
+            }
+            black_box(last)
+        })
+    });
+
+    let mut buffer = String::new();
+    c.bench_function("append_only_into/1000", |b| {
+        b.iter(|| {
+            let mut last = String::new();
+            for _ in 0..1000 {
+                buffer.clear();
+                between.after_into(&last, &mut buffer);
+                std::mem::swap(&mut last, &mut buffer);
+            }
+            black_box(last)
+        })
+    });
+}
+
+/// Inserts keys repeatedly at the same position, so keys grow into the same gap.
+fn hotspot_insert(c: &mut Criterion) {
+    for (name, between) in [
+        ("ascii", Between::init()),
+        ("greek", Between::greek()),
+        ("cjk", Between::cjk()),
+    ] {
+        let first = between.after("").unwrap();
+        let next = between.after(&first).unwrap();
+        c.bench_function(&format!("hotspot_insert/{}/200", name), |b| {
+            b.iter(|| {
+                let mut prev = first.clone();
+                for _ in 0..200 {
+                    prev = between.between(&prev, &next).unwrap();
+                }
+                black_box(prev)
+            })
+        });
+    }
+}
+
+/// Replaces every key of a large list with fresh, evenly spaced ones.
+fn rebalance(c: &mut Criterion) {
+    let between = Between::init();
+    let existing: Vec<String> = between.after_iter("").take(10_000).collect();
+    c.bench_function("rebalance/10000", |b| {
+        b.iter(|| black_box(between.rebalance(&existing).unwrap()))
+    });
+    c.bench_function("compact/10000", |b| {
+        b.iter_batched(
+            || existing.clone(),
+            |keys| black_box(between.compact(&keys)),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+/// Bisects keys hundreds of characters long, as found after heavy churn.
+fn long_key(c: &mut Criterion) {
+    for (name, between) in [("ascii", Between::init()), ("greek", Between::greek())] {
+        // Two keys sharing a 600 character prefix, one character apart.
+        let chars = between.chars();
+        let prefix: String = chars[chars.len() / 2].to_string().repeat(600);
+        let low = prefix.clone() + &chars[1].to_string();
+        let high = prefix + &chars[2].to_string();
+        c.bench_function(&format!("long_key/{}/600", name), |b| {
+            b.iter(|| black_box(between.between(&low, &high)))
+        });
+        c.bench_function(&format!("valid/{}/600", name), |b| {
+            b.iter(|| black_box(between.valid(&high)))
+        });
+    }
+}
+
+criterion_group!(benches, append_only, hotspot_insert, rebalance, long_key);
+criterion_main!(benches);
@@ -0,0 +1,308 @@
+//! Native Node.js bindings for `between`, for teams that prefer a native addon over WASM.
+//!
+//! Build the addon with `npm run build`, which also generates `index.js` and `index.d.ts`:
+//!
+//! ```js
+//! const { Keyspace, OrderedKeyList } = require('@between/node')
+//!
+//! const keyspace = Keyspace.fromProfile('preset=base62&max_len=12')
+//! const key = keyspace.between('A', 'B')
+//!
+//! const list = new OrderedKeyList(keyspace)
+//! list.pushBack('todo-1')
+//! list.pushFront('todo-2')
+//! for (const index of list.takeChanged()) { /* persist list.keyAt(index) */ }
+//! ```
+
+use between::{Between, KeyspaceProfile};
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+
+/// A replacement key for the entry of a list at `index`.
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    pub index: u32,
+    pub key: String,
+}
+
+/// A `Between` instance exposed to JavaScript.
+#[napi]
+#[derive(Debug, Clone)]
+pub struct Keyspace {
+    between: Between,
+}
+
+#[napi]
+impl Keyspace {
+    /// Creates a keyspace over the given characters, in any order.
+    #[napi(constructor)]
+    pub fn new(chars: String) -> Result<Self> {
+        let mut chars: Vec<char> = chars.chars().collect();
+        chars.sort_unstable();
+        chars.dedup();
+        if chars.len() < 2 {
+            return Err(invalid_arg(
+                "expect chars to have at least two distinct characters",
+            ));
+        }
+        Ok(Keyspace {
+            between: Between::new(chars),
+        })
+    }
+
+    /// Creates a keyspace described by a [`KeyspaceProfile`] string.
+    #[napi(factory)]
+    pub fn from_profile(profile: String) -> Result<Self> {
+        let between = KeyspaceProfile::parse(&profile)
+            .and_then(|profile| profile.between())
+            .map_err(invalid_arg)?;
+        Ok(Keyspace { between })
+    }
+
+    /// Returns the profile string describing this keyspace.
+    #[napi]
+    pub fn profile(&self) -> String {
+        // Keyspaces are only built from built-in strategies, which all have names.
+        KeyspaceProfile::of(&self.between).unwrap().to_string()
+    }
+
+    /// Returns the characters of the alphabet, in order.
+    #[napi]
+    pub fn chars(&self) -> String {
+        self.between.chars().iter().collect()
+    }
+
+    /// Returns whether `key` is a valid key. See [`Between::valid`].
+    #[napi]
+    pub fn valid(&self, key: String) -> bool {
+        self.between.valid(key)
+    }
+
+    /// Finds a key between `a` and `b`. See [`Between::between`].
+    #[napi]
+    pub fn between(&self, a: String, b: String) -> Option<String> {
+        self.between.between(a, b)
+    }
+
+    /// Finds a key after `a`. See [`Between::after`].
+    #[napi]
+    pub fn after(&self, a: String) -> Option<String> {
+        self.between.after(a)
+    }
+
+    /// Finds a key before `b`. See [`Between::before`].
+    #[napi]
+    pub fn before(&self, b: String) -> Option<String> {
+        self.between.before(b)
+    }
+
+    /// Finds `n` evenly spaced keys between `a` and `b`. See [`Between::n_between`].
+    #[napi]
+    pub fn n_between(&self, a: String, b: String, n: u32) -> Option<Vec<String>> {
+        self.between.n_between(a, b, n as usize)
+    }
+
+    /// Generates `n` evenly spaced keys. See [`Between::spread`].
+    #[napi]
+    pub fn spread(&self, n: u32) -> Vec<String> {
+        self.between.spread(n as usize)
+    }
+
+    /// Generates fresh keys to replace every key of a list. See [`Between::rebalance`].
+    #[napi]
+    pub fn rebalance(&self, existing: Vec<String>) -> Option<Vec<String>> {
+        self.between.rebalance(&existing)
+    }
+
+    /// Shortens the keys of a sorted list where possible. See [`Between::compact`].
+    #[napi]
+    pub fn compact(&self, keys: Vec<String>) -> Option<Vec<Replacement>> {
+        self.between.compact(&keys).map(replacements)
+    }
+
+    /// Plans the fewest rewrites that bring every key of a sorted list within `max_len`. See
+    /// [`Between::plan_rebalance`].
+    #[napi]
+    pub fn plan_rebalance(&self, keys: Vec<String>, max_len: u32) -> Option<Vec<Replacement>> {
+        self.between
+            .plan_rebalance(&keys, max_len as usize)
+            .map(replacements)
+    }
+}
+
+/// An [`between::OrderedKeyList`] of item ids exposed to JavaScript.
+///
+/// The list holds ids rather than JavaScript objects, so it can live on the native side; callers keep their
+/// items in a map from id.
+#[napi]
+#[derive(Debug, Clone)]
+pub struct OrderedKeyList {
+    list: between::OrderedKeyList<String>,
+}
+
+#[napi]
+impl OrderedKeyList {
+    /// Creates an empty list generating keys with the given keyspace.
+    #[napi(constructor)]
+    pub fn new(keyspace: &Keyspace) -> Self {
+        OrderedKeyList {
+            list: between::OrderedKeyList::new(keyspace.between.clone()),
+        }
+    }
+
+    /// Returns the number of ids in the list.
+    #[napi(getter)]
+    pub fn length(&self) -> u32 {
+        self.list.len() as u32
+    }
+
+    /// Returns the key at `index`, if any.
+    #[napi]
+    pub fn key_at(&self, index: u32) -> Option<String> {
+        self.list
+            .get(index as usize)
+            .map(|(key, _)| key.to_string())
+    }
+
+    /// Returns the id at `index`, if any.
+    #[napi]
+    pub fn id_at(&self, index: u32) -> Option<String> {
+        self.list.get(index as usize).map(|(_, id)| id.clone())
+    }
+
+    /// Returns every key, in order.
+    #[napi]
+    pub fn keys(&self) -> Vec<String> {
+        self.list.iter().map(|(key, _)| key.to_string()).collect()
+    }
+
+    /// Returns every id, in order.
+    #[napi]
+    pub fn ids(&self) -> Vec<String> {
+        self.list.iter().map(|(_, id)| id.clone()).collect()
+    }
+
+    /// Appends an id to the end of the list, returning its key.
+    #[napi]
+    pub fn push_back(&mut self, id: String) -> Result<String> {
+        self.insert_at(self.list.len() as u32, id)
+    }
+
+    /// Inserts an id at the front of the list, returning its key.
+    #[napi]
+    pub fn push_front(&mut self, id: String) -> Result<String> {
+        self.insert_at(0, id)
+    }
+
+    /// Inserts an id so that it ends up at `index`, returning its key.
+    #[napi]
+    pub fn insert_at(&mut self, index: u32, id: String) -> Result<String> {
+        self.check_index(index, self.list.len() + 1)?;
+        self.list
+            .insert_at(index as usize, id)
+            .map(|key| key.to_string())
+            .map_err(generic_failure)
+    }
+
+    /// Moves the id at `from` so that it ends up at `to`, returning its new key.
+    #[napi]
+    pub fn move_item(&mut self, from: u32, to: u32) -> Result<String> {
+        self.check_index(from, self.list.len())?;
+        self.check_index(to, self.list.len())?;
+        self.list
+            .move_item(from as usize, to as usize)
+            .map(|key| key.to_string())
+            .map_err(generic_failure)
+    }
+
+    /// Removes the id at `index` and returns it.
+    #[napi]
+    pub fn remove(&mut self, index: u32) -> Result<String> {
+        self.check_index(index, self.list.len())?;
+        Ok(self.list.remove(index as usize))
+    }
+
+    /// Replaces every key with a fresh, evenly spaced one.
+    #[napi]
+    pub fn rebalance(&mut self) {
+        self.list.rebalance();
+    }
+
+    /// Returns the indices whose keys were created or changed since the last call, and forgets them.
+    #[napi]
+    pub fn take_changed(&mut self) -> Vec<u32> {
+        self.list
+            .take_changed()
+            .into_iter()
+            .map(|index| index as u32)
+            .collect()
+    }
+
+    /// Throws instead of letting the list panic on an out-of-bounds index, which would abort the process.
+    fn check_index(&self, index: u32, len: usize) -> Result<()> {
+        if (index as usize) < len {
+            Ok(())
+        } else {
+            Err(invalid_arg(format!("index {} out of bounds", index)))
+        }
+    }
+}
+
+fn replacements(replacements: Vec<(usize, String)>) -> Vec<Replacement> {
+    replacements
+        .into_iter()
+        .map(|(index, key)| Replacement {
+            index: index as u32,
+            key,
+        })
+        .collect()
+}
+
+fn invalid_arg(reason: impl ToString) -> Error {
+    Error::new(Status::InvalidArg, reason.to_string())
+}
+
+fn generic_failure(reason: impl ToString) -> Error {
+    Error::new(Status::GenericFailure, reason.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Keyspace, OrderedKeyList, Replacement};
+
+    #[test]
+    fn test_keyspace() {
+        let keyspace = Keyspace::new("0123456789".to_string()).unwrap();
+        assert_eq!(
+            keyspace.between("1".to_string(), "3".to_string()),
+            Some("2".to_string())
+        );
+        assert!(Keyspace::new("aa".to_string()).is_err());
+        assert!(Keyspace::from_profile("preset=nope".to_string()).is_err());
+        assert_eq!(
+            keyspace.compact(vec!["1".to_string(), "15".to_string()]),
+            Some(vec![Replacement {
+                index: 1,
+                key: "5".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn test_ordered_key_list() {
+        let keyspace = Keyspace::from_profile("preset=base10".to_string()).unwrap();
+        let mut list = OrderedKeyList::new(&keyspace);
+        list.push_back("b".to_string()).unwrap();
+        list.push_front("a".to_string()).unwrap();
+        list.push_back("c".to_string()).unwrap();
+        list.move_item(2, 0).unwrap();
+        assert_eq!(list.ids(), vec!["c", "a", "b"]);
+        assert!(list.keys().windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(list.take_changed(), vec![0, 1, 2]);
+        assert!(list.move_item(0, 3).is_err());
+        assert!(list.remove(3).is_err());
+        assert_eq!(list.remove(0).unwrap(), "c");
+        assert_eq!(list.length(), 2);
+    }
+}
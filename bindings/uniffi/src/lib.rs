@@ -0,0 +1,147 @@
+//! Swift and Kotlin bindings for `between`, so mobile apps can generate ordering keys offline with the same
+//! implementation as their servers.
+//!
+//! The interface is defined in `src/between.udl`. Build the `cdylib` for each target, then generate the
+//! bindings from it:
+//!
+//! ```sh
+//! cargo run --bin uniffi-bindgen generate --library target/release/libbetween_uniffi.so \
+//!     --language swift --out-dir out
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use between::{Between, KeyspaceProfile};
+
+uniffi::include_scaffolding!("between");
+
+/// An error returned when a keyspace cannot be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyspaceError {
+    /// The alphabet has fewer than two distinct characters.
+    InvalidAlphabet,
+    /// The profile string cannot be parsed, or names an unknown preset or strategy.
+    InvalidProfile,
+}
+
+impl fmt::Display for KeyspaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyspaceError::InvalidAlphabet => {
+                f.write_str("expect chars to have at least two distinct characters")
+            }
+            KeyspaceError::InvalidProfile => f.write_str("invalid keyspace profile"),
+        }
+    }
+}
+
+impl Error for KeyspaceError {}
+
+/// A `Between` instance exposed to foreign languages.
+#[derive(Debug)]
+pub struct Keyspace {
+    between: Between,
+}
+
+impl Keyspace {
+    /// Creates a keyspace over the given characters, in any order.
+    pub fn new(chars: String) -> Result<Self, KeyspaceError> {
+        let mut chars: Vec<char> = chars.chars().collect();
+        chars.sort_unstable();
+        chars.dedup();
+        if chars.len() < 2 {
+            return Err(KeyspaceError::InvalidAlphabet);
+        }
+        Ok(Keyspace {
+            between: Between::new(chars),
+        })
+    }
+
+    /// Creates a keyspace described by a [`KeyspaceProfile`] string.
+    pub fn from_profile(profile: String) -> Result<Self, KeyspaceError> {
+        let between = KeyspaceProfile::parse(&profile)
+            .and_then(|profile| profile.between())
+            .map_err(|_| KeyspaceError::InvalidProfile)?;
+        Ok(Keyspace { between })
+    }
+
+    /// Returns the profile string describing this keyspace.
+    pub fn profile(&self) -> String {
+        // Keyspaces are only built from built-in strategies, which all have names.
+        KeyspaceProfile::of(&self.between).unwrap().to_string()
+    }
+
+    /// Returns the characters of the alphabet, in order.
+    pub fn chars(&self) -> String {
+        self.between.chars().iter().collect()
+    }
+
+    /// Returns whether `key` is a valid key. See [`Between::valid`].
+    pub fn valid(&self, key: String) -> bool {
+        self.between.valid(key)
+    }
+
+    /// Finds a key between `a` and `b`. See [`Between::between`].
+    pub fn between(&self, a: String, b: String) -> Option<String> {
+        self.between.between(a, b)
+    }
+
+    /// Finds a key after `a`. See [`Between::after`].
+    pub fn after(&self, a: String) -> Option<String> {
+        self.between.after(a)
+    }
+
+    /// Finds a key before `b`. See [`Between::before`].
+    pub fn before(&self, b: String) -> Option<String> {
+        self.between.before(b)
+    }
+
+    /// Finds `n` evenly spaced keys between `a` and `b`. See [`Between::n_between`].
+    pub fn n_between(&self, a: String, b: String, n: u32) -> Option<Vec<String>> {
+        self.between.n_between(a, b, n as usize)
+    }
+
+    /// Generates `n` evenly spaced keys. See [`Between::spread`].
+    pub fn spread(&self, n: u32) -> Vec<String> {
+        self.between.spread(n as usize)
+    }
+
+    /// Generates fresh keys to replace every key of a list. See [`Between::rebalance`].
+    pub fn rebalance(&self, existing: Vec<String>) -> Option<Vec<String>> {
+        self.between.rebalance(&existing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Keyspace, KeyspaceError};
+
+    #[test]
+    fn test_keyspace() {
+        let keyspace = Keyspace::new("0123456789".to_string()).unwrap();
+        assert_eq!(
+            keyspace.between("1".to_string(), "3".to_string()),
+            Some("2".to_string())
+        );
+        assert!(keyspace.between("3".to_string(), "1".to_string()).is_none());
+        assert!(keyspace.valid("42".to_string()));
+        assert_eq!(keyspace.spread(3).len(), 3);
+        assert_eq!(
+            Keyspace::new("aa".to_string()).unwrap_err(),
+            KeyspaceError::InvalidAlphabet
+        );
+    }
+
+    #[test]
+    fn test_keyspace_profile() {
+        let keyspace = Keyspace::from_profile("preset=base10&max_len=4".to_string()).unwrap();
+        assert_eq!(keyspace.chars(), "0123456789");
+        let same = Keyspace::from_profile(keyspace.profile()).unwrap();
+        assert_eq!(same.after("9".to_string()), keyspace.after("9".to_string()));
+        assert_eq!(
+            Keyspace::from_profile("preset=nope".to_string()).unwrap_err(),
+            KeyspaceError::InvalidProfile
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use between::Between;
+
+use crate::Keys;
+
+/// Arguments of the `generate` command.
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// The number of keys to emit.
+    #[arg(long)]
+    count: usize,
+
+    /// Emit keys after this one. Defaults to the start of the keyspace.
+    #[arg(long)]
+    after: Option<String>,
+
+    /// Emit keys before this one. Defaults to the end of the keyspace.
+    #[arg(long)]
+    before: Option<String>,
+}
+
+/// Generates `args.count` evenly spaced keys between the bounds, in ascending order.
+pub fn run(between: &Between, args: &Args) -> Result<Keys, String> {
+    let after = args.after.as_deref().unwrap_or("");
+    let before = match &args.before {
+        Some(before) => before.clone(),
+        None => between.high().to_string(),
+    };
+    let keys = between
+        .n_between(after, &before, args.count)
+        .ok_or_else(|| format!("no keys between {:?} and {:?}", after, before))?;
+    Ok(Keys { keys })
+}
+
+#[cfg(test)]
+mod tests {
+    use between::Between;
+
+    use crate::generate::{run, Args};
+
+    fn generate(
+        between: &Between,
+        count: usize,
+        after: Option<&str>,
+        before: Option<&str>,
+    ) -> String {
+        let args = Args {
+            count,
+            after: after.map(String::from),
+            before: before.map(String::from),
+        };
+        run(between, &args).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_generate() {
+        let between = Between::base10();
+        assert_eq!(generate(&between, 3, None, None), "2\n4\n6\n");
+        let keys = between.n_between("2", "3", 2).unwrap();
+        assert_eq!(
+            generate(&between, 2, Some("2"), Some("3")),
+            format!("{}\n{}\n", keys[0], keys[1])
+        );
+        assert_eq!(generate(&between, 0, None, None), "");
+
+        let keys = generate(&between, 100, Some("5"), None);
+        let keys: Vec<&str> = keys.lines().collect();
+        assert_eq!(keys.len(), 100);
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys[0] > "5");
+    }
+
+    #[test]
+    fn test_generate_invalid_bounds() {
+        let args = Args {
+            count: 1,
+            after: Some("3".to_string()),
+            before: Some("2".to_string()),
+        };
+        assert!(run(&Between::base10(), &args).is_err());
+    }
+}
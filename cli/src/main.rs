@@ -0,0 +1,147 @@
+//! The `between` command line tool, for generating and maintaining keys from shell pipelines.
+//!
+//! Every command works in the keyspace given by the global `--profile` option, a [`KeyspaceProfile`] string
+//! that defaults to the alphabet of `Between::init`. Commands build a [`Report`], written as lines of text or,
+//! with the global `--json` option, as a JSON object.
+
+use std::fmt;
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use between::KeyspaceProfile;
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+
+mod generate;
+mod rebalance;
+mod validate;
+
+/// Generate and maintain lexicographically sortable keys.
+#[derive(Debug, Parser)]
+#[command(name = "between", version)]
+struct Cli {
+    /// The keyspace, as a profile string such as `preset=base62&max_len=12`.
+    #[arg(long, global = true, default_value = "preset=default")]
+    profile: String,
+
+    /// Write results to stdout and errors to stderr as JSON objects, one per line.
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Emit evenly spaced keys, one per line.
+    Generate(generate::Args),
+    /// Read a sorted key list from stdin and emit replacement keys.
+    Rebalance(rebalance::Args),
+    /// Check a key list from stdin, reporting problems with their line numbers.
+    Validate(validate::Args),
+}
+
+/// The result of a command.
+///
+/// `Display` renders the text output, one item per line; `Serialize` renders the `--json` output.
+pub trait Report: fmt::Display + Serialize {
+    /// Returns why the command failed despite producing a report, if it did.
+    fn failure(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A list of keys, in ascending order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Keys {
+    pub keys: Vec<String>,
+}
+
+impl fmt::Display for Keys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.keys.iter().try_for_each(|key| writeln!(f, "{}", key))
+    }
+}
+
+impl Report for Keys {}
+
+/// The `--json` form of an error.
+#[derive(Debug, Serialize)]
+struct Error<'a> {
+    error: &'a str,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let json = cli.json;
+    let result = run(cli).and_then(|failure| match failure {
+        Some(failure) => Err(failure),
+        None => Ok(()),
+    });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            if json {
+                let error = serde_json::to_string(&Error { error: &error }).unwrap();
+                eprintln!("{}", error);
+            } else {
+                eprintln!("between: {}", error);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs a command and writes its report, returning why it failed if it did.
+fn run(cli: Cli) -> Result<Option<String>, String> {
+    let between = KeyspaceProfile::parse(&cli.profile)
+        .and_then(|profile| profile.between())
+        .map_err(|error| format!("invalid profile: {}", error))?;
+    let mut out = io::stdout().lock();
+    match cli.command {
+        Command::Generate(args) => emit(generate::run(&between, &args)?, cli.json, &mut out),
+        Command::Rebalance(args) => emit(
+            rebalance::run(&between, &args, io::stdin().lock())?,
+            cli.json,
+            &mut out,
+        ),
+        Command::Validate(args) => emit(
+            validate::run(&between, &args, io::stdin().lock())?,
+            cli.json,
+            &mut out,
+        ),
+    }
+}
+
+/// Writes a report as text, or as one line of JSON.
+fn emit(report: impl Report, json: bool, out: &mut impl Write) -> Result<Option<String>, String> {
+    let written = if json {
+        serde_json::to_writer(&mut *out, &report)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(out))
+    } else {
+        write!(out, "{}", report)
+    };
+    written.map_err(|error| error.to_string())?;
+    Ok(report.failure())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{emit, Keys};
+
+    #[test]
+    fn test_emit() {
+        let keys = Keys {
+            keys: vec!["a".to_string(), "b".to_string()],
+        };
+        let mut out = Vec::new();
+        assert_eq!(emit(keys.clone(), false, &mut out), Ok(None));
+        assert_eq!(out, b"a\nb\n");
+
+        out.clear();
+        emit(keys, true, &mut out).unwrap();
+        assert_eq!(out, b"{\"keys\":[\"a\",\"b\"]}\n");
+    }
+}
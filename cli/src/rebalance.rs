@@ -0,0 +1,184 @@
+use std::fmt;
+use std::io::BufRead;
+
+use between::Between;
+use serde::Serialize;
+
+use crate::{Keys, Report};
+
+/// Arguments of the `rebalance` command.
+#[derive(Debug, clap::Args)]
+pub struct Args {
+    /// Emit only the keys to rewrite, as `<line>\t<key>`, instead of a replacement for every key.
+    #[arg(long)]
+    incremental: bool,
+
+    /// With `--incremental`, bring every key within this many characters. Defaults to the profile's
+    /// `max_len`; without either, keys are only shortened where possible.
+    #[arg(long, requires = "incremental")]
+    max_len: Option<usize>,
+}
+
+/// A key to rewrite, by the line it was read from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Rewrite {
+    /// The line number, counting from one.
+    pub line: usize,
+    /// The new key.
+    pub key: String,
+}
+
+/// The output of the `rebalance` command: a replacement for every key, or only the keys to rewrite.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Rebalanced {
+    /// The replacements, in the order of the input.
+    All(Keys),
+    /// The keys to rewrite, in ascending order of line.
+    Patch { rewrites: Vec<Rewrite> },
+}
+
+impl fmt::Display for Rebalanced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rebalanced::All(keys) => keys.fmt(f),
+            Rebalanced::Patch { rewrites } => rewrites
+                .iter()
+                .try_for_each(|rewrite| writeln!(f, "{}\t{}", rewrite.line, rewrite.key)),
+        }
+    }
+}
+
+impl Report for Rebalanced {}
+
+/// Reads a sorted list of keys, one per line, and finds their replacements.
+///
+/// Without `--incremental`, every key gets a fresh, evenly spaced replacement on the same line (see
+/// [`Between::rebalance`]). With it, only the keys that change are returned, with their line number (see
+/// [`Between::plan_rebalance`] and [`Between::compact`]).
+pub fn run(between: &Between, args: &Args, input: impl BufRead) -> Result<Rebalanced, String> {
+    let keys = read_keys(between, input)?;
+    if !args.incremental {
+        return Ok(Rebalanced::All(Keys {
+            keys: between
+                .rebalance(&keys)
+                .ok_or("keys are not strictly ascending")?,
+        }));
+    }
+
+    let patch = match args.max_len.or(between.max_len()) {
+        Some(max_len) => between
+            .plan_rebalance(&keys, max_len)
+            .ok_or_else(|| format!("keys do not fit within max_len {}", max_len))?,
+        None => between
+            .compact(&keys)
+            .ok_or("keys are not strictly ascending")?,
+    };
+    let rewrites = patch
+        .into_iter()
+        .map(|(index, key)| Rewrite {
+            line: index + 1,
+            key,
+        })
+        .collect();
+    Ok(Rebalanced::Patch { rewrites })
+}
+
+/// Reads keys, one per line, checking that they are valid and strictly ascending.
+///
+/// Keys are compared without their trailing lowest characters, as [`Between::rebalance`] and
+/// [`Between::compact`] do, so "1" and "10" in base 10 count as the same key.
+fn read_keys(between: &Between, input: impl BufRead) -> Result<Vec<String>, String> {
+    let mut keys: Vec<String> = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let key = line.map_err(|error| error.to_string())?;
+        if !between.valid(&key) {
+            return Err(format!("line {}: {:?} is not a valid key", index + 1, key));
+        }
+        if let Some(prev) = keys.last() {
+            if prev.trim_end_matches(between.low()) >= key.trim_end_matches(between.low()) {
+                return Err(format!(
+                    "line {}: {:?} does not sort after {:?}",
+                    index + 1,
+                    key,
+                    prev
+                ));
+            }
+        }
+        keys.push(key);
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use between::Between;
+
+    use crate::rebalance::{run, Args};
+
+    fn rebalance(
+        between: &Between,
+        incremental: bool,
+        max_len: Option<usize>,
+        input: &str,
+    ) -> Result<String, String> {
+        let args = Args {
+            incremental,
+            max_len,
+        };
+        run(between, &args, input.as_bytes()).map(|rebalanced| rebalanced.to_string())
+    }
+
+    #[test]
+    fn test_rebalance() {
+        let between = Between::base10();
+        assert_eq!(
+            rebalance(&between, false, None, "1\n11\n111\n").unwrap(),
+            "2\n4\n6\n"
+        );
+        assert_eq!(
+            rebalance(&between, true, None, "1\n15\n").unwrap(),
+            "2\t5\n"
+        );
+        assert_eq!(
+            rebalance(&between, true, Some(2), "1\n15\n155\n").unwrap(),
+            "3\t5\n"
+        );
+        assert_eq!(rebalance(&between, false, None, "").unwrap(), "");
+
+        let args = Args {
+            incremental: true,
+            max_len: None,
+        };
+        let rebalanced = run(&between, &args, "1\n15\n".as_bytes()).unwrap();
+        assert_eq!(
+            serde_json::to_string(&rebalanced).unwrap(),
+            r#"{"rewrites":[{"line":2,"key":"5"}]}"#
+        );
+    }
+
+    #[test]
+    fn test_rebalance_invalid_input() {
+        let between = Between::base10();
+        assert_eq!(
+            rebalance(&between, false, None, "1\nx\n").unwrap_err(),
+            "line 2: \"x\" is not a valid key"
+        );
+        assert_eq!(
+            rebalance(&between, true, None, "2\n1\n").unwrap_err(),
+            "line 2: \"1\" does not sort after \"2\""
+        );
+        assert_eq!(
+            rebalance(&between, true, None, "1\n10\n").unwrap_err(),
+            "line 2: \"10\" does not sort after \"1\""
+        );
+        assert_eq!(
+            rebalance(&between, false, None, "1\n10\n").unwrap_err(),
+            "line 2: \"10\" does not sort after \"1\""
+        );
+        assert_eq!(
+            rebalance(&between, true, Some(1), "1\n2\n3\n4\n5\n6\n7\n8\n9\n").unwrap_err(),
+            "keys do not fit within max_len 1"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt;
+use std::io::BufRead;
+
+use between::Between;
+use serde::Serialize;
+
+use crate::Report;
+
+/// Arguments of the `validate` command.
+#[derive(Debug, clap::Args)]
+pub struct Args {}
+
+/// A problem with the key on a line of the input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Problem {
+    /// The line number, counting from one.
+    pub line: usize,
+    /// What is wrong with the key.
+    pub message: String,
+}
+
+/// The output of the `validate` command: every problem found, in ascending order of line.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Validation {
+    pub problems: Vec<Problem>,
+}
+
+impl fmt::Display for Validation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.problems
+            .iter()
+            .try_for_each(|problem| writeln!(f, "line {}: {}", problem.line, problem.message))
+    }
+}
+
+impl Report for Validation {
+    fn failure(&self) -> Option<String> {
+        match self.problems.len() {
+            0 => None,
+            1 => Some("found 1 problem".to_string()),
+            n => Some(format!("found {} problems", n)),
+        }
+    }
+}
+
+/// Reads keys, one per line and in list order, and finds every problem with them: invalid characters,
+/// non-canonical keys, duplicates and ordering violations (see [`Between::audit`]).
+///
+/// A key is non-canonical if it ends in the lowest character of the alphabet. It sorts after the same key
+/// without those characters, but [`Between::between`] treats the two as equal and never generates it.
+pub fn run(between: &Between, _args: &Args, input: impl BufRead) -> Result<Validation, String> {
+    let keys = input
+        .lines()
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|error| error.to_string())?;
+    let report = between.audit(&keys);
+
+    let mut problems = Vec::new();
+    let mut report_each = |indices: &[usize], message: &dyn Fn(&str) -> String| {
+        for &index in indices {
+            problems.push(Problem {
+                line: index + 1,
+                message: message(&keys[index]),
+            });
+        }
+    };
+    report_each(&report.invalid, &|key| {
+        if key.is_empty() {
+            return "empty key".to_string();
+        }
+        let invalid: String = key
+            .chars()
+            .filter(|c| !between.chars().contains(c))
+            .collect();
+        format!(
+            "{:?} contains characters outside the alphabet: {:?}",
+            key, invalid
+        )
+    });
+    let non_canonical: Vec<usize> = keys
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| key.ends_with(between.low()) && between.valid(key))
+        .map(|(index, _)| index)
+        .collect();
+    report_each(&non_canonical, &|key| {
+        format!(
+            "{:?} is not canonical: it ends in the lowest character {:?}",
+            key,
+            between.low()
+        )
+    });
+    report_each(&report.duplicates, &|key| {
+        format!("{:?} duplicates an earlier key", key)
+    });
+    report_each(&report.canonical_duplicates, &|key| {
+        format!("{:?} sorts as equal to an earlier key", key)
+    });
+    report_each(&report.out_of_order, &|key| {
+        format!("{:?} sorts before the key preceding it", key)
+    });
+
+    problems.sort_by_key(|problem| problem.line);
+    Ok(Validation { problems })
+}
+
+#[cfg(test)]
+mod tests {
+    use between::Between;
+
+    use crate::validate::{run, Args};
+    use crate::Report;
+
+    #[test]
+    fn test_validate() {
+        let between = Between::base10();
+        let validation = run(&between, &Args {}, "1\n2x\n\n30\n3\n1\n5\n4\n".as_bytes()).unwrap();
+        let lines: Vec<usize> = validation
+            .problems
+            .iter()
+            .map(|problem| problem.line)
+            .collect();
+        assert_eq!(lines, vec![2, 3, 4, 5, 6, 6, 8]);
+        assert_eq!(validation.failure().unwrap(), "found 7 problems");
+        assert!(validation.to_string().starts_with(
+            "line 2: \"2x\" contains characters outside the alphabet: \"x\"\nline 3: empty key\n"
+        ));
+    }
+
+    #[test]
+    fn test_validate_clean() {
+        let validation = run(&Between::base10(), &Args {}, "1\n2\n25\n".as_bytes()).unwrap();
+        assert!(validation.problems.is_empty());
+        assert!(validation.failure().is_none());
+        assert_eq!(
+            serde_json::to_string(&validation).unwrap(),
+            r#"{"problems":[]}"#
+        );
+    }
+}
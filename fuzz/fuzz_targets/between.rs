@@ -0,0 +1,42 @@
+#![no_main]
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use between::{Between, Key};
+use libfuzzer_sys::fuzz_target;
+
+/// An alphabet and two of its keys.
+#[derive(Debug)]
+struct Input {
+    between: Between,
+    this: Key,
+    that: Key,
+}
+
+impl<'a> Arbitrary<'a> for Input {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let between = Between::arbitrary(u)?;
+        let this = between.arbitrary_key(u)?;
+        let that = between.arbitrary_key(u)?;
+        Ok(Input {
+            between,
+            this,
+            that,
+        })
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let Input {
+        between,
+        this,
+        that,
+    } = input;
+    let (this, that) = if this <= that { (this, that) } else { (that, this) };
+    if let Some(key) = between.between(&this, &that) {
+        assert!(between.valid(&key));
+        assert!(this.as_str() < key.as_str() && key.as_str() < that.as_str());
+    }
+    if let Some(key) = between.after(&this) {
+        assert!(this.as_str() < key.as_str());
+    }
+});
@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+/// Generates strictly increasing strings for appending to the end of a list, with logarithmic length growth.
+///
+/// Repeatedly calling [`Between::after`] halves the remaining room each time, so key lengths grow linearly
+/// with the number of appends. An `Appender` instead counts, like an odometer: every key is the last key of the
+/// list followed by a counter, where the counter's first characters encode how many digits follow. When all
+/// counters with `d` digits are used up, the appender carries over to `d + 1` digits, so after `n` appends the
+/// keys are about `log(n) / log(chars.len())` characters longer than the last key.
+///
+/// # Example
+///
+/// ```
+/// use between::{Appender, Between};
+///
+/// let between = Between::init();
+/// let keys: Vec<String> = Appender::new(&between, "V").unwrap().take(3).collect();
+/// assert_eq!(keys, vec!["V!!~", "V!0", "V!1"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Appender<'a> {
+    between: &'a Between,
+    prefix: Vec<usize>,
+    count: u64,
+}
+
+impl<'a> Appender<'a> {
+    /// Creates a new `Appender` generating strings that sort after `last`.
+    ///
+    /// # Arguments
+    ///
+    /// * `between` - The `Between` instance whose alphabet to use.
+    /// * `last` - The current last string of the list, or an empty string if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Appender>`, or `None` if `last` contains characters outside the alphabet or does not sort
+    /// before the highest character, like [`Between::after`].
+    pub fn new<S>(between: &'a Between, last: S) -> Option<Self>
+    where
+        S: AsKey,
+    {
+        let last = last.as_key();
+        let mut prefix = between.indices(last.trim_end_matches(between.low))?;
+        // Keys starting with the highest character sort at or after the end of the keyspace.
+        if prefix.first() == Some(&(between.alphabet.chars.len() - 1)) {
+            return None;
+        }
+        // Counters of many digits start with the highest character, so keep them behind a lower one.
+        if prefix.is_empty() {
+            prefix.push(0);
+        }
+        Some(Appender {
+            between,
+            prefix,
+            count: 0,
+        })
+    }
+}
+
+impl<'a> Iterator for Appender<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let high = self.between.alphabet.chars.len() - 1;
+        let mut key = self.prefix.clone();
+        key.extend(counter(self.between.alphabet.chars.len(), self.count)?);
+        // Strings may not end in the lowest character, so terminate such counters with the highest one.
+        // Counters are prefix-free, so this does not change their order.
+        if key.last() == Some(&0) {
+            key.push(high);
+        }
+        self.count = self.count.checked_add(1)?;
+        Some(self.between.key_from_indices(&key))
+    }
+}
+
+/// Encodes `count` as alphabet positions such that larger counts sort after smaller ones.
+///
+/// Counters with `digits` digits come after all counters with fewer digits. The number of digits is written
+/// first, in base `base - 1` with the highest position acting as a "carry" marker, then the digits themselves.
+/// No counter is a prefix of another.
+pub(crate) fn counter(base: usize, count: u64) -> Option<Vec<usize>> {
+    // Find how many digits 'count' needs, and its offset among counters with that many digits.
+    let base_wide = base as u128;
+    let mut offset = count as u128;
+    let mut digits: usize = 1;
+    let mut capacity = base_wide;
+    while offset >= capacity {
+        offset -= capacity;
+        digits += 1;
+        capacity = capacity.checked_mul(base_wide)?;
+    }
+
+    let high = base - 1;
+    let mut indices = vec![high; (digits - 1) / high];
+    indices.push((digits - 1) % high);
+
+    let start = indices.len();
+    indices.resize(start + digits, 0);
+    for position in (start..indices.len()).rev() {
+        indices[position] = (offset % base_wide) as usize;
+        offset /= base_wide;
+    }
+    Some(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Appender, Between};
+
+    #[test]
+    fn test_appender_is_strictly_increasing() {
+        let between = Between::init();
+
+        let keys: Vec<String> = Appender::new(&between, "").unwrap().take(10_000).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| !key.ends_with(between.low())));
+        assert!(keys.iter().all(|key| between.valid(key.as_str())));
+        assert!(keys.iter().all(|key| key.len() <= 6));
+
+        let mut appender = Appender::new(&between, "A").unwrap();
+        let first = appender.next().unwrap();
+        assert!("A" < first.as_str());
+        assert!(first.as_str() < "B");
+
+        assert!(Appender::new(&between, "$").is_none());
+        assert!(Appender::new(&between, "~").is_none());
+        assert!(Appender::new(&between, "~A").is_none());
+        assert!(Appender::new(&between, "z~").is_some());
+    }
+
+    #[test]
+    fn test_appender_length_grows_logarithmically() {
+        let between = Between::new(vec!['0', '1']);
+
+        let keys: Vec<String> = Appender::new(&between, "").unwrap().take(4096).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| !key.ends_with('0')));
+        assert!(keys.iter().all(|key| between.after(key.as_str()).is_some()));
+        // 4096 appends need 12 digits, plus 12 characters of digit count, the lowest character in front of
+        // them and a possible terminator.
+        assert_eq!(keys.iter().map(String::len).max().unwrap(), 26);
+
+        // Repeatedly calling 'after' grows by one character per append instead.
+        let keys: Vec<String> = between.after_iter("").take(256).collect();
+        assert_eq!(keys.iter().map(String::len).max().unwrap(), 257);
+
+        // Even once digit counts start with the highest character, keys stay before the end of the keyspace.
+        let between = Between::new(vec!['a', 'b', 'c', 'd']);
+        let keys: Vec<String> = Appender::new(&between, "").unwrap().take(200).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| between.after(key.as_str()).is_some()));
+    }
+}
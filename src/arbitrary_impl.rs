@@ -0,0 +1,97 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::prelude::*;
+use crate::{Between, Key};
+
+/// Generates an alphabet of arbitrary characters, falling back to the default alphabet when the input
+/// yields fewer than two distinct characters, so that fuzzers spend no input on rejected alphabets.
+impl<'a> Arbitrary<'a> for Between {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut chars: Vec<char> = u.arbitrary()?;
+        chars.sort_unstable();
+        chars.dedup();
+        if chars.len() < 2 {
+            return Ok(Between::init());
+        }
+        Ok(Between::new(chars))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Vec<char> as Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+/// Generates a key of the default alphabet, like [`Key`]'s `FromStr` accepts. Use [`Between::arbitrary_key`]
+/// for a custom alphabet.
+impl<'a> Arbitrary<'a> for Key {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Between::init().arbitrary_key(u)
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (1, None)
+    }
+}
+
+impl Between {
+    /// Generates an arbitrary valid key of this alphabet from fuzzer input.
+    ///
+    /// # Arguments
+    ///
+    /// * `u` - The unstructured fuzzer input.
+    ///
+    /// # Returns
+    ///
+    /// A non-empty `Key` of characters of the alphabet, or an error if `u` cannot be read.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arbitrary::Unstructured;
+    /// use between::Between;
+    ///
+    /// let between = Between::base10();
+    /// let mut u = Unstructured::new(&[3, 1, 4, 1, 5]);
+    /// let key = between.arbitrary_key(&mut u).unwrap();
+    /// assert!(between.valid(&key));
+    /// ```
+    pub fn arbitrary_key(&self, u: &mut Unstructured<'_>) -> Result<Key> {
+        // Bounded by the remaining input, at a byte or more per character.
+        let len = u.arbitrary_len::<u8>()?.max(1);
+        let key = (0..len)
+            .map(|_| u.choose(&self.alphabet.chars).copied())
+            .collect::<Result<String>>()?;
+        Ok(Key(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+
+    use crate::{Between, Key};
+
+    #[test]
+    fn test_arbitrary_keys_are_valid() {
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 7919 % 251) as u8).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let between = Between::arbitrary(&mut u).unwrap();
+            assert!(between.chars().len() >= 2);
+            let key = between.arbitrary_key(&mut u).unwrap();
+            assert!(between.valid(&key));
+            let key = Key::arbitrary(&mut u).unwrap();
+            assert!(Between::init().valid(&key));
+        }
+    }
+
+    #[test]
+    fn test_arbitrary_from_empty_input() {
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(
+            Between::arbitrary(&mut u).unwrap().chars(),
+            Between::init().chars()
+        );
+        assert!(!Key::arbitrary(&mut u).unwrap().as_str().is_empty());
+    }
+}
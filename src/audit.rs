@@ -0,0 +1,151 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// A health report for a list of keys, produced by [`Between::audit`].
+///
+/// Indices refer to positions in the audited list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditReport {
+    /// The number of keys audited.
+    pub count: usize,
+    /// How many valid keys there are of each length, counted in characters.
+    pub length_histogram: BTreeMap<usize, usize>,
+    /// The keys that are empty or contain characters outside the alphabet.
+    pub invalid: Vec<usize>,
+    /// The keys that are exactly equal to an earlier key.
+    pub duplicates: Vec<usize>,
+    /// The keys that differ from an earlier key only in trailing lowest characters, and so sort as equal
+    /// to it for the purposes of [`Between::between`].
+    pub canonical_duplicates: Vec<usize>,
+    /// The keys that sort before the valid key preceding them.
+    pub out_of_order: Vec<usize>,
+    /// How much a rebalance would shorten the keys, from 0 (already as short as possible) to 1.
+    pub rebalance_score: f64,
+}
+
+impl AuditReport {
+    /// Returns `true` if no key is invalid, duplicated or out of order.
+    pub fn is_consistent(&self) -> bool {
+        self.invalid.is_empty()
+            && self.duplicates.is_empty()
+            && self.canonical_duplicates.is_empty()
+            && self.out_of_order.is_empty()
+    }
+}
+
+impl Between {
+    /// Checks a list of keys, in list order, for the problems that make inserting between them fail or
+    /// produce long keys.
+    ///
+    /// The report flags invalid keys, exact and canonical duplicates and out-of-order keys, and summarizes key
+    /// lengths. Its rebalance score compares the mean key length to the length [`Between::rebalance`] would
+    /// produce for the same number of keys: a score of 0.75 means a rebalance would make keys about four times
+    /// shorter. Invalid keys are left out of every check but the first.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys, in the order of the list.
+    ///
+    /// # Returns
+    ///
+    /// An `AuditReport` describing the list.
+    pub fn audit<S>(&self, keys: &[S]) -> AuditReport
+    where
+        S: AsRef<str>,
+    {
+        let mut report = AuditReport {
+            count: keys.len(),
+            length_histogram: BTreeMap::new(),
+            invalid: Vec::new(),
+            duplicates: Vec::new(),
+            canonical_duplicates: Vec::new(),
+            out_of_order: Vec::new(),
+            rebalance_score: 0.0,
+        };
+
+        let mut seen: BTreeSet<&str> = BTreeSet::new();
+        let mut seen_canonical: BTreeSet<&str> = BTreeSet::new();
+        let mut previous: Option<&str> = None;
+        let mut total_len = 0;
+        let mut valid: usize = 0;
+        for (index, key) in keys.iter().enumerate() {
+            let key = key.as_ref();
+            if !self.valid(key) {
+                report.invalid.push(index);
+                continue;
+            }
+            let len = key.chars().count();
+            *report.length_histogram.entry(len).or_insert(0) += 1;
+            total_len += len;
+            valid += 1;
+
+            let canonical = key.trim_end_matches(self.low);
+            // A key equal to any earlier key is an exact duplicate, even after another spelling of it.
+            if !seen.insert(key) {
+                report.duplicates.push(index);
+            } else if !seen_canonical.insert(canonical) {
+                report.canonical_duplicates.push(index);
+            }
+            if previous.is_some_and(|previous| canonical < previous) {
+                report.out_of_order.push(index);
+            }
+            previous = Some(canonical);
+        }
+
+        if valid > 0 {
+            let mean_len = total_len as f64 / valid as f64;
+            // 'rebalance' uses the shortest length at which all keys fit below the highest character.
+            let high = [self.alphabet.chars.len() - 1];
+            let (ideal_len, _, _) = self.gap_holding(&[], &high, &BigUint::from(valid));
+            let ideal_len = ideal_len as f64;
+            report.rebalance_score = (1.0 - ideal_len / mean_len).max(0.0);
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_audit() {
+        let between = Between::init();
+
+        let report = between.audit(&["A", "B", "B", "B!", "$", "", "0", "C"]);
+        assert_eq!(report.count, 8);
+        assert_eq!(report.invalid, vec![4, 5]);
+        assert_eq!(report.duplicates, vec![2]);
+        assert_eq!(report.canonical_duplicates, vec![3]);
+        assert_eq!(report.out_of_order, vec![6]);
+        assert_eq!(
+            report.length_histogram.into_iter().collect::<Vec<_>>(),
+            vec![(1, 5), (2, 1)]
+        );
+        assert!(!between.audit(&["A", "A"]).is_consistent());
+
+        let report = between.audit(&["B!", "B", "B"]);
+        assert_eq!(report.duplicates, vec![2]);
+        assert_eq!(report.canonical_duplicates, vec![1]);
+    }
+
+    #[test]
+    fn test_audit_rebalance_score() {
+        let between = Between::init();
+
+        let report = between.audit(&between.spread(100));
+        assert!(report.is_consistent());
+        assert_eq!(report.rebalance_score, 0.0);
+
+        let keys: Vec<String> = between.after_iter("").take(100).collect();
+        let report = between.audit(&keys);
+        assert!(report.is_consistent());
+        assert!(report.rebalance_score > 0.75);
+
+        assert_eq!(between.audit::<&str>(&[]).rebalance_score, 0.0);
+    }
+}
@@ -0,0 +1,198 @@
+//! A transcription of the `between` algorithm of the `between2` npm package, for checking that Rust and
+//! Node services generate identical keys.
+//!
+//! [`Between::between`] validates its inputs and refuses bounds the JavaScript package accepts, so the two
+//! can differ on unusual inputs. [`Between::between2`] follows the JavaScript code step by step instead,
+//! comparing strings by UTF-16 code units as JavaScript does. [`Between::between2_mismatches`] checks outputs
+//! recorded from Node against it, and [`Between::between2_differences`] lists the inputs on which
+//! `Between::between` would give a different answer.
+//!
+//! # Example
+//!
+//! ```
+//! use between::Between;
+//!
+//! let between = Between::init();
+//! assert_eq!(between.between2("A", "B").unwrap(), "AV");
+//! assert!(between.between2_differences(&[("A", "B"), ("", "V")]).is_empty());
+//! ```
+
+use core::cmp::Ordering;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// An input on which two implementations disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// The first string.
+    pub this: String,
+    /// The second string.
+    pub that: String,
+    /// The output of the reference implementation.
+    pub expected: Option<String>,
+    /// The output of the implementation being checked.
+    pub actual: Option<String>,
+}
+
+impl Between {
+    /// Finds a string between two given strings exactly as the `between2` npm package does.
+    ///
+    /// Characters outside the alphabet are treated like missing characters, as in JavaScript. Where the
+    /// JavaScript package would loop forever (for example when `this` does not sort before `that`), this
+    /// returns `None` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the string the JavaScript package returns, or `None` if it returns
+    /// nothing.
+    pub fn between2(&self, this: &str, that: &str) -> Option<String> {
+        let a: Vec<char> = this.chars().collect();
+        let b: Vec<char> = that.chars().collect();
+        let position = |c: Option<&char>| c.and_then(|c| self.position(*c));
+
+        // Past the end of both strings every step appends the same character, so if no string has been
+        // found by then, none ever will be.
+        let mut s = String::new();
+        for i in 0..=a.len().max(b.len()) {
+            let low = position(a.get(i)).unwrap_or(0);
+            let high = position(b.get(i)).unwrap_or(self.alphabet.chars.len() - 1);
+            let c = if low + 1 < high {
+                // JavaScript's 'Math.round' rounds halves up.
+                self.alphabet.chars[(low + high).div_ceil(2)]
+            } else {
+                self.alphabet.chars[low]
+            };
+            s.push(c);
+            if js_cmp(this, &s) == Ordering::Less
+                && js_cmp(&s, that) == Ordering::Less
+                && c != self.low
+            {
+                return Some(s);
+            }
+        }
+        None
+    }
+
+    /// Checks outputs recorded from the `between2` npm package against [`Between::between2`].
+    ///
+    /// # Arguments
+    ///
+    /// * `cases` - The inputs and the outputs the JavaScript package returned for them.
+    ///
+    /// # Returns
+    ///
+    /// The cases whose recorded output differs, with the recorded output as `expected`.
+    pub fn between2_mismatches<A, B, E>(&self, cases: &[(A, B, E)]) -> Vec<Mismatch>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+        E: AsRef<str>,
+    {
+        cases
+            .iter()
+            .filter_map(|(this, that, expected)| {
+                let (this, that) = (this.as_ref(), that.as_ref());
+                let expected = Some(expected.as_ref().to_string());
+                mismatch(this, that, expected, self.between2(this, that))
+            })
+            .collect()
+    }
+
+    /// Lists the inputs on which [`Between::between`] differs from the `between2` npm package.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The inputs to try.
+    ///
+    /// # Returns
+    ///
+    /// The inputs on which the outputs differ, with the JavaScript package's output as `expected`.
+    pub fn between2_differences<A, B>(&self, pairs: &[(A, B)]) -> Vec<Mismatch>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        pairs
+            .iter()
+            .filter_map(|(this, that)| {
+                let (this, that) = (this.as_ref(), that.as_ref());
+                mismatch(
+                    this,
+                    that,
+                    self.between2(this, that),
+                    self.between(this, that),
+                )
+            })
+            .collect()
+    }
+}
+
+fn mismatch(
+    this: &str,
+    that: &str,
+    expected: Option<String>,
+    actual: Option<String>,
+) -> Option<Mismatch> {
+    if expected == actual {
+        return None;
+    }
+    Some(Mismatch {
+        this: this.to_string(),
+        that: that.to_string(),
+        expected,
+        actual,
+    })
+}
+
+/// Compares strings by UTF-16 code units, like JavaScript's `<`.
+fn js_cmp(a: &str, b: &str) -> Ordering {
+    a.encode_utf16().cmp(b.encode_utf16())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_between2() {
+        let between = Between::init();
+
+        assert_eq!(between.between2("A", "B").unwrap(), "AV");
+        assert_eq!(between.between2("A", "C").unwrap(), "B");
+        assert_eq!(between.between2("", "~").unwrap(), "V");
+        assert_eq!(between.between2("!", "0").unwrap(), "!V");
+        // The JavaScript package loops forever on these.
+        assert!(between.between2("A", "A!").is_none());
+        assert!(between.between2("B", "A").is_none());
+    }
+
+    #[test]
+    fn test_between2_harness() {
+        let between = Between::init();
+
+        let cases = [("A", "B", "AV"), ("A", "C", "C")];
+        let mismatches = between.between2_mismatches(&cases);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].expected.as_deref(), Some("C"));
+        assert_eq!(mismatches[0].actual.as_deref(), Some("B"));
+
+        let keys: Vec<String> = between.after_iter("").take(50).collect();
+        let pairs: Vec<(&str, &str)> = keys
+            .windows(2)
+            .map(|pair| (pair[0].as_str(), pair[1].as_str()))
+            .chain([("", "A"), ("A", "A0"), ("z", "~")])
+            .collect();
+        assert!(between.between2_differences(&pairs).is_empty());
+
+        // 'between' refuses characters outside the alphabet; the JavaScript package does not.
+        let differences = between.between2_differences(&[("A", "B$")]);
+        assert_eq!(differences[0].actual, None);
+        assert!(differences[0].expected.is_some());
+    }
+}
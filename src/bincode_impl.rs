@@ -0,0 +1,126 @@
+use bincode::de::{BorrowDecoder, Decoder};
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{BorrowDecode, Decode, Encode};
+
+use crate::{Between, Key};
+
+impl Encode for Key {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.as_str().encode(encoder)
+    }
+}
+
+/// Decodes a `Key` without validating it against an alphabet, since binary snapshots are
+/// expected to come from a trusted writer. Use [`Key::parse`] to re-validate untrusted input.
+impl<Context> Decode<Context> for Key {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Ok(Key(String::decode(decoder)?))
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for Key {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Ok(Key(String::borrow_decode(decoder)?))
+    }
+}
+
+/// Encodes a `Between` as its configuration: its alphabet, the name of its strategy, its maximum length and its
+/// forbidden substrings, like the serde implementation.
+///
+/// Fails for custom strategies, which have no [`Strategy::name`](crate::Strategy::name).
+impl Encode for Between {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        let strategy = self.strategy.name().ok_or(EncodeError::Other(
+            "Custom strategies have no name and cannot be encoded.",
+        ))?;
+        self.alphabet.chars.encode(encoder)?;
+        strategy.encode(encoder)?;
+        self.max_len.encode(encoder)?;
+        self.forbidden.encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for Between {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let chars: Vec<char> = Vec::decode(decoder)?;
+        let strategy = String::decode(decoder)?;
+        let max_len = Option::decode(decoder)?;
+        let forbidden = Vec::decode(decoder)?;
+        Between::from_config(chars, &strategy, max_len, forbidden).map_err(DecodeError::Other)
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for Between {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Between::decode(decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, Key, PreferLeft, Strategy};
+
+    #[test]
+    fn test_key_round_trip() {
+        let config = bincode::config::standard();
+        let key: Key = "AV".parse().unwrap();
+
+        let bytes = bincode::encode_to_vec(&key, config).unwrap();
+        let (decoded, _): (Key, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_between_round_trip() {
+        let config = bincode::config::standard();
+        let between = Between::new(vec!['c', 'a', 'b']);
+
+        let bytes = bincode::encode_to_vec(&between, config).unwrap();
+        let (decoded, _): (Between, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded.chars(), &vec!['a', 'b', 'c']);
+        assert_eq!(decoded.between("a", "c").unwrap(), "b");
+        assert_eq!(decoded.strategy().name(), Some("Bisect"));
+        assert_eq!(decoded.max_len(), None);
+        assert!(decoded.forbidden().is_empty());
+
+        let between = Between::base62()
+            .with_strategy(PreferLeft)
+            .with_max_len(12)
+            .with_forbidden(["xx"]);
+        let bytes = bincode::encode_to_vec(&between, config).unwrap();
+        let (decoded, _): (Between, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded.chars(), between.chars());
+        assert_eq!(decoded.strategy().name(), Some("PreferLeft"));
+        assert_eq!(decoded.max_len(), Some(12));
+        assert_eq!(decoded.forbidden(), ["xx"]);
+        assert_eq!(decoded.between("a", "b"), between.between("a", "b"));
+    }
+
+    #[test]
+    fn test_between_rejects_invalid_config() {
+        let config = bincode::config::standard();
+        let encode = |chars: Vec<char>, strategy: &str| {
+            let parts = (chars, strategy, None::<usize>, Vec::<String>::new());
+            bincode::encode_to_vec(parts, config).unwrap()
+        };
+        let bytes = encode(vec!['a', 'a'], "Bisect");
+        assert!(bincode::decode_from_slice::<Between, _>(&bytes, config).is_err());
+        let bytes = encode(vec!['a', 'b'], "Nope");
+        assert!(bincode::decode_from_slice::<Between, _>(&bytes, config).is_err());
+
+        #[derive(Debug)]
+        struct Custom;
+        impl Strategy for Custom {
+            fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+                between.shortest_between(this, that)
+            }
+        }
+        let between = Between::init().with_strategy(Custom);
+        assert!(bincode::encode_to_vec(&between, config).is_err());
+    }
+}
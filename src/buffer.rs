@@ -0,0 +1,203 @@
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Finds a string that is lexicographically between two given strings, appending it to a caller-provided
+    /// buffer instead of allocating a new `String`.
+    ///
+    /// Returns the same string [`Between::between`] does. Clearing and reusing one buffer across calls lets
+    /// tight loops, such as assigning keys to millions of rows, get by with a single allocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `out` - The buffer the between string is appended to.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a between string was appended, or `false` if there is none, in which case `out` is left
+    /// untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use between::Between;
+    ///
+    /// let between = Between::init();
+    /// let mut key = String::new();
+    /// assert!(between.between_into("A", "B", &mut key));
+    /// assert_eq!(key, between.between("A", "B").unwrap());
+    ///
+    /// key.clear();
+    /// assert!(!between.between_into("B", "A", &mut key));
+    /// assert!(key.is_empty());
+    /// ```
+    pub fn between_into<S, T>(&self, this: S, that: T, out: &mut String) -> bool
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = (this.as_key(), that.as_key());
+        let (this, that) = (this.as_ref(), that.as_ref());
+        if !self.forbidden.is_empty() {
+            return match self.between(this, that) {
+                Some(key) => {
+                    out.push_str(&key);
+                    true
+                }
+                None => false,
+            };
+        }
+
+        let start = out.len();
+        if !self.strategy.between_into(self, this, that, out) {
+            return false;
+        }
+        match self.max_len {
+            Some(max_len) if out[start..].chars().count() > max_len => {
+                out.truncate(start);
+                match self
+                    .shortest_between(this, that)
+                    .filter(|key| key.chars().count() <= max_len)
+                {
+                    Some(key) => {
+                        out.push_str(&key);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            _ => true,
+        }
+    }
+
+    /// Finds a string that is lexicographically after a given string, appending it to `out`.
+    ///
+    /// See [`Between::between_into`].
+    pub fn after_into<S>(&self, before_string: S, out: &mut String) -> bool
+    where
+        S: AsKey,
+    {
+        let mut high = [0; 4];
+        self.between_into(before_string, self.high.encode_utf8(&mut high), out)
+    }
+
+    /// Finds a string that is lexicographically before a given string, appending it to `out`.
+    ///
+    /// See [`Between::between_into`].
+    pub fn before_into<S>(&self, after_string: S, out: &mut String) -> bool
+    where
+        S: AsKey,
+    {
+        let mut low = [0; 4];
+        self.between_into(self.low.encode_utf8(&mut low), after_string, out)
+    }
+
+    /// Finds a string between each of many pairs of strings, returning the results in the order of the
+    /// pairs.
+    ///
+    /// Every result is what [`Between::between`] returns for its pair. Keys are built in one scratch buffer
+    /// that is reused across pairs and copied out at their exact size, which makes this the building block
+    /// for batch endpoints that serve many inserts per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - The pairs of strings to find a string between.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Option<String>>` with one result per pair, `None` where [`Between::between`] would return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use between::Between;
+    ///
+    /// let between = Between::init();
+    /// let keys = between.between_many([("A", "B"), ("B", "A"), ("", "C")]);
+    /// assert_eq!(keys[0], between.between("A", "B"));
+    /// assert_eq!(keys[1], None);
+    /// assert_eq!(keys[2], between.between("", "C"));
+    /// ```
+    pub fn between_many<I, S, T>(&self, pairs: I) -> Vec<Option<String>>
+    where
+        I: IntoIterator<Item = (S, T)>,
+        S: AsKey,
+        T: AsKey,
+    {
+        let pairs = pairs.into_iter();
+        let mut keys = Vec::with_capacity(pairs.size_hint().0);
+        let mut scratch = String::new();
+        for (this, that) in pairs {
+            scratch.clear();
+            keys.push(
+                self.between_into(this, that, &mut scratch)
+                    .then(|| scratch.as_str().to_owned()),
+            );
+        }
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, PreferShort};
+
+    #[test]
+    fn test_between_into_matches_between() {
+        for between in [
+            Between::init(),
+            Between::new(vec!['α', 'β', 'γ', 'δ']),
+            Between::init().with_strategy(PreferShort),
+            Between::init().with_max_len(3),
+            Between::base10().with_forbidden(["5"]),
+        ] {
+            let mut key = String::from("prefix:");
+            let mut prev = String::new();
+            for _ in 0..50 {
+                let expected = between.after(&prev);
+                key.truncate("prefix:".len());
+                assert_eq!(between.after_into(&prev, &mut key), expected.is_some());
+                match expected {
+                    Some(expected) => assert_eq!(key["prefix:".len()..], expected),
+                    None => {
+                        assert_eq!(key, "prefix:");
+                        break;
+                    }
+                }
+                prev = key["prefix:".len()..].to_string();
+            }
+        }
+    }
+
+    #[test]
+    fn test_between_many() {
+        let between = Between::init().with_max_len(2);
+        let keys: Vec<String> = between.spread(20);
+        let pairs: Vec<(&str, &str)> = keys
+            .windows(2)
+            .map(|pair| (pair[0].as_str(), pair[1].as_str()))
+            .chain([("B", "A"), ("A", "A!")])
+            .collect();
+        let expected: Vec<Option<String>> = pairs
+            .iter()
+            .map(|(this, that)| between.between(this, that))
+            .collect();
+        assert_eq!(between.between_many(pairs), expected);
+        assert!(between
+            .between_many(Vec::<(String, String)>::new())
+            .is_empty());
+    }
+
+    #[test]
+    fn test_before_into() {
+        let between = Between::init();
+        let mut key = String::new();
+        assert!(between.before_into("B", &mut key));
+        assert_eq!(key, between.before("B").unwrap());
+        key.clear();
+        assert!(!between.before_into("!", &mut key));
+        assert!(key.is_empty());
+    }
+}
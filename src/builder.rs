@@ -0,0 +1,196 @@
+use alloc::sync::Arc;
+use core::error::Error;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+use crate::prelude::*;
+use crate::{Between, Bisect, Strategy};
+
+/// An error returned when an alphabet cannot be built, for example by an [`AlphabetBuilder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlphabetError {
+    /// The alphabet has fewer than two distinct characters.
+    TooFewSymbols {
+        /// The number of distinct characters left.
+        count: usize,
+    },
+    /// A symbol is not a single grapheme cluster.
+    NotAGrapheme {
+        /// The symbol.
+        symbol: String,
+    },
+    /// A symbol is a prefix of another, so keys would not sort as strings the way they sort as symbols.
+    AmbiguousPrefix {
+        /// The shorter symbol.
+        prefix: String,
+        /// The symbol it is a prefix of.
+        symbol: String,
+    },
+    /// Two symbols form a single grapheme cluster when written next to each other.
+    MergingSymbols {
+        /// The first symbol.
+        first: String,
+        /// The symbol written after it.
+        second: String,
+    },
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlphabetError::TooFewSymbols { count } => write!(
+                f,
+                "alphabet has {} distinct characters, expected at least two",
+                count
+            ),
+            AlphabetError::NotAGrapheme { symbol } => {
+                write!(f, "symbol {:?} is not a single grapheme cluster", symbol)
+            }
+            AlphabetError::AmbiguousPrefix { prefix, symbol } => {
+                write!(f, "symbol {:?} is a prefix of {:?}", prefix, symbol)
+            }
+            AlphabetError::MergingSymbols { first, second } => write!(
+                f,
+                "symbols {:?} and {:?} merge into one grapheme cluster",
+                first, second
+            ),
+        }
+    }
+}
+
+impl Error for AlphabetError {}
+
+/// A fluent builder for alphabets, created with [`Between::builder`] or from an existing instance with
+/// [`Between::into_builder`].
+///
+/// The lowest and highest characters are recomputed when the alphabet is built, so excluding either of them
+/// is fine.
+///
+/// # Example
+///
+/// ```
+/// use between::Between;
+///
+/// // The default alphabet, without '_' and '~' for a legacy system.
+/// let between = Between::init().into_builder().exclude(['_', '~']).build().unwrap();
+/// assert_eq!(between.chars().len(), 63);
+/// assert_eq!(between.high(), 'z');
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlphabetBuilder {
+    chars: Vec<char>,
+    strategy: Arc<dyn Strategy>,
+    max_len: Option<usize>,
+    forbidden: Vec<String>,
+}
+
+impl Between {
+    /// Creates a builder with no characters yet.
+    pub fn builder() -> AlphabetBuilder {
+        AlphabetBuilder {
+            chars: Vec::new(),
+            strategy: Arc::new(Bisect),
+            max_len: None,
+            forbidden: Vec::new(),
+        }
+    }
+
+    /// Creates a builder that starts from this instance's characters, strategy, maximum length and forbidden
+    /// substrings, for example to adjust a preset.
+    pub fn into_builder(self) -> AlphabetBuilder {
+        AlphabetBuilder {
+            chars: self.alphabet.chars.clone(),
+            strategy: self.strategy,
+            max_len: self.max_len,
+            forbidden: self.forbidden.to_vec(),
+        }
+    }
+}
+
+impl AlphabetBuilder {
+    /// Adds characters to the alphabet. Characters already in it are ignored.
+    pub fn include<I>(mut self, chars: I) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        self.chars.extend(chars);
+        self
+    }
+
+    /// Adds a range of characters to the alphabet, such as `'a'..='z'`.
+    pub fn include_range(self, range: RangeInclusive<char>) -> Self {
+        self.include(range)
+    }
+
+    /// Removes characters from the alphabet. Characters not in it are ignored.
+    pub fn exclude<I>(mut self, chars: I) -> Self
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let excluded: Vec<char> = chars.into_iter().collect();
+        self.chars.retain(|c| !excluded.contains(c));
+        self
+    }
+
+    /// Builds the `Between` instance.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<Between, AlphabetError>` that contains the instance, or an error if fewer than two distinct
+    /// characters are left.
+    pub fn build(self) -> Result<Between, AlphabetError> {
+        let mut chars = self.chars;
+        chars.sort_unstable();
+        chars.dedup();
+        if chars.len() < 2 {
+            return Err(AlphabetError::TooFewSymbols { count: chars.len() });
+        }
+        let mut between = Between::new(chars);
+        between.strategy = self.strategy;
+        between.max_len = self.max_len;
+        between.forbidden = self.forbidden.into();
+        Ok(between)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlphabetError, Between, PreferLeft};
+
+    #[test]
+    fn test_builder_exclude() {
+        let between = Between::init()
+            .with_max_len(4)
+            .with_strategy(PreferLeft)
+            .into_builder()
+            .exclude(['!', '~', '_', '?'])
+            .build()
+            .unwrap();
+        assert_eq!(between.low(), '0');
+        assert_eq!(between.high(), 'z');
+        assert_eq!(between.chars().len(), 62);
+        assert_eq!(between.max_len(), Some(4));
+        assert_eq!(format!("{:?}", between.strategy()), "PreferLeft");
+        assert!(between.between("A", "B").unwrap().len() <= 4);
+    }
+
+    #[test]
+    fn test_builder_include() {
+        let between = Between::builder()
+            .include_range('a'..='f')
+            .include(['0', '1', 'a'])
+            .build()
+            .unwrap();
+        assert_eq!(between.chars().iter().collect::<String>(), "01abcdef");
+
+        assert_eq!(
+            Between::builder().include(['a', 'a']).build().unwrap_err(),
+            AlphabetError::TooFewSymbols { count: 1 }
+        );
+        assert!(Between::base10()
+            .into_builder()
+            .exclude('1'..='9')
+            .build()
+            .is_err());
+    }
+}
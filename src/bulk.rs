@@ -0,0 +1,422 @@
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+/// The `n` evenly spaced strings of a gap, as found by [`Between::spacing`].
+pub(crate) struct Spacing {
+    len: usize,
+    low: BigUint,
+    span: BigUint,
+    slots: BigUint,
+}
+
+impl Spacing {
+    /// Returns the `i`-th string, counting from one.
+    pub(crate) fn key(&self, between: &Between, i: usize) -> String {
+        // Since span >= n + 1, every step is at least one.
+        let value = &self.low + &self.span * i / &self.slots;
+        between.key_from_indices(&between.unscaled(&value, self.len))
+    }
+}
+
+impl Between {
+    /// Finds `n` strings that are evenly spaced, in ascending order, strictly between two given strings.
+    ///
+    /// The strings are as short as possible: all of them use the smallest length at which `n` distinct
+    /// strings fit between `this` and `that`.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `n` - The number of strings to generate.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the between strings if possible, or `None` if the bounds are
+    /// invalid under the same rules as [`Between::between`].
+    pub fn n_between<S, T>(&self, this: S, that: T, n: usize) -> Option<Vec<String>>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        if n == 0 {
+            return Some(vec![]);
+        }
+        let spacing = self.spacing(&this, &that, n);
+        Some((1..=n).map(|i| spacing.key(self, i)).collect())
+    }
+
+    /// Finds the shortest length at which `n` distinct strings fit between two bounds, given as alphabet
+    /// positions, and how to spread them evenly over the gap.
+    pub(crate) fn spacing(&self, this: &[usize], that: &[usize], n: usize) -> Spacing {
+        // At length 'len', the candidates are the integers strictly between 'low' and 'high'.
+        let slots = BigUint::from(n) + 1u32;
+        let mut len = 1;
+        loop {
+            let low = self.scaled(this, len);
+            let high = self.scaled_ceil(that, len);
+            let span = high - &low;
+            if span >= slots {
+                return Spacing {
+                    len,
+                    low,
+                    span,
+                    slots,
+                };
+            }
+            len += 1;
+        }
+    }
+
+    /// Counts the strings of at most `max_len` characters that sort strictly between two given strings.
+    ///
+    /// Only strings that [`Between::between`] could generate are counted, i.e. strings that do not end in the
+    /// lowest character. This tells how many inserts a gap can absorb before keys must grow longer than
+    /// `max_len`, which helps plan bulk inserts and decide when to rebalance.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `max_len` - The maximum length of the counted strings, in characters.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<BigUint>` that contains the exact count, or `None` if the bounds are invalid under the same
+    /// rules as [`Between::between`].
+    pub fn capacity<S, T>(&self, this: S, that: T, max_len: usize) -> Option<BigUint>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        // Strings of at most 'max_len' characters are exactly the integers at that scale, and those strictly
+        // inside the gap lie strictly between 'this' rounded down and 'that' rounded up.
+        let low = self.scaled(&this, max_len) + 1u32;
+        let high = self.scaled_ceil(&that, max_len);
+        Some(if high > low {
+            high - low
+        } else {
+            BigUint::zero()
+        })
+    }
+
+    /// Finds a string between two given strings that leaves room for `reserve` more strings on each side.
+    ///
+    /// The result is the middle one of the `2 * reserve + 1` evenly spaced strings that [`Between::n_between`]
+    /// would generate, so `reserve` further inserts fit between `this` and the result, and between the result
+    /// and `that`, without growing longer than those strings. With a `reserve` of zero this is the shortest
+    /// string in the gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `reserve` - The number of future inserts to leave room for on each side.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`].
+    pub fn between_with_capacity<S, T>(&self, this: S, that: T, reserve: usize) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let n = reserve.checked_mul(2)?.checked_add(1)?;
+        self.n_between(this, that, n)?.into_iter().nth(reserve)
+    }
+
+    /// Generates `n` short strings spread evenly, in ascending order, across the whole keyspace.
+    ///
+    /// This is useful for assigning initial keys to a list of `n` existing items. Like
+    /// [`Between::after`], the keyspace ends at the highest character.
+    pub fn spread(&self, n: usize) -> Vec<String> {
+        self.n_between("", self.high, n).unwrap()
+    }
+
+    /// Splits the keyspace into `n` contiguous ranges of equal capacity, returning the `n - 1` boundaries.
+    ///
+    /// The first range is everything before the first boundary and the last one everything after the last
+    /// boundary, up to the highest character. This is useful for sharding, parallel backfills and range-based
+    /// routing. Boundaries are as short as possible, so the ranges hold the same number of keys to within one
+    /// at the boundaries' length, and exactly proportionally beyond it.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of ranges. Zero or one range needs no boundaries.
+    pub fn partition(&self, n: usize) -> Vec<String> {
+        self.spread(n.saturating_sub(1))
+    }
+
+    /// Splits the gap between two given strings into `n` contiguous ranges of equal capacity, returning the
+    /// `n - 1` boundaries. See [`Between::partition`].
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the boundaries in ascending order, or `None` if the bounds are
+    /// invalid under the same rules as [`Between::between`].
+    pub fn partition_between<S, T>(&self, this: S, that: T, n: usize) -> Option<Vec<String>>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        self.n_between(this, that, n.saturating_sub(1))
+    }
+
+    /// Generates `n` new strings to insert into an existing sorted list, placing them in its widest gaps.
+    ///
+    /// The new strings are shared out between the gaps (including the gap before the first string and the
+    /// gap after the last one) in proportion to how wide each gap is, so bulk imports into a populated list
+    /// don't all land between the same two neighbors and blow up their length.
+    ///
+    /// # Arguments
+    ///
+    /// * `existing` - The existing strings, in strictly ascending order.
+    /// * `n` - The number of strings to generate.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<(usize, String)>>` that contains, in ascending order, each new string paired with the
+    /// index in `existing` it should be inserted before (`existing.len()` for the end of the list), or `None`
+    /// if `existing` contains invalid strings or is not strictly ascending.
+    pub fn interleave<S>(&self, existing: &[S], n: usize) -> Option<Vec<(usize, String)>>
+    where
+        S: AsRef<str>,
+    {
+        let keys = self.sorted_indices(existing)?;
+
+        // Measure every gap, including the ones at both ends, at a length where all keys are exact.
+        let high = vec![self.alphabet.chars.len() - 1];
+        let len = keys.iter().map(Vec::len).max().unwrap_or(1).max(1);
+        let mut bounds: Vec<&[usize]> = Vec::with_capacity(keys.len() + 2);
+        bounds.push(&[]);
+        bounds.extend(keys.iter().map(Vec::as_slice));
+        bounds.push(&high);
+        let widths: Vec<BigUint> = bounds
+            .windows(2)
+            .map(|pair| {
+                let (low, high) = (self.scaled(pair[0], len), self.scaled(pair[1], len));
+                if low < high {
+                    high - low
+                } else {
+                    BigUint::zero()
+                }
+            })
+            .collect();
+
+        // Share out 'n' in proportion to the widths, handing the remainder to the largest fractional parts.
+        let total: BigUint = widths.iter().sum();
+        if total.is_zero() {
+            return if n == 0 { Some(vec![]) } else { None };
+        }
+        let shares: Vec<(usize, BigUint)> = widths
+            .iter()
+            .map(|width| {
+                let share = width * n;
+                ((&share / &total).to_usize().unwrap(), share % &total)
+            })
+            .collect();
+        let mut counts: Vec<usize> = shares.iter().map(|(count, _)| *count).collect();
+        let leftover = n - counts.iter().sum::<usize>();
+        let mut order: Vec<usize> = (0..shares.len()).collect();
+        order.sort_by(|a, b| {
+            shares[*b]
+                .1
+                .cmp(&shares[*a].1)
+                .then_with(|| widths[*b].cmp(&widths[*a]))
+                .then_with(|| a.cmp(b))
+        });
+        for gap in order.into_iter().take(leftover) {
+            counts[gap] += 1;
+        }
+
+        let mut result = Vec::with_capacity(n);
+        for (gap, count) in counts.into_iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let this = self.key_from_indices(bounds[gap]);
+            let that = self.key_from_indices(bounds[gap + 1]);
+            for key in self.n_between(this, that, count)? {
+                result.push((gap, key));
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+    use num_traits::{ToPrimitive, Zero};
+
+    use crate::Between;
+
+    #[test]
+    fn test_n_between() {
+        let between = Between::init();
+
+        assert_eq!(between.n_between("A", "B", 1).unwrap(), vec!["AV"]);
+        assert_eq!(between.n_between("A", "C", 1).unwrap(), vec!["B"]);
+        assert_eq!(
+            between.n_between("A", "B", 0).unwrap(),
+            Vec::<String>::new()
+        );
+
+        let keys = between.n_between("A", "B", 100).unwrap();
+        assert_eq!(keys.len(), 100);
+        assert!(keys.iter().all(|key| key.len() <= 3));
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!("A" < keys[0].as_str() && keys[99].as_str() < "B");
+
+        assert!(between.n_between("B", "A", 1).is_none());
+        assert!(between.n_between("A", "A!", 1).is_none());
+        assert!(between.n_between("A", "$", 1).is_none());
+    }
+
+    #[test]
+    fn test_n_between_two_char_set() {
+        let between = Between::new(vec!['0', '1']);
+
+        assert_eq!(
+            between.n_between("", "1", 3).unwrap(),
+            vec!["001", "01", "011"]
+        );
+        assert_eq!(between.n_between("0", "001", 1).unwrap(), vec!["0001"]);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.capacity("1", "3", 1).unwrap(), BigUint::from(1u32));
+        assert_eq!(between.capacity("1", "2", 1).unwrap(), BigUint::zero());
+        // "11" through "19".
+        assert_eq!(between.capacity("1", "2", 2).unwrap(), BigUint::from(9u32));
+        // Those, and the 90 strings from "101" to "199" that don't end in '0'.
+        assert_eq!(between.capacity("1", "2", 3).unwrap(), BigUint::from(99u32));
+        assert_eq!(between.capacity("", "9", 2).unwrap(), BigUint::from(89u32));
+        assert_eq!(
+            between.capacity("1234", "1235", 3).unwrap(),
+            BigUint::zero()
+        );
+        assert_eq!(between.capacity("1", "2", 0).unwrap(), BigUint::zero());
+
+        let between = Between::init();
+        assert_eq!(
+            between.capacity("A", "B", 30).unwrap(),
+            BigUint::from(65u32).pow(29) - 1u32
+        );
+        assert!(between.capacity("B", "A", 3).is_none());
+    }
+
+    #[test]
+    fn test_capacity_matches_n_between() {
+        let between = Between::init();
+
+        // 'n_between' uses the shortest length with room for 'n' strings.
+        let capacity = between.capacity("A", "B", 2).unwrap();
+        let n = capacity.to_usize().unwrap();
+        let keys = between.n_between("A", "B", n).unwrap();
+        assert!(keys.iter().all(|key| key.len() <= 2));
+        let keys = between.n_between("A", "B", n + 1).unwrap();
+        assert!(keys.iter().any(|key| key.len() > 2));
+    }
+
+    #[test]
+    fn test_between_with_capacity() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.between_with_capacity("1", "2", 0).unwrap(), "15");
+        assert_eq!(between.between_with_capacity("1", "2", 4).unwrap(), "15");
+        assert_eq!(between.between_with_capacity("1", "2", 5).unwrap(), "15");
+        assert_eq!(between.between_with_capacity("1", "12", 0).unwrap(), "11");
+        assert_eq!(between.between_with_capacity("1", "12", 5).unwrap(), "11");
+
+        // There is room for 'reserve' strings on either side without growing longer.
+        let key = between.between_with_capacity("1", "2", 20).unwrap();
+        assert_eq!(key, "15");
+        let left = between.n_between("1", key.as_str(), 20).unwrap();
+        let right = between.n_between(key.as_str(), "2", 20).unwrap();
+        assert!(left
+            .iter()
+            .chain(right.iter())
+            .all(|other| other.len() <= 3));
+
+        assert!(between.between_with_capacity("2", "1", 1).is_none());
+    }
+
+    #[test]
+    fn test_spread() {
+        let between = Between::init();
+
+        assert_eq!(between.spread(3), vec!["F", "V", "k"]);
+        assert!(between.spread(0).is_empty());
+
+        let keys = between.spread(1000);
+        assert_eq!(keys.len(), 1000);
+        assert!(keys.iter().all(|key| key.len() <= 2));
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| between.after(key.as_str()).is_some()));
+    }
+
+    #[test]
+    fn test_partition() {
+        let between = Between::init();
+
+        assert_eq!(between.partition(4), vec!["F", "V", "k"]);
+        assert!(between.partition(1).is_empty());
+        assert!(between.partition(0).is_empty());
+
+        // Each of the 2 ranges holds 31 single characters.
+        let boundary = &between.partition(2)[0];
+        let low = between.capacity("", boundary.as_str(), 1).unwrap();
+        let high = between.capacity(boundary.as_str(), "~", 1).unwrap();
+        assert_eq!(low, high);
+
+        assert_eq!(between.partition_between("A", "C", 2).unwrap(), vec!["B"]);
+        assert!(between.partition_between("C", "A", 2).is_none());
+    }
+
+    #[test]
+    fn test_interleave() {
+        let between = Between::init();
+
+        // The gap between "V" and "k" is much wider than the one between "A" and "B".
+        let existing = vec!["A", "B", "V", "k"];
+        let inserted = between.interleave(&existing, 4).unwrap();
+        assert_eq!(inserted.len(), 4);
+        assert!(inserted.iter().all(|(index, _)| *index != 1));
+
+        let mut merged: Vec<String> = existing.iter().map(|key| key.to_string()).collect();
+        for (offset, (index, key)) in inserted.into_iter().enumerate() {
+            merged.insert(index + offset, key);
+        }
+        assert!(merged.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_interleave_edge_cases() {
+        let between = Between::init();
+
+        let empty: Vec<&str> = vec![];
+        assert_eq!(
+            between.interleave(&empty, 3).unwrap(),
+            vec![
+                (0, "F".to_string()),
+                (0, "V".to_string()),
+                (0, "k".to_string())
+            ]
+        );
+        assert!(between.interleave(&["A"], 0).unwrap().is_empty());
+
+        assert!(between.interleave(&["B", "A"], 1).is_none());
+        assert!(between.interleave(&["A", "A!"], 1).is_none());
+        assert!(between.interleave(&["A", "$"], 1).is_none());
+    }
+}
@@ -0,0 +1,151 @@
+use crate::prelude::*;
+use crate::{bisect_positions, Positions};
+
+/// Generates keys that are byte strings rather than text, for binary key-value stores that compare keys
+/// byte by byte and do not need them to be valid UTF-8.
+///
+/// Keys are found by bisecting, like the default [`Bisect`](crate::Bisect) strategy, and trailing low bytes
+/// are ignored the way [`Between::between`](crate::Between::between) ignores trailing low characters.
+///
+/// # Example
+///
+/// ```
+/// use between::ByteBetween;
+///
+/// let between = ByteBetween::full();
+/// assert_eq!(between.between(&[0x10], &[0x20]).unwrap(), vec![0x18]);
+/// assert_eq!(between.between(&[0x10], &[0x11]).unwrap(), vec![0x10, 0x80]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteBetween {
+    bytes: Vec<u8>,
+    lookup: [Option<u8>; 256],
+}
+
+impl ByteBetween {
+    /// Creates a new `ByteBetween` instance with a given set of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes keys are made of, in any order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the provided byte set has fewer than two distinct bytes.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        let mut bytes = bytes;
+        bytes.sort_unstable();
+        bytes.dedup();
+        assert!(
+            bytes.len() >= 2,
+            "Expect bytes to have at least two distinct bytes."
+        );
+        let mut lookup = [None; 256];
+        for (index, byte) in bytes.iter().enumerate() {
+            lookup[*byte as usize] = Some(index as u8);
+        }
+        ByteBetween { bytes, lookup }
+    }
+
+    /// Initializes a `ByteBetween` instance with all 256 bytes.
+    pub fn full() -> Self {
+        ByteBetween::new((0..=u8::MAX).collect())
+    }
+
+    /// Returns the bytes keys are made of, in ascending order.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the lowest byte.
+    pub fn low(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    /// Returns the highest byte.
+    pub fn high(&self) -> u8 {
+        self.bytes[self.bytes.len() - 1]
+    }
+
+    /// Checks if a key is non-empty and made only of bytes of the set.
+    pub fn valid(&self, key: &[u8]) -> bool {
+        !key.is_empty() && key.iter().all(|byte| self.lookup[*byte as usize].is_some())
+    }
+
+    /// Finds a key that sorts between two given keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `&[]` for the start of the keyspace.
+    /// * `that` - The second key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<u8>>` that contains the between key if possible, or `None` if a key contains bytes
+    /// outside the set or `this` does not sort before `that`.
+    pub fn between(&self, this: &[u8], that: &[u8]) -> Option<Vec<u8>> {
+        let this = self.positions(this)?;
+        let that = self.positions(that)?;
+        let between = bisect_positions(self.bytes.len(), &this, &that)?;
+        Some(between.into_iter().map(|index| self.bytes[index]).collect())
+    }
+
+    /// Finds a key that sorts after a given key, or `&[]` for the start of the keyspace.
+    pub fn after(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.between(key, &[self.high()])
+    }
+
+    /// Finds a key that sorts before a given key.
+    pub fn before(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.between(&[self.low()], key)
+    }
+
+    /// Returns the positions of a key's bytes, without trailing low bytes.
+    fn positions(&self, key: &[u8]) -> Option<Positions> {
+        let end = key
+            .iter()
+            .rposition(|byte| *byte != self.low())
+            .map_or(0, |position| position + 1);
+        key[..end]
+            .iter()
+            .map(|byte| self.lookup[*byte as usize].map(usize::from))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ByteBetween;
+
+    #[test]
+    fn test_byte_between_full() {
+        let between = ByteBetween::full();
+        assert_eq!((between.low(), between.high()), (0x00, 0xff));
+
+        let mut keys = vec![between.after(&[]).unwrap()];
+        for _ in 0..100 {
+            let key = between.after(keys.last().unwrap()).unwrap();
+            keys.push(key);
+        }
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        let key = between.between(&keys[7], &keys[8]).unwrap();
+        assert!(keys[7] < key && key < keys[8]);
+
+        // Trailing zero bytes are ignored, and keys never end with one.
+        assert_eq!(
+            between.between(&[0x01, 0x00], &[0x02]).unwrap(),
+            vec![0x01, 0x80]
+        );
+        assert!(between.between(&[0x02], &[0x01]).is_none());
+        assert!(between.before(&[0x00, 0x01]).unwrap() < vec![0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_byte_between_subset() {
+        let between = ByteBetween::new(b"0123456789".to_vec());
+        assert_eq!(between.between(b"1", b"2").unwrap(), b"15");
+        assert!(between.valid(b"09"));
+        assert!(!between.valid(b"0a"));
+        assert!(between.between(b"1", b"a").is_none());
+    }
+}
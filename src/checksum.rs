@@ -0,0 +1,258 @@
+use core::error::Error;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// An error returned when a key read back by a [`Checksummed`] handle fails validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChecksumError {
+    /// The key is not a key followed by a separator and a check character, for example because it was
+    /// truncated.
+    Malformed,
+    /// The check character does not match the key, for example because a character was changed.
+    Mismatch {
+        /// The check character the key should end with.
+        expected: char,
+        /// The check character the key ends with.
+        actual: char,
+    },
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecksumError::Malformed => f.write_str("malformed checksummed key"),
+            ChecksumError::Mismatch { expected, actual } => write!(
+                f,
+                "checksum mismatch: expected {:?}, found {:?}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl Error for ChecksumError {}
+
+/// A handle that ends every generated key with a check character, so keys truncated or corrupted on their
+/// way back from clients are detected instead of silently sorting in the wrong place. Created with
+/// [`Between::with_checksum`].
+///
+/// A key is written as its body, the alphabet's lowest character as a separator, and a check character
+/// computed over the body with the Luhn mod N algorithm, which detects any single changed character and most
+/// swaps of adjacent ones (all of them for alphabets of an even number of characters, whose bodies use an
+/// odd number). Bodies are generated without the lowest character, so the separator sorts below
+/// any character that could follow it, and checksummed keys sort exactly like their bodies.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, ChecksumError};
+///
+/// let keys = Between::init().with_checksum().unwrap();
+/// let a = keys.after("").unwrap();
+/// let b = keys.after(&a).unwrap();
+/// assert!(a < b);
+/// assert!(keys.verify(&b));
+///
+/// let key = keys.between(&a, &b).unwrap();
+/// assert!(a < key && key < b);
+/// assert_eq!(keys.parse(&key[..key.len() - 1]), Err(ChecksumError::Malformed));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Checksummed {
+    between: Between,
+    separator: char,
+}
+
+impl Between {
+    /// Creates a handle that ends every generated key with a check character.
+    ///
+    /// The maximum length, if any, counts the separator and the check character.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Checksummed>` that contains the handle, or `None` if the alphabet has only two characters,
+    /// which leaves a single character to write bodies with.
+    pub fn with_checksum(self) -> Option<Checksummed> {
+        if self.alphabet.chars.len() < 3 {
+            return None;
+        }
+        let separator = self.low;
+        let max_len = self.max_len;
+        let mut between = self.into_builder().exclude([separator]).build().ok()?;
+        between.max_len = max_len.map(|max_len| max_len.saturating_sub(2));
+        Some(Checksummed { between, separator })
+    }
+}
+
+impl Checksummed {
+    /// Returns the `Between` instance that generates key bodies, whose alphabet lacks the separator.
+    pub fn inner(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the character that separates a key's body from its check character.
+    pub fn separator(&self) -> char {
+        self.separator
+    }
+
+    /// Ends a key body with the separator and its check character.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the checksummed key, or `None` if the body is not valid in the inner
+    /// alphabet.
+    pub fn checksummed(&self, body: &str) -> Option<String> {
+        let check = self.check(body)?;
+        let mut key = String::with_capacity(body.len() + 2);
+        key.push_str(body);
+        key.push(self.separator);
+        key.push(check);
+        Some(key)
+    }
+
+    /// Validates a checksummed key and returns its body.
+    ///
+    /// # Returns
+    ///
+    /// The body, or a [`ChecksumError`] if the key is malformed or its check character does not match.
+    pub fn parse<'a>(&self, key: &'a str) -> Result<&'a str, ChecksumError> {
+        let mut chars = key.char_indices().rev();
+        let (_, actual) = chars.next().ok_or(ChecksumError::Malformed)?;
+        let (end, separator) = chars.next().ok_or(ChecksumError::Malformed)?;
+        let body = &key[..end];
+        if separator != self.separator || !self.between.valid(body) {
+            return Err(ChecksumError::Malformed);
+        }
+        let expected = self.check(body).ok_or(ChecksumError::Malformed)?;
+        if actual != expected {
+            return Err(ChecksumError::Mismatch { expected, actual });
+        }
+        Ok(body)
+    }
+
+    /// Checks if a checksummed key is well-formed and its check character matches.
+    pub fn verify(&self, key: &str) -> bool {
+        self.parse(key).is_ok()
+    }
+
+    /// Finds a checksummed key between two given ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `""` for the start of the keyspace.
+    /// * `that` - The second key, or `""` for the end of the keyspace.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between key if possible, or `None` if a key fails validation or
+    /// `this` does not sort before `that`.
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        let this = if this.is_empty() {
+            ""
+        } else {
+            self.parse(this).ok()?
+        };
+        let body = if that.is_empty() {
+            self.between.after(this)?
+        } else {
+            self.between.between(this, self.parse(that).ok()?)?
+        };
+        self.checksummed(&body)
+    }
+
+    /// Finds a checksummed key that sorts after a given one, or `""` for the start of the keyspace.
+    pub fn after(&self, key: &str) -> Option<String> {
+        self.between(key, "")
+    }
+
+    /// Finds a checksummed key that sorts before a given one.
+    pub fn before(&self, key: &str) -> Option<String> {
+        self.between("", key)
+    }
+
+    /// Computes the check character of a body with the Luhn mod N algorithm.
+    ///
+    /// For an odd base, doubling modulo the base replaces Luhn's sum of digits, which is not a permutation then.
+    fn check(&self, body: &str) -> Option<char> {
+        let indices = self.between.indices(body)?;
+        let base = self.between.alphabet.chars.len();
+        let mut sum = 0;
+        for (position, index) in indices.iter().rev().enumerate() {
+            // Every other character is doubled, in a way that maps the alphabet onto itself: with Luhn's
+            // sum of digits for an even base, and modulo an odd one.
+            sum += match (position % 2, base % 2) {
+                (1, _) => *index,
+                (_, 0) => 2 * index / base + 2 * index % base,
+                _ => 2 * index % base,
+            };
+        }
+        Some(self.between.alphabet.chars[(base - sum % base) % base])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, ChecksumError};
+
+    #[test]
+    fn test_checksummed_keys_stay_ordered() {
+        let keys = Between::init().with_checksum().unwrap();
+        assert_eq!(keys.separator(), '!');
+
+        let mut list = vec![keys.after("").unwrap()];
+        for _ in 0..50 {
+            list.push(keys.after(list.last().unwrap()).unwrap());
+        }
+        let mut tweens: Vec<String> = list
+            .windows(2)
+            .map(|pair| keys.between(&pair[0], &pair[1]).unwrap())
+            .collect();
+        tweens.push(keys.before(&list[0]).unwrap());
+        list.extend(tweens);
+
+        let mut sorted = list.clone();
+        sorted.sort();
+        let bodies: Vec<&str> = sorted.iter().map(|key| keys.parse(key).unwrap()).collect();
+        assert!(bodies.windows(2).all(|pair| pair[0] < pair[1]));
+        // A body that is a prefix of another still sorts first.
+        assert!(keys.checksummed("A").unwrap() < keys.checksummed("A0").unwrap());
+    }
+
+    #[test]
+    fn test_checksum_detects_corruption() {
+        let keys = Between::base36().with_checksum().unwrap();
+        let key = keys.checksummed("hello").unwrap();
+        assert!(keys.verify(&key));
+
+        // Every single changed character is detected.
+        for position in 0..5 {
+            for c in keys.inner().chars() {
+                let mut corrupted: Vec<char> = key.chars().collect();
+                if corrupted[position] == *c {
+                    continue;
+                }
+                corrupted[position] = *c;
+                let corrupted: String = corrupted.into_iter().collect();
+                assert!(!keys.verify(&corrupted));
+            }
+        }
+        // With an odd number of characters in bodies, every swap of adjacent characters is detected too.
+        for position in 0..4 {
+            let mut swapped: Vec<char> = key.chars().collect();
+            if swapped[position] == swapped[position + 1] {
+                continue;
+            }
+            swapped.swap(position, position + 1);
+            let swapped: String = swapped.into_iter().collect();
+            assert!(matches!(
+                keys.parse(&swapped),
+                Err(ChecksumError::Mismatch { .. })
+            ));
+        }
+        assert_eq!(keys.parse("hel"), Err(ChecksumError::Malformed));
+        assert_eq!(keys.parse(""), Err(ChecksumError::Malformed));
+        assert!(Between::new(vec!['a', 'b']).with_checksum().is_none());
+    }
+}
@@ -0,0 +1,202 @@
+use core::cmp::Ordering;
+
+use crate::prelude::*;
+use crate::seeded::mix;
+use crate::Between;
+
+/// The longest keys [`Between::verify_collation`] checks exhaustively.
+const EXHAUSTIVE_LEN: usize = 2;
+
+/// The most keys [`Between::verify_collation`] checks exhaustively. Large alphabets, such as
+/// [`Between::cjk`] with about 21,000 characters, only have their 1-character keys checked exhaustively.
+const MAX_EXHAUSTIVE_KEYS: usize = 1_000_000;
+
+/// The number of longer keys [`Between::verify_collation`] samples.
+const SAMPLES: usize = 2000;
+
+/// The longest key [`Between::verify_collation`] samples.
+const SAMPLE_LEN: usize = 8;
+
+/// Two keys that a collation sorts differently from the strings' own order, found by
+/// [`Between::verify_collation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollationMismatch {
+    /// The key that sorts first as a Rust string.
+    pub lesser: String,
+    /// The key that sorts right after it as a Rust string.
+    pub greater: String,
+    /// How the collation compares `lesser` to `greater`, which is not `Ordering::Less`.
+    pub actual: Ordering,
+}
+
+impl Between {
+    /// Checks that keys of this alphabet sort the same under a caller-provided comparison, such as a
+    /// database collation, as they do as Rust strings.
+    ///
+    /// Every key of up to 2 characters is checked, along with 2000 pseudo-random keys of up to 8 characters.
+    /// Alphabets of more than 1000 characters, which have over a million 2-character keys, only have every
+    /// 1-character key checked. `cmp` is called once per key checked, so at most about a million times. See
+    /// [`Between::verify_collation_with`] to choose the bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp` - The comparison to check, for example one that asks the database to compare two strings.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<CollationMismatch>` that contains the counterexamples found, empty if the comparison agrees.
+    pub fn verify_collation<F>(&self, cmp: F) -> Vec<CollationMismatch>
+    where
+        F: Fn(&str, &str) -> Ordering,
+    {
+        let k = self.alphabet.chars.len();
+        let mut exhaustive_len = 1;
+        while exhaustive_len < EXHAUSTIVE_LEN
+            && k.checked_pow(exhaustive_len as u32 + 1)
+                .is_some_and(|keys| keys <= MAX_EXHAUSTIVE_KEYS)
+        {
+            exhaustive_len += 1;
+        }
+        self.verify_collation_with(cmp, exhaustive_len, SAMPLES, SAMPLE_LEN, 0)
+    }
+
+    /// Checks that keys of this alphabet sort the same under a caller-provided comparison as they do as Rust
+    /// strings, with chosen bounds.
+    ///
+    /// The keys are sorted as Rust strings and each one is compared to the next, so for a comparison that is
+    /// a total order, an empty result means it agrees on every key checked.
+    ///
+    /// The exhaustive keys are all held in memory, and there are `k^exhaustive_len` of them for an alphabet
+    /// of `k` characters, so keep `exhaustive_len` small for large alphabets.
+    ///
+    /// # Arguments
+    ///
+    /// * `cmp` - The comparison to check.
+    /// * `exhaustive_len` - Every key of up to this many characters is checked.
+    /// * `samples` - The number of pseudo-random keys to check as well.
+    /// * `sample_len` - The longest pseudo-random key.
+    /// * `seed` - The seed the pseudo-random keys are derived from.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<CollationMismatch>` that contains the counterexamples found, empty if the comparison agrees.
+    pub fn verify_collation_with<F>(
+        &self,
+        cmp: F,
+        exhaustive_len: usize,
+        samples: usize,
+        sample_len: usize,
+        seed: u64,
+    ) -> Vec<CollationMismatch>
+    where
+        F: Fn(&str, &str) -> Ordering,
+    {
+        let mut keys = vec![String::new()];
+        let mut shorter = vec![String::new()];
+        for _ in 0..exhaustive_len {
+            shorter = shorter
+                .iter()
+                .flat_map(|key| {
+                    self.alphabet.chars.iter().map(move |c| {
+                        let mut longer = key.clone();
+                        longer.push(*c);
+                        longer
+                    })
+                })
+                .collect();
+            keys.extend(shorter.iter().cloned());
+        }
+
+        let k = self.alphabet.chars.len() as u64;
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(1);
+            mix(state)
+        };
+        for _ in 0..if sample_len == 0 { 0 } else { samples } {
+            let len = 1 + (next() % sample_len as u64) as usize;
+            let key: String = (0..len)
+                .map(|_| self.alphabet.chars[(next() % k) as usize])
+                .collect();
+            keys.push(key);
+        }
+
+        // Generated keys never end with the lowest character.
+        keys.retain(|key| !key.is_empty() && !key.ends_with(self.low));
+        keys.sort();
+        keys.dedup();
+        keys.windows(2)
+            .filter_map(|pair| {
+                let actual = cmp(&pair[0], &pair[1]);
+                (actual != Ordering::Less).then(|| CollationMismatch {
+                    lesser: pair[0].clone(),
+                    greater: pair[1].clone(),
+                    actual,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::Between;
+
+    #[test]
+    fn test_verify_collation_agrees() {
+        let between = Between::init();
+        assert!(between.verify_collation(|a, b| a.cmp(b)).is_empty());
+        // Byte order is the same as character order.
+        assert!(between
+            .verify_collation(|a, b| a.as_bytes().cmp(b.as_bytes()))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_verify_collation_caps_large_alphabets() {
+        use std::cell::Cell;
+
+        let calls = Cell::new(0);
+        let between = Between::cjk();
+        let mismatches = between.verify_collation(|a, b| {
+            calls.set(calls.get() + 1);
+            a.cmp(b)
+        });
+        assert!(mismatches.is_empty());
+        assert!(calls.get() < between.chars().len() + 2000);
+
+        // Small alphabets are still checked up to 2 characters.
+        let calls = Cell::new(0);
+        Between::base10().verify_collation(|a, b| {
+            calls.set(calls.get() + 1);
+            a.cmp(b)
+        });
+        assert!(calls.get() >= 90);
+    }
+
+    #[test]
+    fn test_verify_collation_finds_counterexamples() {
+        // A case-insensitive collation sorts "a" with "A".
+        let between = Between::base62();
+        let mismatches = between.verify_collation(|a, b| a.to_lowercase().cmp(&b.to_lowercase()));
+        assert!(!mismatches.is_empty());
+        assert!(mismatches.iter().all(
+            |mismatch| mismatch.lesser < mismatch.greater && mismatch.actual != Ordering::Less
+        ));
+
+        // A collation that ignores punctuation, like many natural-language ones.
+        let between = Between::init();
+        let ignore = |s: &str| {
+            s.chars()
+                .filter(|c| c.is_alphanumeric())
+                .collect::<String>()
+        };
+        let mismatches =
+            between.verify_collation_with(|a, b| ignore(a).cmp(&ignore(b)), 1, 0, 0, 0);
+        assert!(mismatches.iter().any(|mismatch| mismatch.lesser == "Z"
+            && mismatch.greater == "_"
+            && mismatch.actual == Ordering::Greater));
+    }
+}
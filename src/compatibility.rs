@@ -0,0 +1,134 @@
+use crate::prelude::*;
+use crate::Between;
+
+/// How keys generated with one alphabet fare under another, as reported by [`Between::compatible_with`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Compatibility {
+    /// Both alphabets have the same characters.
+    Identical,
+    /// The other alphabet has every character of this one and more, so every key remains valid and sorts the
+    /// same. Widening an alphabet this way is safe.
+    Superset {
+        /// The characters only the other alphabet has, in ascending order.
+        added: Vec<char>,
+    },
+    /// The other alphabet lacks some characters of this one, so keys using them become invalid.
+    Subset {
+        /// The characters only this alphabet has, in ascending order.
+        removed: Vec<char>,
+    },
+    /// Each alphabet has characters the other lacks, so keys using the removed ones become invalid.
+    Incompatible {
+        /// The characters only the other alphabet has, in ascending order.
+        added: Vec<char>,
+        /// The characters only this alphabet has, in ascending order.
+        removed: Vec<char>,
+    },
+}
+
+impl Compatibility {
+    /// Returns `true` if every key generated with the first alphabet remains valid and sorts the same under the
+    /// second.
+    pub fn is_compatible(&self) -> bool {
+        matches!(
+            self,
+            Compatibility::Identical | Compatibility::Superset { .. }
+        )
+    }
+}
+
+impl Between {
+    /// Checks whether keys generated with this alphabet remain valid and identically ordered under another, for
+    /// example before widening an alphabet.
+    ///
+    /// Keys always sort by their characters, so a key that remains valid keeps its place among the others.
+    /// Since a superset cannot have a higher lowest character, no two keys become equal by ignoring trailing
+    /// low characters either.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The alphabet keys would be used with.
+    ///
+    /// # Returns
+    ///
+    /// A `Compatibility` that tells whether `other` has the same characters as this alphabet, more, fewer, or
+    /// some of each, and which ones differ.
+    pub fn compatible_with(&self, other: &Between) -> Compatibility {
+        let added: Vec<char> = other
+            .alphabet
+            .chars
+            .iter()
+            .filter(|c| self.position(**c).is_none())
+            .copied()
+            .collect();
+        let removed: Vec<char> = self
+            .alphabet
+            .chars
+            .iter()
+            .filter(|c| other.position(**c).is_none())
+            .copied()
+            .collect();
+        match (added.is_empty(), removed.is_empty()) {
+            (true, true) => Compatibility::Identical,
+            (false, true) => Compatibility::Superset { added },
+            (true, false) => Compatibility::Subset { removed },
+            (false, false) => Compatibility::Incompatible { added, removed },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, Compatibility};
+
+    #[test]
+    fn test_compatible_with() {
+        let base62 = Between::base62();
+        assert_eq!(
+            base62.compatible_with(&Between::base62()),
+            Compatibility::Identical
+        );
+
+        let widened = base62.compatible_with(&Between::init());
+        assert_eq!(
+            widened,
+            Compatibility::Superset {
+                added: vec!['!', '_', '~']
+            }
+        );
+        assert!(widened.is_compatible());
+
+        let narrowed = base62.compatible_with(&Between::base36());
+        assert!(matches!(&narrowed, Compatibility::Subset { removed } if removed.len() == 26));
+        assert!(!narrowed.is_compatible());
+
+        let crossed = Between::base16().compatible_with(
+            &Between::base10()
+                .into_builder()
+                .include(['x'])
+                .build()
+                .unwrap(),
+        );
+        assert_eq!(
+            crossed,
+            Compatibility::Incompatible {
+                added: vec!['x'],
+                removed: vec!['a', 'b', 'c', 'd', 'e', 'f']
+            }
+        );
+    }
+
+    #[test]
+    fn test_superset_keeps_keys_valid_and_ordered() {
+        let from = Between::base62();
+        let to = Between::init();
+        assert!(from.compatible_with(&to).is_compatible());
+
+        let keys: Vec<String> = from.after_iter("").take(100).collect();
+        assert!(keys.iter().all(|key| to.valid(key.as_str())));
+        for pair in keys.windows(2) {
+            let key = to.between(pair[0].as_str(), pair[1].as_str()).unwrap();
+            assert!(pair[0] < key && key < pair[1]);
+        }
+    }
+}
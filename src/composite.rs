@@ -0,0 +1,215 @@
+use crate::prelude::*;
+use crate::Between;
+
+/// A key made of several ordered segments, such as board / column / card, each drawn from its own alphabet.
+///
+/// Composite keys compare segment by segment: the first segment decides, and later segments only break ties.
+/// Segments are stored without trailing lowest characters, so comparing them as strings follows each
+/// segment's alphabet. Composite keys are created and generated by a [`CompositeBetween`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CompositeKey(Vec<String>);
+
+impl CompositeKey {
+    /// Returns the segments of the key, from most to least significant.
+    pub fn segments(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Consumes the key, returning its segments.
+    pub fn into_segments(self) -> Vec<String> {
+        self.0
+    }
+}
+
+/// Generates [`CompositeKey`]s whose segments each use their own [`Between`].
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, CompositeBetween};
+///
+/// let boards = Between::new(('a'..='z').collect());
+/// let cards = Between::init();
+/// let composite = CompositeBetween::new(vec![boards, cards]);
+///
+/// let first = composite.key(&["b", "A"]).unwrap();
+/// let second = composite.after(&first).unwrap();
+/// let other_board = composite.key(&["c", "A"]).unwrap();
+/// assert!(first < second && second < other_board);
+/// assert_eq!(second.segments()[0], "b");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CompositeBetween {
+    segments: Vec<Between>,
+}
+
+impl CompositeBetween {
+    /// Creates a generator for keys with one segment per `Between` instance, from most to least significant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segments` is empty.
+    pub fn new(segments: Vec<Between>) -> Self {
+        assert!(
+            !segments.is_empty(),
+            "Expect at least one segment for a composite key."
+        );
+        CompositeBetween { segments }
+    }
+
+    /// Returns the `Between` instances of the segments, from most to least significant.
+    pub fn segments(&self) -> &[Between] {
+        &self.segments
+    }
+
+    /// Creates a composite key from its segments.
+    ///
+    /// # Arguments
+    ///
+    /// * `segments` - The segments, from most to least significant.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<CompositeKey>` that contains the key, or `None` if the number of segments is wrong or a
+    /// segment is empty or contains characters outside its alphabet.
+    pub fn key<S>(&self, segments: &[S]) -> Option<CompositeKey>
+    where
+        S: AsRef<str>,
+    {
+        if segments.len() != self.segments.len() {
+            return None;
+        }
+        self.segments
+            .iter()
+            .zip(segments)
+            .map(|(between, segment)| {
+                let segment = segment.as_ref().trim_end_matches(between.low());
+                if segment.is_empty() || !between.valid(segment) {
+                    None
+                } else {
+                    Some(segment.to_string())
+                }
+            })
+            .collect::<Option<Vec<String>>>()
+            .map(CompositeKey)
+    }
+
+    /// Finds a composite key that sorts right after the given one, changing only its last segment.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<CompositeKey>` that contains the new key, or `None` if `key` does not belong to this
+    /// generator or its last segment has nothing after it.
+    pub fn after(&self, key: &CompositeKey) -> Option<CompositeKey> {
+        self.with_last(key, |between, last| between.after(last))
+    }
+
+    /// Finds a composite key that sorts right before the given one, changing only its last segment.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<CompositeKey>` that contains the new key, or `None` if `key` does not belong to this
+    /// generator or its last segment has nothing before it.
+    pub fn before(&self, key: &CompositeKey) -> Option<CompositeKey> {
+        self.with_last(key, |between, last| between.before(last))
+    }
+
+    /// Finds a composite key that sorts strictly between two given ones.
+    ///
+    /// If the keys differ only in their last segment, the new key shares their other segments. Otherwise it
+    /// shares every segment but the last with `this` (or, if there is no room there, with `that`), so a new
+    /// card lands in an existing column rather than in a new one.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<CompositeKey>` that contains the new key, or `None` if a key does not belong to this
+    /// generator, `this` does not sort before `that`, or there is no room between them.
+    pub fn between(&self, this: &CompositeKey, that: &CompositeKey) -> Option<CompositeKey> {
+        if !self.owns(this) || !self.owns(that) || this >= that {
+            return None;
+        }
+        let last = self.segments.len() - 1;
+        if this.0[..last] == that.0[..last] {
+            let segment =
+                self.segments[last].between(this.0[last].as_str(), that.0[last].as_str())?;
+            let mut segments = this.0.clone();
+            segments[last] = segment;
+            return Some(CompositeKey(segments));
+        }
+        self.after(this).or_else(|| self.before(that))
+    }
+
+    fn with_last<F>(&self, key: &CompositeKey, generate: F) -> Option<CompositeKey>
+    where
+        F: FnOnce(&Between, &str) -> Option<String>,
+    {
+        if !self.owns(key) {
+            return None;
+        }
+        let last = self.segments.len() - 1;
+        let segment = generate(&self.segments[last], &key.0[last])?;
+        let mut segments = key.0.clone();
+        segments[last] = segment;
+        Some(CompositeKey(segments))
+    }
+
+    /// Returns `true` if `key` has one valid segment per `Between` instance.
+    fn owns(&self, key: &CompositeKey) -> bool {
+        key.0.len() == self.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(&key.0)
+                .all(|(between, segment)| between.valid(segment.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, CompositeBetween};
+
+    fn composite() -> CompositeBetween {
+        CompositeBetween::new(vec![
+            Between::new(('a'..='z').collect()),
+            Between::new(vec!['0', '1', '2', '3']),
+            Between::init(),
+        ])
+    }
+
+    #[test]
+    fn test_composite_key() {
+        let composite = composite();
+
+        let key = composite.key(&["ba", "10", "A!"]).unwrap();
+        assert_eq!(key.segments(), &["b", "1", "A"]);
+        assert!(composite.key(&["b", "1"]).is_none());
+        assert!(composite.key(&["b", "4", "A"]).is_none());
+        assert!(composite.key(&["b", "0", "A"]).is_none());
+
+        // The first segment decides, whatever the later ones are.
+        let low = composite.key(&["b", "3", "z"]).unwrap();
+        let high = composite.key(&["c", "1", "0"]).unwrap();
+        assert!(low < high);
+    }
+
+    #[test]
+    fn test_composite_between() {
+        let composite = composite();
+        let a = composite.key(&["b", "1", "A"]).unwrap();
+        let b = composite.key(&["b", "1", "B"]).unwrap();
+        let c = composite.key(&["c", "2", "A"]).unwrap();
+
+        let tween = composite.between(&a, &b).unwrap();
+        assert_eq!(tween.segments(), &["b", "1", "AV"]);
+
+        // Across columns, the new key stays in the first one.
+        let tween = composite.between(&b, &c).unwrap();
+        assert!(b < tween && tween < c);
+        assert_eq!(&tween.segments()[..2], &["b", "1"]);
+
+        let before = composite.before(&a).unwrap();
+        assert!(before < a);
+        assert!(composite.between(&b, &a).is_none());
+        assert!(composite.between(&a, &a).is_none());
+    }
+}
@@ -0,0 +1,103 @@
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::Text;
+
+use crate::Key;
+
+impl<DB> ToSql<Text, DB> for Key
+where
+    DB: Backend,
+    str: ToSql<Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        <str as ToSql<Text, DB>>::to_sql(self.as_str(), out)
+    }
+}
+
+/// Reads a `Key` from a `Text` column as-is. Use [`Key::validate`] to check it against an alphabet.
+impl<DB> FromSql<Text, DB> for Key
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        String::from_sql(bytes).map(Key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use diesel::prelude::*;
+    use diesel::sql_types::Text;
+    use diesel::sqlite::SqliteConnection;
+
+    use crate::{Between, Key};
+
+    diesel::table! {
+        items (id) {
+            id -> Integer,
+            position -> Text,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = items)]
+    struct Item {
+        id: i32,
+        position: Key,
+    }
+
+    #[test]
+    fn test_round_trip_in_order() {
+        let mut connection = SqliteConnection::establish(":memory:").unwrap();
+        diesel::sql_query("CREATE TABLE items (id INTEGER PRIMARY KEY, position TEXT NOT NULL)")
+            .execute(&mut connection)
+            .unwrap();
+
+        let between = Between::base10();
+        let keys: Vec<Key> = between
+            .spread(5)
+            .into_iter()
+            .map(|key| Key::new(key, &between).unwrap())
+            .collect();
+        let rows: Vec<Item> = keys
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(id, key)| Item {
+                id: id as i32,
+                position: key.clone(),
+            })
+            .collect();
+        diesel::insert_into(items::table)
+            .values(&rows)
+            .execute(&mut connection)
+            .unwrap();
+
+        let read: Vec<Key> = items::table
+            .select(items::position)
+            .order(items::position)
+            .load(&mut connection)
+            .unwrap();
+        assert_eq!(read, keys);
+
+        let first = items::table
+            .filter(items::position.eq(&keys[0]))
+            .select(items::id)
+            .first::<i32>(&mut connection)
+            .unwrap();
+        assert_eq!(first, 4);
+        let key: Key = diesel::select(diesel::dsl::sql::<Text>("'42'"))
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(key.as_str(), "42");
+
+        // Keys are decoded as-is, whatever their alphabet.
+        let key: Key = diesel::select(diesel::dsl::sql::<Text>("'4$'"))
+            .get_result(&mut connection)
+            .unwrap();
+        assert_eq!(key.as_str(), "4$");
+        assert!(key.validate(&between).is_err());
+    }
+}
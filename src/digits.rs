@@ -0,0 +1,153 @@
+use num_bigint::BigUint;
+use num_traits::{ToPrimitive, Zero};
+
+use crate::prelude::*;
+use crate::Between;
+
+// Helpers for treating keys as numbers.
+//
+// A key with character positions `d1 d2 ... dn` (indices into the sorted alphabet of size `k`) represents
+// the fraction `d1/k + d2/k^2 + ... + dn/k^n` in `[0, 1)`. Keys compare exactly like their fractions, and
+// trailing low characters (index 0) do not change the value, which is why `between` trims them.
+//
+// Scaling a key to `len` digits multiplies its fraction by `k^len`, giving an integer when the key has at most
+// `len` characters.
+impl Between {
+    /// Returns the alphabet positions of the characters of `key`, or `None` if a character is not in the alphabet.
+    pub(crate) fn indices(&self, key: &str) -> Option<Vec<usize>> {
+        key.chars().map(|c| self.position(c)).collect()
+    }
+
+    /// Builds a key from alphabet positions, dropping trailing low characters.
+    pub(crate) fn key_from_indices(&self, indices: &[usize]) -> String {
+        let end = indices
+            .iter()
+            .rposition(|index| *index != 0)
+            .map_or(0, |position| position + 1);
+        indices[..end]
+            .iter()
+            .map(|index| self.alphabet.chars[*index])
+            .collect()
+    }
+
+    /// Trims and validates a pair of bounds the same way `between` does.
+    ///
+    /// Returns the alphabet positions of both bounds if `this < that`, `that` is valid and `this` is empty or valid.
+    pub(crate) fn bounds(&self, this: &str, that: &str) -> Option<(Vec<usize>, Vec<usize>)> {
+        let this = this.trim_end_matches(self.low);
+        let that = that.trim_end_matches(self.low);
+        if this >= that || that.is_empty() {
+            return None;
+        }
+        Some((self.indices(this)?, self.indices(that)?))
+    }
+
+    /// Trims and validates a list of keys, returning their alphabet positions if every key is valid and the
+    /// list is in strictly ascending order.
+    pub(crate) fn sorted_indices<S>(&self, keys: &[S]) -> Option<Vec<Vec<usize>>>
+    where
+        S: AsRef<str>,
+    {
+        let mut sorted: Vec<Vec<usize>> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key.as_ref();
+            if !self.valid(key) {
+                return None;
+            }
+            sorted.push(self.indices(key.trim_end_matches(self.low))?);
+        }
+        if sorted.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return None;
+        }
+        Some(sorted)
+    }
+
+    /// Returns the value of a key scaled to `len` digits, truncating any digits beyond `len`.
+    pub(crate) fn scaled(&self, indices: &[usize], len: usize) -> BigUint {
+        let base = BigUint::from(self.alphabet.chars.len());
+        (0..len).fold(BigUint::zero(), |value, position| {
+            value * &base + indices.get(position).copied().unwrap_or(0)
+        })
+    }
+
+    /// Returns the value of a key scaled to `len` digits, rounding up if it has more than `len` characters.
+    pub(crate) fn scaled_ceil(&self, indices: &[usize], len: usize) -> BigUint {
+        let value = self.scaled(indices, len);
+        if indices.iter().skip(len).any(|index| *index != 0) {
+            value + 1u32
+        } else {
+            value
+        }
+    }
+
+    /// Converts a value scaled to `len` digits back into exactly `len` alphabet positions.
+    ///
+    /// The value must be less than `k^len`.
+    pub(crate) fn unscaled(&self, value: &BigUint, len: usize) -> Vec<usize> {
+        let base = BigUint::from(self.alphabet.chars.len());
+        let mut value = value.clone();
+        let mut indices = vec![0; len];
+        for position in (0..len).rev() {
+            indices[position] = (&value % &base).to_usize().unwrap();
+            value /= &base;
+        }
+        indices
+    }
+
+    /// Returns the number of characters needed to write any value of `bits` bits.
+    pub(crate) fn fixed_width(&self, bits: u32) -> usize {
+        let base = BigUint::from(self.alphabet.chars.len());
+        let limit = BigUint::from(1u32) << bits;
+        let mut width = 1;
+        while base.pow(width as u32) < limit {
+            width += 1;
+        }
+        width
+    }
+
+    /// Writes a value as exactly `width` characters, padded with leading low characters.
+    ///
+    /// The value must be less than `k^width`.
+    pub(crate) fn fixed_digits(&self, value: u64, width: usize) -> String {
+        self.unscaled(&BigUint::from(value), width)
+            .iter()
+            .map(|index| self.alphabet.chars[*index])
+            .collect()
+    }
+
+    /// Finds a key between two keys that starts with the first `width` characters of `this`, for keys that
+    /// start with a fixed-width field such as a timestamp.
+    ///
+    /// Bisecting keys whose fields differ can give a key shorter than the field, so the new key is kept
+    /// below the next value of the field instead. `this` must have at least `width` characters.
+    pub(crate) fn between_in_field(&self, this: &str, that: &str, width: usize) -> Option<String> {
+        self.bounds(this, that)?;
+        let field: String = this.chars().take(width).collect();
+        // Keys with the same field are bisected as they are; otherwise the next field sorts at or before `that`.
+        let upper = match self.nth_after(field, 1, width) {
+            Some(next) if next.as_str() < that => next,
+            _ => that.to_string(),
+        };
+        self.between(this, upper)
+    }
+
+    /// Finds the shortest length at which at least `slots` values lie strictly between two bounds.
+    ///
+    /// Returns that length and both bounds scaled to it, rounding `that` up.
+    pub(crate) fn gap_holding(
+        &self,
+        this: &[usize],
+        that: &[usize],
+        slots: &BigUint,
+    ) -> (usize, BigUint, BigUint) {
+        let mut len = 1;
+        loop {
+            let low = self.scaled(this, len);
+            let high = self.scaled_ceil(that, len);
+            if &high - &low > *slots {
+                return (len, low, high);
+            }
+            len += 1;
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::Between;
+
+impl Between {
+    /// Gives distinct keys to items that ended up with the same key, e.g. because two writers inserted at the
+    /// same position at the same time.
+    ///
+    /// In each run of equal keys (including keys that differ only in trailing lowest characters), the first
+    /// item keeps its key and the others get the lowest keys above it at the shortest length that fits them,
+    /// so they stay as close as possible to where they were. The result depends only on the input.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The keys, in non-descending order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains a strictly ascending key for each item, or `None` if a key is
+    /// invalid, the keys are out of order, or a run of duplicates has no room below the next key (or below the
+    /// highest character, at the end of the list).
+    pub fn resolve_duplicates<S>(&self, keys: &[S]) -> Option<Vec<String>>
+    where
+        S: AsRef<str>,
+    {
+        let mut indices: Vec<Vec<usize>> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key.as_ref();
+            if !self.valid(key) {
+                return None;
+            }
+            indices.push(self.indices(key.trim_end_matches(self.low))?);
+        }
+        if indices.windows(2).any(|pair| pair[0] > pair[1]) {
+            return None;
+        }
+
+        let high = [self.alphabet.chars.len() - 1];
+        let mut resolved = Vec::with_capacity(keys.len());
+        let mut start = 0;
+        while start < keys.len() {
+            let end = (start..keys.len())
+                .find(|index| indices[*index] != indices[start])
+                .unwrap_or(keys.len());
+            resolved.push(keys[start].as_ref().to_string());
+
+            let duplicates = end - start - 1;
+            if duplicates > 0 {
+                let next = indices.get(end).map_or(&high[..], |next| &next[..]);
+                if indices[start][..] >= *next {
+                    return None;
+                }
+                let (len, low, _) =
+                    self.gap_holding(&indices[start], next, &BigUint::from(duplicates));
+                resolved
+                    .extend((1..=duplicates).map(|offset| {
+                        self.key_from_indices(&self.unscaled(&(&low + offset), len))
+                    }));
+            }
+            start = end;
+        }
+        Some(resolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_resolve_duplicates() {
+        let between = Between::init();
+
+        assert_eq!(
+            between
+                .resolve_duplicates(&["A", "B", "B", "B!", "C"])
+                .unwrap(),
+            vec!["A", "B", "B0", "B1", "C"]
+        );
+        assert_eq!(
+            between.resolve_duplicates(&["A", "B"]).unwrap(),
+            vec!["A", "B"]
+        );
+
+        let keys = vec!["V"; 100];
+        let resolved = between.resolve_duplicates(&keys).unwrap();
+        assert_eq!(resolved[0], "V");
+        assert!(resolved.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(resolved.iter().all(|key| key.as_str() < "~"));
+        assert!(resolved.iter().all(|key| key.len() <= 2));
+    }
+
+    #[test]
+    fn test_resolve_duplicates_invalid() {
+        let between = Between::init();
+
+        assert!(between.resolve_duplicates(&["B", "A"]).is_none());
+        assert!(between.resolve_duplicates(&["A", "$"]).is_none());
+        assert!(between.resolve_duplicates(&["~", "~"]).is_none());
+        assert!(between.resolve_duplicates::<&str>(&[]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// The number of bits used to encode a single character in [`Between::encode_key`].
+    ///
+    /// Each character is stored as its index in the alphabet plus one, leaving zero free for padding.
+    fn bits_per_char(&self) -> u32 {
+        usize::BITS - self.alphabet.chars.len().leading_zeros()
+    }
+
+    /// Encodes a key into a compact byte string whose byte-wise (`memcmp`) order matches the key order.
+    ///
+    /// Every character is replaced by its position in the alphabet and bit-packed using the fewest
+    /// bits that can represent the alphabet, so small alphabets use less than a byte per character.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to encode.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<u8>>` that contains the encoded key, or `None` if it contains characters
+    /// outside the alphabet.
+    pub fn encode_key<S>(&self, key: S) -> Option<Vec<u8>>
+    where
+        S: AsKey,
+    {
+        let key = key.as_key();
+        let bits = self.bits_per_char();
+
+        let mut bytes: Vec<u8> = Vec::with_capacity((key.len() * bits as usize).div_ceil(8));
+        // Bits that have not yet been flushed into 'bytes', right-aligned in 'buffer'.
+        let mut buffer: u64 = 0;
+        let mut buffered: u32 = 0;
+
+        for c in key.chars() {
+            let code = self.position(c)? as u64 + 1;
+            buffer = (buffer << bits) | code;
+            buffered += bits;
+            while buffered >= 8 {
+                buffered -= 8;
+                bytes.push((buffer >> buffered) as u8);
+            }
+            buffer &= (1 << buffered) - 1;
+        }
+
+        // Pad the last byte with zero bits, which sort before every character code.
+        if buffered > 0 {
+            bytes.push((buffer << (8 - buffered)) as u8);
+        }
+
+        Some(bytes)
+    }
+
+    /// Decodes a byte string produced by [`Between::encode_key`] back into a key.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The encoded key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the decoded key, or `None` if `bytes` is not a valid
+    /// encoding for this alphabet.
+    pub fn decode_key(&self, bytes: &[u8]) -> Option<String> {
+        let bits = self.bits_per_char();
+
+        let mut key = String::new();
+        let mut buffer: u64 = 0;
+        let mut buffered: u32 = 0;
+
+        for byte in bytes {
+            buffer = (buffer << 8) | *byte as u64;
+            buffered += 8;
+            while buffered >= bits {
+                buffered -= bits;
+                let code = (buffer >> buffered) as usize;
+                buffer &= (1 << buffered) - 1;
+                if code == 0 {
+                    // Padding; only valid as the tail of the last byte.
+                    break;
+                }
+                key.push(*self.alphabet.chars.get(code - 1)?);
+            }
+        }
+
+        // Reject non-canonical encodings such as stray padding or trailing garbage bits.
+        if self.encode_key(key.as_str())?.as_slice() != bytes {
+            return None;
+        }
+
+        Some(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_round_trip() {
+        let between = Between::init();
+        for key in &["", "!", "V", "AV", "~~~~", "0~~0", "!!!!V"] {
+            let bytes = between.encode_key(*key).unwrap();
+            assert_eq!(between.decode_key(&bytes).unwrap(), *key);
+        }
+
+        assert!(between.encode_key("a$b").is_none());
+    }
+
+    #[test]
+    fn test_bit_packing() {
+        // Three characters need two bits each, so four characters fit in one byte.
+        let between = Between::new(vec!['a', 'b', 'c']);
+        assert_eq!(between.encode_key("abca").unwrap(), vec![0b0110_1101]);
+        assert_eq!(between.encode_key("c").unwrap(), vec![0b1100_0000]);
+
+        // The default alphabet has 65 characters and needs seven bits each.
+        let between = Between::init();
+        assert_eq!(between.encode_key("VVVVVVVV").unwrap().len(), 7);
+    }
+
+    #[test]
+    fn test_byte_order_matches_key_order() {
+        let between = Between::new(vec!['a', 'b', 'c']);
+        let mut keys: Vec<String> = vec![];
+        for a in &["", "a", "b", "c"] {
+            for b in &["", "a", "b", "c"] {
+                for c in &["", "a", "b", "c"] {
+                    keys.push(format!("{}{}{}", a, b, c));
+                }
+            }
+        }
+        keys.sort();
+        keys.dedup();
+
+        for pair in keys.windows(2) {
+            let left = between.encode_key(pair[0].as_str()).unwrap();
+            let right = between.encode_key(pair[1].as_str()).unwrap();
+            assert!(left < right, "{:?} < {:?}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_encodings() {
+        let between = Between::new(vec!['a', 'b', 'c', 'd']);
+
+        // Four characters need three bits each, leaving codes 5 to 7 unused.
+        assert!(between.decode_key(&[0b1110_0000]).is_none());
+        // Characters after the padding.
+        assert!(between.decode_key(&[0b0010_0000, 0b0100_0000]).is_none());
+        // Non-zero bits in the padding.
+        assert!(between.decode_key(&[0b0010_0001]).is_none());
+        // A whole padding byte.
+        assert!(between.decode_key(&[0b0010_0000, 0]).is_none());
+    }
+}
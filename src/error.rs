@@ -0,0 +1,53 @@
+use core::error::Error;
+use core::fmt;
+
+use crate::prelude::*;
+
+/// An error returned when no string can be generated between two given strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BetweenError {
+    /// The bounds were invalid: not in ascending order, or containing characters outside the alphabet.
+    InvalidBounds,
+    /// No string between the bounds can be generated: every one is longer than the configured maximum length,
+    /// or contains a forbidden substring.
+    ///
+    /// The gap is used up, so the surrounding keys need rebalancing before anything can be inserted here.
+    KeyspaceExhausted {
+        /// The lower bound.
+        this: String,
+        /// The upper bound.
+        that: String,
+        /// The maximum length, counted in characters, or `None` if there is no limit.
+        max_len: Option<usize>,
+    },
+    /// The generated key is already held by an entry, which inserting would replace.
+    Collision {
+        /// The colliding key.
+        key: String,
+    },
+}
+
+impl fmt::Display for BetweenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BetweenError::InvalidBounds => f.write_str("invalid bounds"),
+            BetweenError::KeyspaceExhausted {
+                this,
+                that,
+                max_len: Some(max_len),
+            } => write!(
+                f,
+                "no key of at most {} characters between {:?} and {:?}",
+                max_len, this, that
+            ),
+            BetweenError::KeyspaceExhausted {
+                this,
+                that,
+                max_len: None,
+            } => write!(f, "no key between {:?} and {:?}", this, that),
+            BetweenError::Collision { key } => write!(f, "key {:?} is already in use", key),
+        }
+    }
+}
+
+impl Error for BetweenError {}
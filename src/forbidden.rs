@@ -0,0 +1,161 @@
+use crate::prelude::*;
+use crate::Between;
+
+/// The most gaps searched for a key free of forbidden substrings before giving up.
+const MAX_ATTEMPTS: usize = 256;
+
+impl Between {
+    /// Forbids substrings, such as profanity or reserved tokens like `..`, from appearing in generated
+    /// strings.
+    ///
+    /// [`Between::between`], [`Between::after`] and [`Between::before`], and the APIs built on them, route around
+    /// forbidden substrings: when the strategy picks a string containing one, every string sharing the prefix
+    /// up to it is skipped, and the search continues in the gaps on either side. The result still sorts
+    /// strictly between the bounds. Strings generated arithmetically, such as those of
+    /// [`Between::n_between`], are not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `substrings` - The substrings to forbid. Empty substrings are ignored.
+    pub fn with_forbidden<I, S>(mut self, substrings: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden = substrings
+            .into_iter()
+            .map(Into::into)
+            .filter(|substring: &String| !substring.is_empty())
+            .collect();
+        self
+    }
+
+    /// Returns the substrings generated strings must not contain.
+    pub fn forbidden(&self) -> &[String] {
+        &self.forbidden
+    }
+
+    /// Checks if a string contains a forbidden substring.
+    pub fn contains_forbidden(&self, string: &str) -> bool {
+        self.forbidden_end(string).is_some()
+    }
+
+    /// Returns the end, in bytes, of the earliest-ending forbidden substring of a string.
+    fn forbidden_end(&self, string: &str) -> Option<usize> {
+        self.forbidden
+            .iter()
+            .filter_map(|substring| {
+                string
+                    .find(substring.as_str())
+                    .map(|start| start + substring.len())
+            })
+            .min()
+    }
+
+    /// Finds a string between two bounds that contains no forbidden substring, starting from a candidate
+    /// picked by `generate`.
+    pub(crate) fn avoid_forbidden<F>(
+        &self,
+        this: &str,
+        that: &str,
+        candidate: String,
+        generate: F,
+    ) -> Option<String>
+    where
+        F: Fn(&str, &str) -> Option<String>,
+    {
+        // Gaps left to search, as open intervals; the flag marks a lower bound that is a candidate itself.
+        let mut gaps = vec![(this.to_string(), that.to_string(), false)];
+        let mut candidate = Some(candidate);
+        for _ in 0..MAX_ATTEMPTS {
+            let (lower, upper, inclusive) = gaps.pop()?;
+            let key = match candidate.take() {
+                Some(key) => key,
+                None if inclusive && !self.contains_forbidden(&lower) => return Some(lower),
+                None => match generate(&lower, &upper) {
+                    Some(key) => key,
+                    None => continue,
+                },
+            };
+            let end = match self.forbidden_end(&key) {
+                Some(end) => end,
+                None => return Some(key),
+            };
+
+            // Every string starting with 'prefix' contains the forbidden substring, so search the gaps
+            // before and after all of them, the one before first.
+            let prefix = key[..end].to_string();
+            if let Some(next) = self.successor_of_prefix(&prefix) {
+                if next < upper {
+                    gaps.push((next, upper, true));
+                }
+            }
+            if lower < prefix {
+                gaps.push((lower, prefix, false));
+            }
+        }
+        None
+    }
+
+    /// Returns the smallest string greater than every string starting with `prefix`, or `None` if there is
+    /// none because `prefix` is made of high characters.
+    fn successor_of_prefix(&self, prefix: &str) -> Option<String> {
+        let mut chars: Vec<char> = prefix.chars().collect();
+        while let Some(last) = chars.pop() {
+            let position = self.position(last)?;
+            if position + 1 < self.alphabet.chars.len() {
+                chars.push(self.alphabet.chars[position + 1]);
+                return Some(chars.into_iter().collect());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, PreferShort};
+
+    #[test]
+    fn test_with_forbidden() {
+        let between = Between::base10().with_forbidden(["5", ""]);
+        assert_eq!(between.forbidden(), ["5"]);
+        assert_eq!(between.between("4", "6").unwrap(), "43");
+        assert!(between.contains_forbidden("151"));
+
+        let between = Between::init().with_forbidden(["V"]);
+        let key = between.after("").unwrap();
+        assert!(!key.contains('V'));
+
+        // No string of 'a' and 'b' avoids 'b' without ending in the low character.
+        let between = Between::new(vec!['a', 'b']).with_forbidden(["b"]);
+        assert!(between.after("").is_none());
+    }
+
+    #[test]
+    fn test_forbidden_keys_stay_ordered() {
+        for between in [
+            Between::init().with_forbidden(["..", "V", "ab", "k"]),
+            Between::base10()
+                .with_strategy(PreferShort)
+                .with_forbidden(["5", "00"]),
+        ] {
+            let mut keys: Vec<String> = Vec::new();
+            for _ in 0..100 {
+                let key = between
+                    .after(keys.last().map_or("", String::as_str))
+                    .unwrap();
+                keys.push(key);
+            }
+            for index in 0..99 {
+                let key = between
+                    .between(keys[index].as_str(), keys[index + 1].as_str())
+                    .unwrap();
+                assert!(keys[index] < key && key < keys[index + 1]);
+                assert!(!between.contains_forbidden(&key));
+            }
+            assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(keys.iter().all(|key| !between.contains_forbidden(key)));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use num_bigint::BigUint;
+use num_traits::{Float, One, ToPrimitive};
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Finds a string that sits at a given fraction of the way between two given strings.
+    ///
+    /// Where [`Between::between`] always aims for the midpoint, this aims for the point `t` of the way from
+    /// `this` to `that`. A small `t` keeps new keys close to `this` and leaves most of the gap free above them,
+    /// which suits append-mostly lists; a large `t` does the opposite.
+    ///
+    /// The result is the string closest to that point at the shortest length where the closest string lies
+    /// strictly inside the gap, so short strings only approximate `t`.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `t` - The fraction of the way from `this` to `that`, strictly between 0 and 1.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if `t` is not strictly
+    /// between 0 and 1, or if the bounds are invalid under the same rules as [`Between::between`].
+    pub fn key_at_fraction<S, T>(&self, this: S, that: T, t: f64) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        if !(t > 0.0 && t < 1.0) {
+            return None;
+        }
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+
+        // Every float in (0, 1) is exactly 'mantissa / 2^-exponent' with a negative exponent.
+        let (mantissa, exponent, _) = t.integer_decode();
+        let numerator = BigUint::from(mantissa);
+        let denominator = BigUint::one() << (-i32::from(exponent)) as usize;
+
+        let mut len = 1;
+        loop {
+            let low = self.scaled(&this, len);
+            let high = self.scaled_ceil(&that, len);
+            let span = &high - &low;
+            // Round 'span * t' to the nearest integer, with halves rounding up like 'between' does.
+            let offset = (span * &numerator * 2u32 + &denominator) / (&denominator * 2u32);
+            let value = &low + offset;
+            if value > low && value < high {
+                return Some(self.key_from_indices(&self.unscaled(&value, len)));
+            }
+            len += 1;
+        }
+    }
+
+    /// Estimates where a string sits between two given strings, as a fraction of the way from one to the other.
+    ///
+    /// This is the inverse of [`Between::key_at_fraction`], useful for rendering scroll positions or for
+    /// noticing that part of a list is getting crowded. The result is computed exactly and then rounded to
+    /// the nearest `f64`, so strings that are very close together may map to the same fraction.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The string to locate.
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<f64>` that contains the fraction, between 0 and 1, or `None` if `key` does not sort strictly
+    /// between `this` and `that`, or if any of the strings are invalid under the same rules as
+    /// [`Between::between`].
+    pub fn fraction_of<K, S, T>(&self, key: K, this: S, that: T) -> Option<f64>
+    where
+        K: AsKey,
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        let key = key.as_key();
+        let key = self.indices(key.trim_end_matches(self.low))?;
+        if key <= this || key >= that {
+            return None;
+        }
+
+        let len = this.len().max(that.len()).max(key.len());
+        let low = self.scaled(&this, len);
+        let mut offset = self.scaled(&key, len) - &low;
+        let mut span = self.scaled(&that, len) - low;
+        // Drop low bits that an 'f64' could not represent anyway, so both values fit.
+        let excess = span.bits().saturating_sub(f64::MANTISSA_DIGITS as u64 + 1);
+        offset >>= excess;
+        span >>= excess;
+        Some(offset.to_f64()? / span.to_f64()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_key_at_fraction() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.key_at_fraction("1", "3", 0.5).unwrap(), "2");
+        assert_eq!(between.key_at_fraction("1", "2", 0.25).unwrap(), "13");
+        assert_eq!(between.key_at_fraction("1", "2", 0.75).unwrap(), "18");
+        // The closest string at length one would be "3" itself, so a longer string is needed.
+        assert_eq!(between.key_at_fraction("1", "3", 0.9).unwrap(), "28");
+        assert_eq!(between.key_at_fraction("", "1", 0.001).unwrap(), "0001");
+
+        assert!(between.key_at_fraction("1", "3", 0.0).is_none());
+        assert!(between.key_at_fraction("1", "3", 1.0).is_none());
+        assert!(between.key_at_fraction("1", "3", f64::NAN).is_none());
+        assert!(between.key_at_fraction("3", "1", 0.5).is_none());
+    }
+
+    #[test]
+    fn test_fraction_of() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.fraction_of("2", "1", "3").unwrap(), 0.5);
+        assert_eq!(between.fraction_of("125", "1", "2").unwrap(), 0.25);
+        assert_eq!(between.fraction_of("5", "", "9").unwrap(), 5.0 / 9.0);
+
+        assert!(between.fraction_of("1", "1", "3").is_none());
+        assert!(between.fraction_of("3", "1", "3").is_none());
+        assert!(between.fraction_of("4", "1", "3").is_none());
+        assert!(between.fraction_of("2", "3", "1").is_none());
+        assert!(between.fraction_of("2a", "1", "3").is_none());
+    }
+
+    #[test]
+    fn test_fraction_of_round_trips() {
+        let between = Between::init();
+
+        for t in [0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99] {
+            let key = between.key_at_fraction("A", "B", t).unwrap();
+            let fraction = between.fraction_of(key.as_str(), "A", "B").unwrap();
+            assert!((fraction - t).abs() < 0.01);
+        }
+
+        // Very long strings still give a fraction.
+        let this = "A".repeat(500);
+        let that = format!("{}B", "A".repeat(499));
+        let key = between.between(this.as_str(), that.as_str()).unwrap();
+        let fraction = between.fraction_of(key, this, that).unwrap();
+        assert!(fraction > 0.0 && fraction < 1.0);
+    }
+
+    #[test]
+    fn test_key_at_fraction_biased_appends() {
+        let between = Between::init();
+
+        // Appending with a low bias uses up the gap more slowly than bisecting it every time.
+        let mut biased = "A".to_string();
+        let mut halved = "A".to_string();
+        for _ in 0..200 {
+            let key = between.key_at_fraction(biased.as_str(), "B", 0.1).unwrap();
+            assert!(biased < key && key.as_str() < "B");
+            biased = key;
+            halved = between.between(halved.as_str(), "B").unwrap();
+        }
+        assert!(biased.len() < halved.len());
+    }
+}
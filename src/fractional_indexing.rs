@@ -0,0 +1,514 @@
+//! Generates and reads keys in the format of the `fractional-indexing` libraries, so Rust backends can share
+//! keys with JavaScript frontends that use them.
+//!
+//! A key has an integer part, whose first character encodes its length (`a0` to `az` are the two-character
+//! integers, `b00` onwards the three-character ones, `Zz` downwards the negative ones), followed by an
+//! optional fraction without trailing zeros. The functions here produce exactly the keys
+//! `generateKeyBetween` and `generateNKeysBetween` produce for the same inputs and digits.
+//!
+//! # Example
+//!
+//! ```
+//! use between::fractional_indexing::FractionalIndexing;
+//!
+//! let indexing = FractionalIndexing::base62();
+//! let first = indexing.key_between(None, None).unwrap();
+//! let second = indexing.key_between(Some(&first), None).unwrap();
+//! let tween = indexing.key_between(Some(&first), Some(&second)).unwrap();
+//! assert_eq!((first.as_str(), second.as_str(), tween.as_str()), ("a0", "a1", "a0V"));
+//! ```
+
+use core::error::Error;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::seeded::mix;
+
+/// The digits of the base 62 format, which the JavaScript libraries use by default.
+const BASE_62_DIGITS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+/// The number of bisections `key_between_jittered` makes, giving 4096 possible keys per gap.
+const JITTER_BITS: u32 = 12;
+
+/// A generator of keys in the `fractional-indexing` format, with a given set of digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FractionalIndexing {
+    digits: Vec<char>,
+}
+
+impl FractionalIndexing {
+    /// Creates a generator using the base 62 digits `0-9A-Za-z`, the libraries' default.
+    pub fn base62() -> Self {
+        FractionalIndexing {
+            digits: BASE_62_DIGITS.chars().collect(),
+        }
+    }
+
+    /// Creates a generator using custom digits, like the libraries' `digits` argument.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<FractionalIndexing>` that contains the generator, or `None` if there are fewer than two
+    /// digits or they are not in strictly ascending order.
+    pub fn new(digits: &str) -> Option<Self> {
+        let digits: Vec<char> = digits.chars().collect();
+        if digits.len() < 2 || digits.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return None;
+        }
+        Some(FractionalIndexing { digits })
+    }
+
+    /// Checks if a key is valid in this format.
+    pub fn is_valid_key(&self, key: &str) -> bool {
+        self.validate_key(key).is_ok()
+    }
+
+    /// Generates a key between two keys, like `generateKeyBetween`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The lower key, or `None` for the start of the list.
+    /// * `b` - The upper key, or `None` for the end of the list.
+    ///
+    /// # Returns
+    ///
+    /// The new key, or a [`FractionalIndexError`] if a key is invalid, `a` does not sort before `b`, or the
+    /// integer part cannot grow any further.
+    pub fn key_between(
+        &self,
+        a: Option<&str>,
+        b: Option<&str>,
+    ) -> Result<String, FractionalIndexError> {
+        if let Some(a) = a {
+            self.validate_key(a)?;
+        }
+        if let Some(b) = b {
+            self.validate_key(b)?;
+        }
+        match (a, b) {
+            (Some(a), Some(b)) if a >= b => Err(FractionalIndexError::InvalidOrder),
+            (None, None) => Ok(format!("a{}", self.zero())),
+            (None, Some(b)) => {
+                let (integer, fraction) = self.split(b)?;
+                if integer == self.smallest_integer() {
+                    return Ok(format!(
+                        "{}{}",
+                        integer,
+                        self.midpoint(&[], Some(&chars(fraction)))
+                    ));
+                }
+                if integer.len() < b.len() {
+                    return Ok(integer.to_string());
+                }
+                self.decrement_integer(integer)
+                    .ok_or(FractionalIndexError::Exhausted)
+            }
+            (Some(a), None) => {
+                let (integer, fraction) = self.split(a)?;
+                Ok(self.increment_integer(integer).unwrap_or_else(|| {
+                    format!("{}{}", integer, self.midpoint(&chars(fraction), None))
+                }))
+            }
+            (Some(a), Some(b)) => {
+                let (integer_a, fraction_a) = self.split(a)?;
+                let (integer_b, fraction_b) = self.split(b)?;
+                if integer_a == integer_b {
+                    let fraction = self.midpoint(&chars(fraction_a), Some(&chars(fraction_b)));
+                    return Ok(format!("{}{}", integer_a, fraction));
+                }
+                let integer = self
+                    .increment_integer(integer_a)
+                    .ok_or(FractionalIndexError::Exhausted)?;
+                if integer.as_str() < b {
+                    return Ok(integer);
+                }
+                Ok(format!(
+                    "{}{}",
+                    integer_a,
+                    self.midpoint(&chars(fraction_a), None)
+                ))
+            }
+        }
+    }
+
+    /// Generates `n` keys between two keys, in ascending order, like `generateNKeysBetween`.
+    ///
+    /// # Returns
+    ///
+    /// The new keys, or a [`FractionalIndexError`] under the same conditions as
+    /// [`FractionalIndexing::key_between`].
+    pub fn n_keys_between(
+        &self,
+        a: Option<&str>,
+        b: Option<&str>,
+        n: usize,
+    ) -> Result<Vec<String>, FractionalIndexError> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+        if n == 1 {
+            return Ok(vec![self.key_between(a, b)?]);
+        }
+        match (a, b) {
+            (_, None) => {
+                let mut keys = vec![self.key_between(a, b)?];
+                for _ in 1..n {
+                    let key = self.key_between(keys.last().map(String::as_str), b)?;
+                    keys.push(key);
+                }
+                Ok(keys)
+            }
+            (None, _) => {
+                let mut keys = vec![self.key_between(a, b)?];
+                for _ in 1..n {
+                    let key = self.key_between(a, keys.last().map(String::as_str))?;
+                    keys.push(key);
+                }
+                keys.reverse();
+                Ok(keys)
+            }
+            _ => {
+                let mid = n / 2;
+                let key = self.key_between(a, b)?;
+                let mut keys = self.n_keys_between(a, Some(&key), mid)?;
+                let after = self.n_keys_between(Some(&key), b, n - mid - 1)?;
+                keys.push(key);
+                keys.extend(after);
+                Ok(keys)
+            }
+        }
+    }
+
+    /// Generates a key between two keys, offset within the gap by a caller-provided seed.
+    ///
+    /// Concurrent inserts at the same position generate the same key with `key_between`. This instead
+    /// bisects the gap 12 more times, going up or down according to the seed, so clients passing different
+    /// seeds collide with a probability of about 1 in 4096. The key is in the same format, so it can be read
+    /// by any `fractional-indexing` library.
+    ///
+    /// # Returns
+    ///
+    /// The new key, or a [`FractionalIndexError`] under the same conditions as
+    /// [`FractionalIndexing::key_between`].
+    pub fn key_between_jittered(
+        &self,
+        a: Option<&str>,
+        b: Option<&str>,
+        seed: u64,
+    ) -> Result<String, FractionalIndexError> {
+        let mut low = a.map(str::to_string);
+        let mut high = b.map(str::to_string);
+        let mut key = self.key_between(a, b)?;
+        let bits = mix(seed);
+        for bit in 0..JITTER_BITS {
+            if bits >> bit & 1 == 1 {
+                low = Some(key);
+            } else {
+                high = Some(key);
+            }
+            key = self.key_between(low.as_deref(), high.as_deref())?;
+        }
+        Ok(key)
+    }
+
+    fn zero(&self) -> char {
+        self.digits[0]
+    }
+
+    fn last_digit(&self) -> char {
+        self.digits[self.digits.len() - 1]
+    }
+
+    fn digit(&self, c: char) -> Option<usize> {
+        self.digits.iter().position(|digit| *digit == c)
+    }
+
+    /// Returns the integer part with the lowest head and all zero digits, which has no key below it.
+    fn smallest_integer(&self) -> String {
+        core::iter::once('A')
+            .chain(core::iter::repeat_n(self.zero(), 26))
+            .collect()
+    }
+
+    /// Checks a key's format like the libraries do, and also checks that every digit belongs to the digits.
+    fn validate_key(&self, key: &str) -> Result<(), FractionalIndexError> {
+        if key == self.smallest_integer() {
+            return Err(FractionalIndexError::InvalidKey);
+        }
+        let (integer, fraction) = self.split(key)?;
+        if integer
+            .chars()
+            .skip(1)
+            .chain(fraction.chars())
+            .any(|c| self.digit(c).is_none())
+            || fraction.ends_with(self.zero())
+        {
+            return Err(FractionalIndexError::InvalidKey);
+        }
+        Ok(())
+    }
+
+    /// Splits a key into its integer part and its fraction.
+    fn split<'k>(&self, key: &'k str) -> Result<(&'k str, &'k str), FractionalIndexError> {
+        let len = key
+            .chars()
+            .next()
+            .and_then(integer_len)
+            .ok_or(FractionalIndexError::InvalidKey)?;
+        let end = key
+            .char_indices()
+            .nth(len)
+            .map_or(key.len(), |(end, _)| end);
+        if key.chars().count() < len {
+            return Err(FractionalIndexError::InvalidKey);
+        }
+        Ok(key.split_at(end))
+    }
+
+    /// Finds a fraction between two fractions, the second of which may be the end of the range.
+    fn midpoint(&self, a: &[char], b: Option<&[char]>) -> String {
+        let zero = self.zero();
+        if let Some(b) = b {
+            // Keep the common prefix, padding 'a' with zeros.
+            let n = (0..b.len())
+                .find(|n| a.get(*n).copied().unwrap_or(zero) != b[*n])
+                .unwrap_or(b.len());
+            if n > 0 {
+                let rest = self.midpoint(a.get(n..).unwrap_or(&[]), Some(&b[n..]));
+                return b[..n].iter().collect::<String>() + &rest;
+            }
+        }
+        let digit_a = a.first().map_or(0, |c| self.digit(*c).unwrap());
+        let digit_b = b.map_or(self.digits.len(), |b| self.digit(b[0]).unwrap());
+        if digit_b - digit_a > 1 {
+            // The libraries round the midpoint half up.
+            self.digits[(digit_a + digit_b).div_ceil(2)].to_string()
+        } else if let Some(b) = b.filter(|b| b.len() > 1) {
+            b[0].to_string()
+        } else {
+            let rest = self.midpoint(a.get(1..).unwrap_or(&[]), None);
+            format!("{}{}", self.digits[digit_a], rest)
+        }
+    }
+
+    fn increment_integer(&self, integer: &str) -> Option<String> {
+        let mut chars = chars(integer);
+        let head = chars.remove(0);
+        let mut carry = true;
+        for digit in chars.iter_mut().rev() {
+            let next = self.digit(*digit).unwrap() + 1;
+            if next == self.digits.len() {
+                *digit = self.zero();
+            } else {
+                *digit = self.digits[next];
+                carry = false;
+                break;
+            }
+        }
+        if !carry {
+            return Some(core::iter::once(head).chain(chars).collect());
+        }
+        match head {
+            'Z' => Some(format!("a{}", self.zero())),
+            'z' => None,
+            _ => {
+                let head = (head as u8 + 1) as char;
+                if head > 'a' {
+                    chars.push(self.zero());
+                } else {
+                    chars.pop();
+                }
+                Some(core::iter::once(head).chain(chars).collect())
+            }
+        }
+    }
+
+    fn decrement_integer(&self, integer: &str) -> Option<String> {
+        let mut chars = chars(integer);
+        let head = chars.remove(0);
+        let mut borrow = true;
+        for digit in chars.iter_mut().rev() {
+            match self.digit(*digit).unwrap().checked_sub(1) {
+                Some(previous) => {
+                    *digit = self.digits[previous];
+                    borrow = false;
+                    break;
+                }
+                None => *digit = self.last_digit(),
+            }
+        }
+        if !borrow {
+            return Some(core::iter::once(head).chain(chars).collect());
+        }
+        match head {
+            'a' => Some(format!("Z{}", self.last_digit())),
+            'A' => None,
+            _ => {
+                let head = (head as u8 - 1) as char;
+                if head < 'Z' {
+                    chars.push(self.last_digit());
+                } else {
+                    chars.pop();
+                }
+                Some(core::iter::once(head).chain(chars).collect())
+            }
+        }
+    }
+}
+
+/// Returns the length of an integer part, including its head, from its head.
+fn integer_len(head: char) -> Option<usize> {
+    match head {
+        'a'..='z' => Some(head as usize - 'a' as usize + 2),
+        'A'..='Z' => Some('Z' as usize - head as usize + 2),
+        _ => None,
+    }
+}
+
+fn chars(s: &str) -> Vec<char> {
+    s.chars().collect()
+}
+
+/// An error returned when a key cannot be generated in the `fractional-indexing` format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FractionalIndexError {
+    /// A key was not in the format, or used a digit outside the digits.
+    InvalidKey,
+    /// The lower key did not sort before the upper key.
+    InvalidOrder,
+    /// The integer part could not be incremented or decremented any further.
+    Exhausted,
+}
+
+impl fmt::Display for FractionalIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FractionalIndexError::InvalidKey => f.write_str("invalid order key"),
+            FractionalIndexError::InvalidOrder => f.write_str("keys are not in ascending order"),
+            FractionalIndexError::Exhausted => f.write_str("integer part cannot grow any further"),
+        }
+    }
+}
+
+impl Error for FractionalIndexError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{FractionalIndexError, FractionalIndexing};
+
+    #[test]
+    fn test_key_between() {
+        let indexing = FractionalIndexing::base62();
+        let between = |a: Option<&str>, b: Option<&str>| indexing.key_between(a, b);
+
+        // These match the test suite of the JavaScript library.
+        let cases = [
+            (None, None, "a0"),
+            (None, Some("a0"), "Zz"),
+            (None, Some("Zz"), "Zy"),
+            (Some("a0"), None, "a1"),
+            (Some("a1"), None, "a2"),
+            (Some("a0"), Some("a1"), "a0V"),
+            (Some("a1"), Some("a2"), "a1V"),
+            (Some("a0V"), Some("a1"), "a0l"),
+            (Some("Zz"), Some("a0"), "ZzV"),
+            (Some("Zz"), Some("a1"), "a0"),
+            (None, Some("Y00"), "Xzzz"),
+            (Some("bzz"), None, "c000"),
+            (Some("a0"), Some("a0V"), "a0G"),
+            (Some("a0"), Some("a0G"), "a08"),
+            (Some("b125"), Some("b129"), "b127"),
+            (Some("a0"), Some("a1V"), "a1"),
+            (Some("Zz"), Some("a01"), "a0"),
+            (None, Some("a0V"), "a0"),
+            (None, Some("b999"), "b99"),
+            (
+                None,
+                Some("A000000000000000000000000001"),
+                "A000000000000000000000000000V",
+            ),
+            (
+                Some("zzzzzzzzzzzzzzzzzzzzzzzzzzy"),
+                None,
+                "zzzzzzzzzzzzzzzzzzzzzzzzzzz",
+            ),
+            (
+                Some("zzzzzzzzzzzzzzzzzzzzzzzzzzz"),
+                None,
+                "zzzzzzzzzzzzzzzzzzzzzzzzzzzV",
+            ),
+        ];
+        for (a, b, expected) in cases {
+            assert_eq!(between(a, b).unwrap(), expected, "{:?} {:?}", a, b);
+        }
+
+        assert_eq!(
+            between(None, Some("A00000000000000000000000000")),
+            Err(FractionalIndexError::InvalidKey)
+        );
+        assert_eq!(
+            between(Some("a00"), None),
+            Err(FractionalIndexError::InvalidKey)
+        );
+        assert_eq!(
+            between(Some("a00"), Some("a1")),
+            Err(FractionalIndexError::InvalidKey)
+        );
+        assert_eq!(
+            between(Some("0"), Some("1")),
+            Err(FractionalIndexError::InvalidKey)
+        );
+        assert_eq!(
+            between(Some("a1"), Some("a0")),
+            Err(FractionalIndexError::InvalidOrder)
+        );
+    }
+
+    #[test]
+    fn test_n_keys_between() {
+        let indexing = FractionalIndexing::base62();
+        let keys = |a: Option<&str>, b: Option<&str>, n| {
+            indexing.n_keys_between(a, b, n).unwrap().join(" ")
+        };
+
+        assert_eq!(keys(None, None, 5), "a0 a1 a2 a3 a4");
+        assert_eq!(keys(Some("a4"), None, 10), "a5 a6 a7 a8 a9 aA aB aC aD aE");
+        assert_eq!(keys(None, Some("a0"), 5), "Zv Zw Zx Zy Zz");
+        assert_eq!(
+            keys(Some("a0"), Some("a2"), 20),
+            "a04 a08 a0G a0K a0O a0V a0Z a0d a0l a0t a1 a14 a18 a1G a1O a1V a1Z a1d a1l a1t"
+        );
+    }
+
+    #[test]
+    fn test_key_between_jittered() {
+        let indexing = FractionalIndexing::base62();
+
+        let mut keys: Vec<String> = (0..1000)
+            .map(|seed| {
+                indexing
+                    .key_between_jittered(Some("a0"), Some("a1"), seed)
+                    .unwrap()
+            })
+            .collect();
+        assert!(keys
+            .iter()
+            .all(|key| "a0" < key.as_str() && key.as_str() < "a1" && indexing.is_valid_key(key)));
+        assert_eq!(
+            indexing.key_between_jittered(Some("a0"), None, 7),
+            indexing.key_between_jittered(Some("a0"), None, 7)
+        );
+        keys.sort();
+        keys.dedup();
+        assert!(keys.len() > 850);
+    }
+
+    #[test]
+    fn test_custom_digits() {
+        let indexing = FractionalIndexing::new("0123456789").unwrap();
+
+        assert_eq!(indexing.key_between(Some("a0"), Some("a1")).unwrap(), "a05");
+        assert_eq!(indexing.key_between(Some("a9"), None).unwrap(), "b00");
+        assert!(!indexing.is_valid_key("a0A"));
+        assert!(FractionalIndexing::new("10").is_none());
+    }
+}
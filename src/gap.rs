@@ -0,0 +1,105 @@
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::Between;
+
+impl Between {
+    /// Finds the widest gap between neighboring strings in a sorted list.
+    ///
+    /// Gap width is measured as the distance between the two strings as fractions, which is proportional to
+    /// how many strings of any given (long enough) length fit in the gap. This drives heuristics such as
+    /// inserting bulk data where there is room.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings, in strictly ascending order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<usize>` that contains the index `i` of the widest gap, between `keys[i]` and `keys[i + 1]`
+    /// (the first one on ties), or `None` if there are fewer than two strings, any string is invalid or the
+    /// list is not strictly ascending.
+    pub fn largest_gap<S>(&self, keys: &[S]) -> Option<usize>
+    where
+        S: AsRef<str>,
+    {
+        let widths = self.gap_widths(keys)?;
+        // 'max_by_key' returns the last maximum, so search from the end to get the first one.
+        widths
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, width)| *width)
+            .map(|(index, _)| index)
+    }
+
+    /// Finds the narrowest gap between neighboring strings in a sorted list.
+    ///
+    /// This is the mirror image of [`Between::largest_gap`], useful for warning when a gap is nearly
+    /// exhausted. See also [`Between::min_between_len`].
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings, in strictly ascending order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<usize>` that contains the index `i` of the narrowest gap, between `keys[i]` and
+    /// `keys[i + 1]` (the first one on ties), or `None` if there are fewer than two strings, any string is
+    /// invalid or the list is not strictly ascending.
+    pub fn smallest_gap<S>(&self, keys: &[S]) -> Option<usize>
+    where
+        S: AsRef<str>,
+    {
+        let widths = self.gap_widths(keys)?;
+        widths
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, width)| *width)
+            .map(|(index, _)| index)
+    }
+
+    /// Measures the gaps between neighboring strings, scaled to a length at which every string is exact.
+    fn gap_widths<S>(&self, keys: &[S]) -> Option<Vec<BigUint>>
+    where
+        S: AsRef<str>,
+    {
+        let keys = self.sorted_indices(keys)?;
+        let len = keys.iter().map(Vec::len).max()?;
+        let widths = keys
+            .windows(2)
+            .map(|pair| self.scaled(&pair[1], len) - self.scaled(&pair[0], len))
+            .collect();
+        Some(widths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_largest_and_smallest_gap() {
+        let between = Between::init();
+
+        let keys = vec!["A", "AV", "B", "V", "k", "kV"];
+        assert_eq!(between.largest_gap(&keys), Some(2));
+        assert_eq!(between.smallest_gap(&keys), Some(0));
+
+        // Ties go to the first gap.
+        let keys = vec!["1", "2", "3"];
+        assert_eq!(between.largest_gap(&keys), Some(0));
+        assert_eq!(between.smallest_gap(&keys), Some(0));
+    }
+
+    #[test]
+    fn test_gap_invalid_input() {
+        let between = Between::init();
+
+        assert_eq!(between.largest_gap(&["A"]), None);
+        assert_eq!(between.largest_gap::<&str>(&[]), None);
+        assert_eq!(between.largest_gap(&["B", "A"]), None);
+        assert_eq!(between.smallest_gap(&["A", "A!"]), None);
+        assert_eq!(between.smallest_gap(&["A", "$"]), None);
+    }
+}
@@ -0,0 +1,188 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::prelude::*;
+use crate::symbols::Symbols;
+use crate::AlphabetError;
+
+/// Generates keys from an alphabet of grapheme clusters, such as emoji or letters with combining marks,
+/// instead of single characters.
+///
+/// Keys are split into grapheme clusters, so a generated key never ends in the middle of a cluster. The
+/// alphabet is checked when it is created so that keys still sort correctly as plain strings: every symbol
+/// must be a single grapheme cluster, no symbol may be a prefix of another, and no two symbols may merge
+/// into a single cluster when written next to each other.
+///
+/// Keys are found by bisecting, like the default [`Bisect`](crate::Bisect) strategy.
+///
+/// # Example
+///
+/// ```
+/// use between::GraphemeBetween;
+///
+/// let between = GraphemeBetween::new(vec!["🍎", "🍌", "🍒", "🍇", "🍉"]).unwrap();
+/// let key = between.between("🍌", "🍒").unwrap();
+/// assert_eq!(key, "🍎");
+/// assert!("🍌" < key.as_str() && key.as_str() < "🍒");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GraphemeBetween {
+    symbols: Symbols,
+}
+
+impl GraphemeBetween {
+    /// Creates a new `GraphemeBetween` instance with a given set of grapheme clusters.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - The grapheme clusters to generate keys from, in any order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<GraphemeBetween, AlphabetError>` that contains the instance, or an error if there are fewer
+    /// than two distinct symbols, a symbol is not a single grapheme cluster, a symbol is a prefix of another,
+    /// or two symbols merge into one cluster.
+    pub fn new<S>(symbols: Vec<S>) -> Result<Self, AlphabetError>
+    where
+        S: Into<String>,
+    {
+        let symbols: Vec<String> = symbols.into_iter().map(Into::into).collect();
+        if let Some(symbol) = symbols
+            .iter()
+            .find(|symbol| symbol.graphemes(true).count() != 1)
+        {
+            return Err(AlphabetError::NotAGrapheme {
+                symbol: symbol.clone(),
+            });
+        }
+        let symbols = Symbols::new(symbols)?;
+        for first in symbols.symbols() {
+            for second in symbols.symbols() {
+                let pair = format!("{}{}", first, second);
+                if pair.graphemes(true).count() != 2 {
+                    return Err(AlphabetError::MergingSymbols {
+                        first: first.clone(),
+                        second: second.clone(),
+                    });
+                }
+            }
+        }
+        Ok(GraphemeBetween { symbols })
+    }
+
+    /// Returns the grapheme clusters of the alphabet, in ascending order.
+    pub fn symbols(&self) -> &[String] {
+        self.symbols.symbols()
+    }
+
+    /// Returns the lowest symbol.
+    pub fn low(&self) -> &str {
+        &self.symbols()[0]
+    }
+
+    /// Returns the highest symbol.
+    pub fn high(&self) -> &str {
+        &self.symbols()[self.symbols().len() - 1]
+    }
+
+    /// Splits a key into its symbols.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<&str>>` that contains the grapheme clusters of the key, or `None` if one of them is not
+    /// in the alphabet.
+    pub fn split<'a>(&self, key: &'a str) -> Option<Vec<&'a str>> {
+        key.graphemes(true)
+            .map(|symbol| self.symbols.position(symbol).map(|_| symbol))
+            .collect()
+    }
+
+    /// Checks if a key is non-empty and made only of symbols of the alphabet.
+    pub fn valid(&self, key: &str) -> bool {
+        !key.is_empty() && self.split(key).is_some()
+    }
+
+    /// Finds a key that sorts between two given keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `""` for the start of the keyspace.
+    /// * `that` - The second key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between key if possible, or `None` if a key is invalid or `this`
+    /// does not sort before `that`.
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        let this = self.positions(this)?;
+        let that = self.positions(that)?;
+        let between = self.symbols.between(&this, &that)?;
+        Some(self.symbols.key(&between))
+    }
+
+    /// Finds a key that sorts after a given key, or `""` for the start of the keyspace.
+    pub fn after(&self, key: &str) -> Option<String> {
+        self.between(key, self.high())
+    }
+
+    /// Finds a key that sorts before a given key.
+    pub fn before(&self, key: &str) -> Option<String> {
+        self.between(self.low(), key)
+    }
+
+    /// Returns the symbol positions of a key.
+    fn positions(&self, key: &str) -> Option<Vec<usize>> {
+        key.graphemes(true)
+            .map(|symbol| self.symbols.position(symbol))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlphabetError, GraphemeBetween};
+
+    #[test]
+    fn test_grapheme_between() {
+        // Letters with combining marks are single clusters of several characters.
+        let between = GraphemeBetween::new(vec!["a\u{301}", "b", "c\u{327}", "d", "🇫🇷"]).unwrap();
+        assert_eq!(between.low(), "a\u{301}");
+        assert_eq!(between.high(), "🇫🇷");
+
+        let mut keys = vec![between.after("").unwrap()];
+        for _ in 0..50 {
+            let key = between.after(keys.last().unwrap()).unwrap();
+            keys.push(key);
+        }
+        let key = between.between(&keys[3], &keys[4]).unwrap();
+        assert!(keys[3] < key && key < keys[4]);
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| between.valid(key)));
+
+        assert_eq!(between.split("c\u{327}🇫🇷").unwrap(), vec!["c\u{327}", "🇫🇷"]);
+        assert!(!between.valid("c"));
+        assert!(between.between("d", "b").is_none());
+    }
+
+    #[test]
+    fn test_grapheme_alphabet_errors() {
+        assert_eq!(
+            GraphemeBetween::new(vec!["ab", "c"]).unwrap_err(),
+            AlphabetError::NotAGrapheme {
+                symbol: "ab".to_string()
+            }
+        );
+        assert_eq!(
+            GraphemeBetween::new(vec!["e", "e\u{301}"]).unwrap_err(),
+            AlphabetError::AmbiguousPrefix {
+                prefix: "e".to_string(),
+                symbol: "e\u{301}".to_string()
+            }
+        );
+        // Two regional indicators written side by side form a flag.
+        assert!(matches!(
+            GraphemeBetween::new(vec!["🇫", "🇷"]).unwrap_err(),
+            AlphabetError::MergingSymbols { .. }
+        ));
+        assert!(GraphemeBetween::new(vec!["🍎"]).is_err());
+    }
+}
@@ -0,0 +1,295 @@
+//! Generates keys ordered by a hybrid logical clock (HLC), which can still have keys inserted between them.
+//!
+//! A hybrid logical clock timestamp combines the physical time in milliseconds, a logical counter that orders
+//! events within a millisecond or across clock skew, and the id of the node that created it. Timestamps of
+//! causally related events are ordered, and timestamps of different nodes never collide. A key starts with
+//! the fixed-width timestamp written in the alphabet, followed by a suffix generated with
+//! [`Between::between`], so [`Clock::between`] can insert between any two keys.
+//!
+//! # Example
+//!
+//! ```
+//! use between::hlc::Clock;
+//! use between::Between;
+//!
+//! let mut alice = Clock::new(Between::init(), 1);
+//! let mut bob = Clock::new(Between::init(), 2);
+//!
+//! let sent = alice.now(1_000).unwrap();
+//! // Bob's clock is behind, but his reply still sorts after Alice's message.
+//! let received = bob.observe(&sent, 900).unwrap();
+//! assert!(sent < received);
+//!
+//! let first = alice.key(&sent).unwrap();
+//! let second = bob.key(&received).unwrap();
+//! assert!(first < second);
+//! assert_eq!(bob.parse(&second), Some(received));
+//! ```
+
+use num_traits::ToPrimitive;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// The number of bits of the physical time in a key. Timestamps past it are still counted by the clock, but
+/// have no key.
+const PHYSICAL_BITS: u32 = 48;
+/// The number of bits of the logical counter.
+const LOGICAL_BITS: u32 = 16;
+/// The number of bits of a node id.
+const NODE_BITS: u32 = 32;
+
+/// A hybrid logical clock timestamp.
+///
+/// Timestamps compare by physical time, then logical counter, then node id, which is also the order of their
+/// keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    /// The physical time, in milliseconds since the Unix epoch.
+    pub physical: u64,
+    /// The logical counter, ordering events that share a physical time.
+    pub logical: u16,
+    /// The id of the node that created the timestamp.
+    pub node: u32,
+}
+
+/// A hybrid logical clock for one node, generating timestamps and their keys.
+#[derive(Debug, Clone)]
+pub struct Clock {
+    between: Between,
+    node: u32,
+    physical: u64,
+    logical: u16,
+    widths: [usize; 3],
+}
+
+impl Clock {
+    /// Creates a clock for the given node, writing keys with the alphabet of `between`.
+    pub fn new(between: Between, node: u32) -> Self {
+        let widths = [
+            between.fixed_width(PHYSICAL_BITS),
+            between.fixed_width(LOGICAL_BITS),
+            between.fixed_width(NODE_BITS),
+        ];
+        Clock {
+            between,
+            node,
+            physical: 0,
+            logical: 0,
+            widths,
+        }
+    }
+
+    /// Returns the `Between` instance used to write keys.
+    pub fn inner(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the id of the clock's node.
+    pub fn node(&self) -> u32 {
+        self.node
+    }
+
+    /// Returns the timestamp for a local event or for sending a message.
+    ///
+    /// # Arguments
+    ///
+    /// * `millis` - The current physical time, in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// The new timestamp, or `None` if the clock is at the last possible timestamp and cannot advance.
+    pub fn now(&mut self, millis: u64) -> Option<Timestamp> {
+        if millis > self.physical {
+            Some(self.set(millis, 0))
+        } else {
+            self.tick(self.physical, self.logical)
+        }
+    }
+
+    /// Returns the timestamp for receiving a message stamped `remote`, which sorts after both `remote` and
+    /// every timestamp this clock returned before.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote` - The timestamp of the received message.
+    /// * `millis` - The current physical time, in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// The new timestamp, or `None` if `remote` or the clock is at the last possible timestamp, so that no
+    /// timestamp sorts after it.
+    pub fn observe(&mut self, remote: &Timestamp, millis: u64) -> Option<Timestamp> {
+        let physical = self.physical.max(remote.physical).max(millis);
+        match (physical == self.physical, physical == remote.physical) {
+            (true, true) => self.tick(physical, self.logical.max(remote.logical)),
+            (true, false) => self.tick(physical, self.logical),
+            (false, true) => self.tick(physical, remote.logical),
+            (false, false) => Some(self.set(physical, 0)),
+        }
+    }
+
+    /// Writes the key of a timestamp.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the key, or `None` if the physical time does not fit in 48 bits.
+    pub fn key(&self, timestamp: &Timestamp) -> Option<String> {
+        if timestamp.physical >> PHYSICAL_BITS != 0 {
+            return None;
+        }
+        let [physical, logical, node] = self.widths;
+        let mut key = self.between.fixed_digits(timestamp.physical, physical);
+        key.push_str(&self.between.fixed_digits(timestamp.logical.into(), logical));
+        key.push_str(&self.between.fixed_digits(timestamp.node.into(), node));
+        key.push_str(&self.between.after("")?);
+        Some(key)
+    }
+
+    /// Reads the timestamp at the start of a key, including keys inserted between other keys.
+    pub fn parse(&self, key: &str) -> Option<Timestamp> {
+        let indices = self.between.indices(key)?;
+        let [physical, logical, node] = self.widths;
+        if indices.len() < physical + logical + node {
+            return None;
+        }
+        let (physical_digits, rest) = indices.split_at(physical);
+        let (logical_digits, rest) = rest.split_at(logical);
+        Some(Timestamp {
+            physical: self.between.scaled(physical_digits, physical).to_u64()?,
+            logical: self.between.scaled(logical_digits, logical).to_u16()?,
+            node: self.between.scaled(&rest[..node], node).to_u32()?,
+        })
+    }
+
+    /// Finds a key between two given keys.
+    ///
+    /// The new key starts with the physical time, logical counter and node id of `this`, so [`Clock::parse`]
+    /// reads the timestamp of `this` back from it, and only its suffix differs.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if either bound does not
+    /// start with a timestamp or the bounds are invalid under the same rules as [`Between::between`].
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        self.parse(this)?;
+        self.parse(that)?;
+        let width = self.widths.iter().sum();
+        self.between.between_in_field(this, that, width)
+    }
+
+    /// Increments the logical counter, moving to the next millisecond if it overflows, or returns `None` if
+    /// the physical time overflows too.
+    fn tick(&mut self, physical: u64, logical: u16) -> Option<Timestamp> {
+        match logical.checked_add(1) {
+            Some(logical) => Some(self.set(physical, logical)),
+            None => Some(self.set(physical.checked_add(1)?, 0)),
+        }
+    }
+
+    fn set(&mut self, physical: u64, logical: u16) -> Timestamp {
+        self.physical = physical;
+        self.logical = logical;
+        Timestamp {
+            physical,
+            logical,
+            node: self.node,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clock, Timestamp};
+    use crate::Between;
+
+    #[test]
+    fn test_clock() {
+        let mut clock = Clock::new(Between::init(), 7);
+
+        let first = clock.now(1_000).unwrap();
+        assert_eq!((first.physical, first.logical, first.node), (1_000, 0, 7));
+        let second = clock.now(1_000).unwrap();
+        let third = clock.now(999).unwrap();
+        assert_eq!((second.logical, third.logical), (1, 2));
+        assert_eq!(clock.now(1_001).unwrap().logical, 0);
+
+        let remote = Timestamp {
+            physical: 5_000,
+            logical: 3,
+            node: 9,
+        };
+        let received = clock.observe(&remote, 1_002).unwrap();
+        assert_eq!((received.physical, received.logical), (5_000, 4));
+        assert!(remote < received && clock.now(1_003).unwrap() > received);
+
+        clock.logical = u16::MAX;
+        assert_eq!(clock.now(0).unwrap().physical, 5_001);
+    }
+
+    #[test]
+    fn test_clock_at_last_timestamp() {
+        let last = Timestamp {
+            physical: u64::MAX,
+            logical: u16::MAX,
+            node: 9,
+        };
+        let mut clock = Clock::new(Between::init(), 7);
+        assert_eq!(clock.observe(&last, 1_000), None);
+
+        let mut clock = Clock::new(Between::init(), 7);
+        clock.now(u64::MAX).unwrap();
+        clock.logical = u16::MAX;
+        assert_eq!(clock.now(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_keys() {
+        let mut clock = Clock::new(Between::init(), 7);
+        let timestamps: Vec<Timestamp> = (0..100)
+            .map(|i| clock.now(1_000 + i / 10).unwrap())
+            .collect();
+        let keys: Vec<String> = timestamps
+            .iter()
+            .map(|timestamp| clock.key(timestamp).unwrap())
+            .collect();
+
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        for (key, timestamp) in keys.iter().zip(&timestamps) {
+            assert_eq!(clock.parse(key).as_ref(), Some(timestamp));
+        }
+
+        let tween = clock.between(&keys[0], &keys[1]).unwrap();
+        assert!(keys[0] < tween && tween < keys[1]);
+        assert_eq!(clock.parse(&tween), Some(timestamps[0]));
+
+        let far = Timestamp {
+            physical: 1 << 48,
+            logical: 0,
+            node: 0,
+        };
+        assert!(clock.key(&far).is_none());
+        assert!(clock.parse("A").is_none());
+    }
+
+    #[test]
+    fn test_between_different_physical_times() {
+        let mut clock = Clock::new(Between::init(), 7);
+        let early = clock.now(1_000).unwrap();
+        let late = clock.now(9_000_000_000).unwrap();
+        let (first, last) = (clock.key(&early).unwrap(), clock.key(&late).unwrap());
+
+        let mut tween = clock.between(&first, &last).unwrap();
+        assert!(first < tween && tween < last);
+        assert_eq!(clock.parse(&tween), Some(early));
+        for _ in 0..50 {
+            let next = clock.between(&tween, &last).unwrap();
+            assert!(tween < next && next < last);
+            assert_eq!(clock.parse(&next), Some(early));
+            tween = next;
+        }
+
+        assert!(clock.between(&last, &first).is_none());
+        assert!(clock.between("", &first).is_none());
+    }
+}
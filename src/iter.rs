@@ -0,0 +1,187 @@
+use alloc::collections::VecDeque;
+use core::iter;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Returns an unbounded iterator of strictly increasing strings, each sorting after the previous one.
+    ///
+    /// The first string sorts after `start`. Every following string is [`Between::after`] the previous one,
+    /// so this is the natural way to append many items to the end of a list.
+    ///
+    /// Since each step halves the remaining room below the highest character, the strings grow by one
+    /// character about every `log2(chars.len())` steps, i.e. length grows linearly with the number of
+    /// strings taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The string to start after, usually the current last key of a list.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that never ends, unless `start` has no successor (in which case it yields nothing).
+    pub fn after_iter<S>(&self, start: S) -> impl Iterator<Item = String> + '_
+    where
+        S: AsKey,
+    {
+        iter::successors(self.after(start), move |key| self.after(key.as_str()))
+    }
+
+    /// Returns an unbounded iterator of strictly decreasing strings, each sorting before the previous one.
+    ///
+    /// The first string sorts before `start`. Every following string is [`Between::before`] the previous one,
+    /// so this is the natural way to prepend many items to the start of a list.
+    ///
+    /// The strings approach the lowest character, growing by one character about every `log2(chars.len())`
+    /// steps. Since generated strings never end in the lowest character, each new character is a midpoint
+    /// rather than the lowest character itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The string to start before, usually the current first key of a list.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that never ends, unless `start` has no predecessor (in which case it yields nothing).
+    pub fn before_iter<S>(&self, start: S) -> impl Iterator<Item = String> + '_
+    where
+        S: AsKey,
+    {
+        iter::successors(self.before(start), move |key| self.before(key.as_str()))
+    }
+
+    /// Returns an unbounded iterator of distinct strings that all sort strictly between two fixed bounds.
+    ///
+    /// The strings are produced by repeatedly bisecting the gap, breadth-first: first the midpoint of
+    /// `this` and `that`, then the midpoints of the two halves, then of the four quarters, and so on. Shorter
+    /// strings therefore come first, and the strings are spread over the whole gap rather than crowding
+    /// towards one end. This suits inserting many items at the same position over time without having to
+    /// look up the new neighbors after every insert.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// An iterator that never ends, unless the bounds are invalid under the same rules as
+    /// [`Between::between`] (in which case it yields nothing).
+    pub fn between_iter<S, T>(&self, this: S, that: T) -> impl Iterator<Item = String> + '_
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let mut gaps: VecDeque<(String, String)> = VecDeque::new();
+        gaps.push_back((this.as_key().into_owned(), that.as_key().into_owned()));
+
+        iter::from_fn(move || {
+            let (this, that) = gaps.pop_front()?;
+            let key = self.between(this.as_str(), that.as_str())?;
+            gaps.push_back((this, key.clone()));
+            gaps.push_back((key.clone(), that));
+            Some(key)
+        })
+    }
+
+    /// Returns an iterator over every string of at most `max_len` characters, in ascending order.
+    ///
+    /// Only strings that [`Between::between`] could generate are produced, i.e. strings that do not end in
+    /// the lowest character. This is useful for exhaustive tests, seeding tables and checking that a database
+    /// orders keys the same way. Note that there are `chars.len() ^ max_len - 1` such strings.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum length of the strings.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the strings, which yields nothing if `max_len` is zero.
+    pub fn keys(&self, max_len: usize) -> impl Iterator<Item = String> + '_ {
+        // Count through every value with 'max_len' digits, like an odometer. Values with trailing zero
+        // digits are the shorter strings.
+        let mut digits = vec![0; max_len];
+        iter::from_fn(move || {
+            let position = digits
+                .iter()
+                .rposition(|digit| *digit + 1 < self.alphabet.chars.len())?;
+            digits[position] += 1;
+            for digit in &mut digits[position + 1..] {
+                *digit = 0;
+            }
+            Some(self.key_from_indices(&digits))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_after_iter() {
+        let between = Between::init();
+
+        let keys: Vec<String> = between.after_iter("A").take(5).collect();
+        assert_eq!(keys, vec!["a", "n", "u", "x", "z"]);
+
+        let keys: Vec<String> = between.after_iter("").take(500).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| between.valid(key.as_str())));
+        // 65 characters give about six halvings per character.
+        assert_eq!(keys[99].len(), 17);
+        assert_eq!(keys[499].len(), 84);
+
+        assert_eq!(between.after_iter("~").next(), None);
+    }
+
+    #[test]
+    fn test_before_iter() {
+        let between = Between::init();
+
+        let keys: Vec<String> = between.before_iter("V").take(7).collect();
+        assert_eq!(keys, vec!["F", "7", "3", "1", "0", "!V", "!F"]);
+
+        let keys: Vec<String> = between.before_iter("~").take(500).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(keys.iter().all(|key| !key.ends_with(between.low())));
+        assert_eq!(keys[99].len(), 17);
+        assert_eq!(keys[499].len(), 84);
+
+        assert_eq!(between.before_iter("!").next(), None);
+    }
+
+    #[test]
+    fn test_keys() {
+        let between = Between::new(vec!['a', 'b', 'c']);
+
+        let keys: Vec<String> = between.keys(2).collect();
+        assert_eq!(keys, vec!["ab", "ac", "b", "bb", "bc", "c", "cb", "cc"]);
+        assert_eq!(between.keys(0).next(), None);
+
+        let keys: Vec<String> = Between::init().keys(2).collect();
+        assert_eq!(keys.len(), 65 * 65 - 1);
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| !key.ends_with('!')));
+    }
+
+    #[test]
+    fn test_between_iter() {
+        let between = Between::init();
+
+        let keys: Vec<String> = between.between_iter("A", "B").take(3).collect();
+        assert_eq!(keys, vec!["AV", "AF", "Ak"]);
+
+        let mut keys: Vec<String> = between.between_iter("A", "B").take(1000).collect();
+        assert!(keys
+            .iter()
+            .all(|key| "A" < key.as_str() && key.as_str() < "B"));
+        assert!(keys.windows(2).all(|pair| pair[0].len() <= pair[1].len()));
+        keys.sort();
+        keys.dedup();
+        assert_eq!(keys.len(), 1000);
+
+        assert_eq!(between.between_iter("B", "A").next(), None);
+    }
+}
@@ -0,0 +1,355 @@
+use alloc::borrow::Cow;
+use core::borrow::Borrow;
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::prelude::*;
+use crate::{Between, DEFAULT_CHARS};
+
+/// An ordering key whose characters are known to belong to the alphabet of a [`Between`].
+///
+/// Keys compare in the same order as the strings they wrap, which is the order `Between` generates them in.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(
+        derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash),
+        compare(PartialEq)
+    )
+)]
+pub struct Key(pub(crate) String);
+
+impl Key {
+    /// Creates a new `Key` from a string, validating it against the alphabet of `between`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The string to wrap.
+    /// * `between` - The `Between` instance whose alphabet the key must use.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Key)` if every character of `key` belongs to the alphabet, `None` otherwise.
+    pub fn new<S>(key: S, between: &Between) -> Option<Self>
+    where
+        S: Into<String>,
+    {
+        let key: String = key.into();
+        if between.valid(key.as_str()) {
+            Some(Key(key))
+        } else {
+            None
+        }
+    }
+
+    /// Parses a string into a `Key`, validating it against the alphabet of `between`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The string to parse.
+    /// * `between` - The `Between` instance whose alphabet the key must use.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `Key`, or a [`ParseKeyError`] describing the first problem found.
+    pub fn parse(key: &str, between: &Between) -> Result<Self, ParseKeyError> {
+        Key::parse_with(key, |c| between.position(c).is_some())
+    }
+
+    /// Checks that a key obtained without validation, such as one decoded from a database row, uses the
+    /// alphabet of `between`.
+    ///
+    /// Database integrations decode keys as-is, since a column is expected to be written by this crate. Call
+    /// this on keys read from columns other applications can write to.
+    ///
+    /// # Arguments
+    ///
+    /// * `between` - The `Between` instance whose alphabet the key must use.
+    ///
+    /// # Returns
+    ///
+    /// The key itself, or a [`ParseKeyError`] describing the first problem found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use between::{Between, Key};
+    ///
+    /// let key: Key = "42".parse().unwrap();
+    /// assert!(key.clone().validate(&Between::base10()).is_ok());
+    /// let key: Key = "4x".parse().unwrap();
+    /// assert!(key.validate(&Between::base10()).is_err());
+    /// ```
+    pub fn validate(self, between: &Between) -> Result<Self, ParseKeyError> {
+        check(&self.0, |c| between.position(c).is_some())?;
+        Ok(self)
+    }
+
+    /// Parses a string into a `Key` of the default alphabet, without building a `Between` for it.
+    pub(crate) fn parse_default(key: &str) -> Result<Self, ParseKeyError> {
+        Key::parse_with(key, is_default_char)
+    }
+
+    fn parse_with<F>(key: &str, valid: F) -> Result<Self, ParseKeyError>
+    where
+        F: Fn(char) -> bool,
+    {
+        check(key, valid)?;
+        Ok(Key(key.into()))
+    }
+
+    /// Returns the key as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the key, returning the underlying `String`.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// A key or bound accepted by the methods of [`Between`]: a string, borrowed or owned, or a single
+/// character.
+///
+/// Strings are only borrowed, so `&str`, `String`, `&String`, `Cow<str>` and [`Key`] all work without copying
+/// the input.
+pub trait AsKey {
+    /// Returns the key as a string.
+    fn as_key(&self) -> Cow<'_, str>;
+}
+
+impl<T> AsKey for &T
+where
+    T: AsRef<str> + ?Sized,
+{
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed((**self).as_ref())
+    }
+}
+
+impl<T> AsKey for &mut T
+where
+    T: AsRef<str> + ?Sized,
+{
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed((**self).as_ref())
+    }
+}
+
+impl AsKey for String {
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsKey for Cow<'_, str> {
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsKey for Box<str> {
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl AsKey for Key {
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.0)
+    }
+}
+
+impl AsKey for char {
+    fn as_key(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Parses a `Key` using the default alphabet.
+///
+/// Use [`Key::parse`] to validate against a custom alphabet.
+impl FromStr for Key {
+    type Err = ParseKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Key::parse_default(s)
+    }
+}
+
+/// Checks that a string is not empty and that `valid` accepts each of its characters.
+fn check<F>(key: &str, valid: F) -> Result<(), ParseKeyError>
+where
+    F: Fn(char) -> bool,
+{
+    if key.is_empty() {
+        return Err(ParseKeyError::Empty);
+    }
+    if let Some((index, character)) = key.chars().enumerate().find(|(_, c)| !valid(*c)) {
+        return Err(ParseKeyError::InvalidCharacter { character, index });
+    }
+    Ok(())
+}
+
+/// Checks if a character belongs to the default alphabet, whose characters are sorted ASCII.
+pub(crate) fn is_default_char(c: char) -> bool {
+    c.is_ascii() && DEFAULT_CHARS.as_bytes().binary_search(&(c as u8)).is_ok()
+}
+
+impl AsRef<str> for Key {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Key {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Key> for String {
+    fn from(key: Key) -> Self {
+        key.0
+    }
+}
+
+/// An error returned when a string cannot be parsed into a [`Key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseKeyError {
+    /// The string was empty.
+    Empty,
+    /// The string contained a character outside the alphabet.
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+        /// The position of the offending character, counted in characters.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseKeyError::Empty => f.write_str("key is empty"),
+            ParseKeyError::InvalidCharacter { character, index } => write!(
+                f,
+                "invalid character {:?} at index {} in key",
+                character, index
+            ),
+        }
+    }
+}
+
+impl Error for ParseKeyError {}
+
+#[cfg(test)]
+mod tests {
+    use super::is_default_char;
+    use crate::{Between, Key, ParseKeyError};
+
+    #[test]
+    fn test_new_validates_alphabet() {
+        let between = Between::new(vec!['a', 'b', 'c']);
+
+        assert_eq!(Key::new("abc", &between).unwrap().as_str(), "abc");
+        assert!(Key::new("abd", &between).is_none());
+        assert!(Key::new("", &between).is_none());
+    }
+
+    #[test]
+    fn test_keys_sort_like_strings() {
+        let between = Between::init();
+        let a = Key::new("A", &between).unwrap();
+        let tween = Key::new(between.between("A", "B").unwrap(), &between).unwrap();
+        let b = Key::new("B", &between).unwrap();
+
+        assert!(a < tween);
+        assert!(tween < b);
+        assert_eq!(String::from(tween), "AV");
+    }
+
+    #[test]
+    fn test_parse() {
+        let between = Between::new(vec!['a', 'b', 'c']);
+
+        assert_eq!(Key::parse("cab", &between).unwrap().as_str(), "cab");
+        assert_eq!(Key::parse("", &between), Err(ParseKeyError::Empty));
+        assert_eq!(
+            Key::parse("abd", &between),
+            Err(ParseKeyError::InvalidCharacter {
+                character: 'd',
+                index: 2
+            })
+        );
+        assert_eq!(
+            Key::parse("abd", &between).unwrap_err().to_string(),
+            "invalid character 'd' at index 2 in key"
+        );
+    }
+
+    #[test]
+    fn test_display_from_str_round_trip() {
+        let key: Key = "AV".parse().unwrap();
+        assert_eq!(key.to_string(), "AV");
+        assert_eq!(key.to_string().parse::<Key>().unwrap(), key);
+
+        assert!("a$b".parse::<Key>().is_err());
+    }
+
+    #[test]
+    fn test_as_key() {
+        use std::borrow::Cow;
+
+        let between = Between::init();
+        let key = Key::new("A", &between).unwrap();
+        let expected = between.between("A", "B");
+        assert_eq!(between.between('A', 'B'), expected);
+        assert_eq!(between.between(&key, String::from("B")), expected);
+        assert_eq!(between.between(key, Cow::Borrowed("B")), expected);
+        assert_eq!(
+            between.shortest_between('A', "B"),
+            between.shortest_between("A", "B")
+        );
+        assert!(between.valid('A'));
+    }
+
+    #[test]
+    fn test_default_chars_match_init() {
+        let between = Between::init();
+        for c in (0..=0x2FFu32).filter_map(char::from_u32) {
+            assert_eq!(is_default_char(c), between.position(c).is_some(), "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        let key = Key("4x".to_string());
+        assert_eq!(
+            key.clone().validate(&Between::base10()),
+            Err(ParseKeyError::InvalidCharacter {
+                character: 'x',
+                index: 1
+            })
+        );
+        assert_eq!(key.clone().validate(&Between::base36()), Ok(key));
+        assert_eq!(
+            Key(String::new()).validate(&Between::base10()),
+            Err(ParseKeyError::Empty)
+        );
+    }
+}
@@ -0,0 +1,286 @@
+use alloc::collections::BTreeMap;
+use core::error::Error;
+use core::fmt;
+use core::hash::Hash;
+use core::ops::Bound;
+use std::collections::HashMap;
+
+use crate::{Between, BetweenError, Key, ParseKeyError};
+
+/// An allocator that remembers every key it has issued, and which member each key belongs to.
+///
+/// Members are identified by ids of the caller's choosing, so new keys can be allocated relative to existing
+/// members without handling their keys. Keys created elsewhere can be registered too, and are rejected if
+/// they collide with a key already in use, so the allocator can act as the single source of truth for a list
+/// in a server process (wrapped in a `Mutex` when shared between threads).
+///
+/// Keys are stored without trailing lowest characters, so `"A"` and `"A!"` collide under the default alphabet.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, KeySpace};
+///
+/// let mut space = KeySpace::new(Between::init());
+/// space.allocate_last("first").unwrap();
+/// space.allocate_last("third").unwrap();
+/// space.allocate_between("second", &"first", &"third").unwrap();
+///
+/// let ids: Vec<&str> = space.iter().map(|(_, id)| *id).collect();
+/// assert_eq!(ids, vec!["first", "second", "third"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeySpace<I> {
+    between: Between,
+    members: BTreeMap<Key, I>,
+    keys: HashMap<I, Key>,
+}
+
+impl<I> KeySpace<I>
+where
+    I: Eq + Hash + Clone,
+{
+    /// Creates an empty allocator generating keys with the given `Between` instance.
+    pub fn new(between: Between) -> Self {
+        KeySpace {
+            between,
+            members: BTreeMap::new(),
+            keys: HashMap::new(),
+        }
+    }
+
+    /// Returns the `Between` instance used to generate and validate keys.
+    pub fn between(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the number of members.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Returns `true` if there are no members.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Returns the key of the member with the given id, if any.
+    pub fn key_of(&self, id: &I) -> Option<&Key> {
+        self.keys.get(id)
+    }
+
+    /// Returns the id of the member holding `key`, ignoring any trailing lowest characters.
+    pub fn id_of(&self, key: &str) -> Option<&I> {
+        self.members.get(key.trim_end_matches(self.between.low()))
+    }
+
+    /// Returns an iterator over the keys and ids of the members, in key order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&Key, &I)> + '_ {
+        self.members.iter()
+    }
+
+    /// Adds a member with a key created elsewhere, such as one loaded from storage or sent by a client.
+    ///
+    /// # Returns
+    ///
+    /// The stored key, or a [`KeySpaceError`] if the id is already a member, the key is invalid, or the key
+    /// collides with another member's.
+    pub fn register(&mut self, id: I, key: &str) -> Result<Key, KeySpaceError> {
+        if self.keys.contains_key(&id) {
+            return Err(KeySpaceError::DuplicateId);
+        }
+        let key = Key::parse(key.trim_end_matches(self.between.low()), &self.between)?;
+        if self.members.contains_key(&key) {
+            return Err(KeySpaceError::Collision { key: key.0 });
+        }
+        self.add(id, key.clone());
+        Ok(key)
+    }
+
+    /// Removes a member and returns its key, which may then be issued again.
+    pub fn release(&mut self, id: &I) -> Option<Key> {
+        let key = self.keys.remove(id)?;
+        self.members.remove(&key);
+        Some(key)
+    }
+
+    /// Allocates a key for a new member before every other member.
+    ///
+    /// # Returns
+    ///
+    /// The new key, or a [`KeySpaceError`] if the id is already a member or no key fits within the maximum
+    /// length.
+    pub fn allocate_first(&mut self, id: I) -> Result<Key, KeySpaceError> {
+        let next = self.members.keys().next().map(|key| key.0.clone());
+        self.allocate(id, String::new(), next)
+    }
+
+    /// Allocates a key for a new member after every other member.
+    ///
+    /// # Returns
+    ///
+    /// The new key, or a [`KeySpaceError`] if the id is already a member or no key fits within the maximum
+    /// length.
+    pub fn allocate_last(&mut self, id: I) -> Result<Key, KeySpaceError> {
+        let prev = self
+            .members
+            .keys()
+            .next_back()
+            .map_or(String::new(), |key| key.0.clone());
+        self.allocate(id, prev, None)
+    }
+
+    /// Allocates a key for a new member between two existing members.
+    ///
+    /// The new key lands right after `prev`, before any member that sorts between `prev` and `next`, so it
+    /// never collides with an issued key.
+    ///
+    /// # Returns
+    ///
+    /// The new key, or a [`KeySpaceError`] if the id is already a member, `prev` or `next` is not a member,
+    /// `prev` does not sort before `next`, or no key fits within the maximum length.
+    pub fn allocate_between(&mut self, id: I, prev: &I, next: &I) -> Result<Key, KeySpaceError> {
+        let prev = self.keys.get(prev).ok_or(KeySpaceError::UnknownId)?;
+        let next = self.keys.get(next).ok_or(KeySpaceError::UnknownId)?;
+        if prev >= next {
+            return Err(BetweenError::InvalidBounds.into());
+        }
+        let successor = self
+            .members
+            .range::<Key, _>((Bound::Excluded(prev), Bound::Unbounded))
+            .next()
+            .map(|(key, _)| key.0.clone());
+        let prev = prev.0.clone();
+        self.allocate(id, prev, successor)
+    }
+
+    /// Generates a key between `prev` and `next` (the end of the keyspace if `None`) and adds the member.
+    fn allocate(
+        &mut self,
+        id: I,
+        prev: String,
+        next: Option<String>,
+    ) -> Result<Key, KeySpaceError> {
+        if self.keys.contains_key(&id) {
+            return Err(KeySpaceError::DuplicateId);
+        }
+        let next = next.unwrap_or_else(|| self.between.high().to_string());
+        let key = Key(self.between.try_between(prev, next)?);
+        self.add(id, key.clone());
+        Ok(key)
+    }
+
+    fn add(&mut self, id: I, key: Key) {
+        self.members.insert(key.clone(), id.clone());
+        self.keys.insert(id, key);
+    }
+}
+
+/// An error returned by a [`KeySpace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySpaceError {
+    /// The id already belongs to a member.
+    DuplicateId,
+    /// The id does not belong to any member.
+    UnknownId,
+    /// The key is already held by another member.
+    Collision {
+        /// The colliding key, without trailing lowest characters.
+        key: String,
+    },
+    /// The key could not be parsed.
+    InvalidKey(ParseKeyError),
+    /// No key could be generated.
+    Between(BetweenError),
+}
+
+impl From<ParseKeyError> for KeySpaceError {
+    fn from(error: ParseKeyError) -> Self {
+        KeySpaceError::InvalidKey(error)
+    }
+}
+
+impl From<BetweenError> for KeySpaceError {
+    fn from(error: BetweenError) -> Self {
+        KeySpaceError::Between(error)
+    }
+}
+
+impl fmt::Display for KeySpaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeySpaceError::DuplicateId => f.write_str("id is already a member"),
+            KeySpaceError::UnknownId => f.write_str("id is not a member"),
+            KeySpaceError::Collision { key } => write!(f, "key {:?} is already issued", key),
+            KeySpaceError::InvalidKey(error) => error.fmt(f),
+            KeySpaceError::Between(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for KeySpaceError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KeySpaceError::InvalidKey(error) => Some(error),
+            KeySpaceError::Between(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, BetweenError, KeySpace, KeySpaceError};
+
+    #[test]
+    fn test_key_space() {
+        let mut space = KeySpace::new(Between::init());
+
+        let b = space.allocate_last(2).unwrap();
+        let a = space.allocate_first(1).unwrap();
+        let d = space.allocate_last(4).unwrap();
+        let c = space.allocate_between(3, &2, &4).unwrap();
+        assert!(a < b && b < c && c < d);
+        assert_eq!(space.key_of(&3), Some(&c));
+        assert_eq!(space.id_of(c.as_str()), Some(&3));
+
+        // Allocating between non-adjacent members lands right after the first one.
+        let ab = space.allocate_between(5, &1, &4).unwrap();
+        assert!(a < ab && ab < b);
+        assert_eq!(space.len(), 5);
+
+        assert_eq!(space.release(&5), Some(ab));
+        assert!(space.release(&5).is_none());
+        assert_eq!(
+            space.iter().map(|(_, id)| *id).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_key_space_errors() {
+        let mut space = KeySpace::new(Between::init());
+        space.register("a", "A").unwrap();
+        space.register("b", "B").unwrap();
+
+        assert_eq!(
+            space.register("c", "A!"),
+            Err(KeySpaceError::Collision { key: "A".into() })
+        );
+        assert_eq!(space.register("a", "C"), Err(KeySpaceError::DuplicateId));
+        assert_eq!(space.allocate_last("a"), Err(KeySpaceError::DuplicateId));
+        assert!(matches!(
+            space.register("c", "$"),
+            Err(KeySpaceError::InvalidKey(_))
+        ));
+        assert_eq!(
+            space.allocate_between("c", &"a", &"z"),
+            Err(KeySpaceError::UnknownId)
+        );
+        assert_eq!(
+            space.allocate_between("c", &"b", &"a"),
+            Err(KeySpaceError::Between(BetweenError::InvalidBounds))
+        );
+        assert_eq!(space.len(), 2);
+    }
+}
@@ -0,0 +1,332 @@
+//! Reads, writes and generates Jira-style LexoRank ranks, for interoperating with LexoRank-based systems.
+//!
+//! A rank such as `0|hzzzzz:i` has a bucket (`0`, `1` or `2`), a six-digit base-36 integer part and an
+//! optional base-36 decimal part. Ranks sort like their strings. To rebalance, every rank of a list moves to
+//! the next bucket, wrapping from `2` back to `0`, with evenly spaced values; the other buckets are kept free
+//! so that ranks of the old and the new bucket can coexist while the list is migrated.
+//!
+//! # Example
+//!
+//! ```
+//! use between::lexorank::LexoRank;
+//!
+//! let first = LexoRank::middle(0);
+//! let second = first.gen_next().unwrap();
+//! let tween = first.between(&second).unwrap();
+//! assert_eq!(first.to_string(), "0|hzzzzz:");
+//! assert!(first < tween && tween < second);
+//! assert_eq!("0|hzzzzz:".parse::<LexoRank>().unwrap(), first);
+//! ```
+
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// The digits of a rank, in order.
+const DIGITS: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+/// The number of digits of the integer part.
+const INTEGER_LEN: usize = 6;
+/// How far `gen_next` and `gen_prev` move the integer part, as LexoRank does.
+const STEP: u64 = 8;
+/// The number of buckets.
+const BUCKETS: u8 = 3;
+
+/// A LexoRank rank.
+///
+/// Ranks compare in the same order as their strings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LexoRank {
+    bucket: u8,
+    integer: String,
+    decimal: String,
+}
+
+impl LexoRank {
+    /// Returns the lowest rank of a bucket, `0|000000:` for bucket 0. No rank can be generated before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is not 0, 1 or 2.
+    pub fn min(bucket: u8) -> Self {
+        LexoRank::from_parts(bucket, "000000")
+    }
+
+    /// Returns the highest rank of a bucket, `0|zzzzzz:` for bucket 0. No rank can be generated after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is not 0, 1 or 2.
+    pub fn max(bucket: u8) -> Self {
+        LexoRank::from_parts(bucket, "zzzzzz")
+    }
+
+    /// Returns the rank LexoRank gives the first item of a list, `0|hzzzzz:` for bucket 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bucket` is not 0, 1 or 2.
+    pub fn middle(bucket: u8) -> Self {
+        LexoRank::from_parts(bucket, "hzzzzz")
+    }
+
+    /// Parses a rank.
+    ///
+    /// # Returns
+    ///
+    /// The rank, or a [`ParseLexoRankError`] if the bucket is not 0, 1 or 2, or the rest is not six
+    /// lowercase base-36 digits, a `:` and more base-36 digits. Trailing zeros of the decimal part are dropped.
+    pub fn parse(rank: &str) -> Result<Self, ParseLexoRankError> {
+        let (bucket, value) = rank
+            .split_once('|')
+            .ok_or(ParseLexoRankError::InvalidFormat)?;
+        let bucket = match bucket {
+            "0" => 0,
+            "1" => 1,
+            "2" => 2,
+            _ => return Err(ParseLexoRankError::InvalidBucket),
+        };
+        let (integer, decimal) = value
+            .split_once(':')
+            .ok_or(ParseLexoRankError::InvalidFormat)?;
+        let is_digits = |part: &str| part.chars().all(|c| DIGITS.contains(c));
+        if integer.len() != INTEGER_LEN || !is_digits(integer) || !is_digits(decimal) {
+            return Err(ParseLexoRankError::InvalidFormat);
+        }
+        Ok(LexoRank {
+            bucket,
+            integer: integer.to_string(),
+            decimal: decimal.trim_end_matches('0').to_string(),
+        })
+    }
+
+    /// Returns the bucket of the rank.
+    pub fn bucket(&self) -> u8 {
+        self.bucket
+    }
+
+    /// Returns the bucket ranks move to when a list in this rank's bucket is rebalanced.
+    pub fn next_bucket(&self) -> u8 {
+        (self.bucket + 1) % BUCKETS
+    }
+
+    /// Generates a rank after this one, moving the integer part up by 8 while there is room.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<LexoRank>` that contains the new rank, or `None` if this is the highest rank of its bucket.
+    pub fn gen_next(&self) -> Option<Self> {
+        let max = LexoRank::max(self.bucket);
+        let integer = self.integer_value() + STEP;
+        if integer < max.integer_value() {
+            return Some(LexoRank::from_parts(self.bucket, &format_integer(integer)));
+        }
+        self.between(&max)
+    }
+
+    /// Generates a rank before this one, moving the integer part down by 8 while there is room.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<LexoRank>` that contains the new rank, or `None` if this is the lowest rank of its bucket.
+    pub fn gen_prev(&self) -> Option<Self> {
+        let min = LexoRank::min(self.bucket);
+        match self.integer_value().checked_sub(STEP) {
+            Some(integer) if integer > 0 => {
+                Some(LexoRank::from_parts(self.bucket, &format_integer(integer)))
+            }
+            _ => self.between(&min),
+        }
+    }
+
+    /// Generates a rank between this one and another one of the same bucket, in either order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<LexoRank>` that contains the new rank, or `None` if the ranks are equal or in different
+    /// buckets.
+    pub fn between(&self, other: &LexoRank) -> Option<Self> {
+        if self.bucket != other.bucket {
+            return None;
+        }
+        let (low, high) = if self < other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let value = Between::base36().between(low.digits(), high.digits())?;
+        Some(LexoRank::from_digits(self.bucket, &value))
+    }
+
+    /// Rebalances a list of ranks by moving them, evenly spaced, to the next bucket.
+    ///
+    /// # Arguments
+    ///
+    /// * `ranks` - The ranks of the list, in ascending order and all in the same bucket.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<LexoRank>>` that contains the new ranks, in the same order, or `None` if the ranks are
+    /// not strictly ascending or not all in the same bucket. An empty list stays empty.
+    pub fn rebalance(ranks: &[LexoRank]) -> Option<Vec<LexoRank>> {
+        let first = match ranks.first() {
+            Some(first) => first,
+            None => return Some(Vec::new()),
+        };
+        if ranks.iter().any(|rank| rank.bucket != first.bucket)
+            || ranks.windows(2).any(|pair| pair[0] >= pair[1])
+        {
+            return None;
+        }
+        let bucket = first.next_bucket();
+        let values = Between::base36().n_between("", "zzzzzz", ranks.len())?;
+        Some(
+            values
+                .iter()
+                .map(|value| LexoRank::from_digits(bucket, value))
+                .collect(),
+        )
+    }
+
+    fn from_parts(bucket: u8, integer: &str) -> Self {
+        assert!(bucket < BUCKETS, "Expect a LexoRank bucket of 0, 1 or 2.");
+        LexoRank {
+            bucket,
+            integer: integer.to_string(),
+            decimal: String::new(),
+        }
+    }
+
+    /// Splits the digits of a value into an integer and a decimal part.
+    fn from_digits(bucket: u8, digits: &str) -> Self {
+        let split = digits.len().min(INTEGER_LEN);
+        let mut rank = LexoRank::from_parts(
+            bucket,
+            &format!("{:0<width$}", &digits[..split], width = INTEGER_LEN),
+        );
+        rank.decimal = digits[split..].to_string();
+        rank
+    }
+
+    /// Returns the digits of the rank's value, as a key of the base-36 alphabet.
+    fn digits(&self) -> String {
+        format!("{}{}", self.integer, self.decimal)
+    }
+
+    fn integer_value(&self) -> u64 {
+        u64::from_str_radix(&self.integer, 36).unwrap()
+    }
+}
+
+impl fmt::Display for LexoRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}|{}:{}", self.bucket, self.integer, self.decimal)
+    }
+}
+
+impl FromStr for LexoRank {
+    type Err = ParseLexoRankError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LexoRank::parse(s)
+    }
+}
+
+/// An error returned when a string cannot be parsed into a [`LexoRank`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseLexoRankError {
+    /// The bucket was not 0, 1 or 2.
+    InvalidBucket,
+    /// The string was not of the form `bucket|integer:decimal`.
+    InvalidFormat,
+}
+
+impl fmt::Display for ParseLexoRankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLexoRankError::InvalidBucket => f.write_str("invalid LexoRank bucket"),
+            ParseLexoRankError::InvalidFormat => f.write_str("invalid LexoRank format"),
+        }
+    }
+}
+
+impl Error for ParseLexoRankError {}
+
+fn format_integer(value: u64) -> String {
+    let mut digits = vec![b'0'; INTEGER_LEN];
+    let mut value = value;
+    for digit in digits.iter_mut().rev() {
+        *digit = DIGITS.as_bytes()[(value % 36) as usize];
+        value /= 36;
+    }
+    String::from_utf8(digits).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LexoRank, ParseLexoRankError};
+
+    #[test]
+    fn test_parse_and_format() {
+        for rank in ["0|hzzzzz:", "1|000000:i", "2|zzzzzz:a0b"] {
+            assert_eq!(LexoRank::parse(rank).unwrap().to_string(), rank);
+        }
+        assert_eq!(
+            LexoRank::parse("1|i00000:a00").unwrap().to_string(),
+            "1|i00000:a"
+        );
+
+        assert_eq!(
+            LexoRank::parse("3|hzzzzz:"),
+            Err(ParseLexoRankError::InvalidBucket)
+        );
+        for rank in ["0|hzzzz:", "0|hzzzzz", "0|HZZZZZ:", "hzzzzz:", "0|hzzzzz:!"] {
+            assert_eq!(
+                LexoRank::parse(rank),
+                Err(ParseLexoRankError::InvalidFormat)
+            );
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let middle = LexoRank::middle(0);
+        assert_eq!(middle.gen_next().unwrap().to_string(), "0|i00007:");
+        assert_eq!(middle.gen_prev().unwrap().to_string(), "0|hzzzzr:");
+
+        let next = LexoRank::parse("0|i00000:").unwrap();
+        let tween = middle.between(&next).unwrap();
+        assert!(middle < tween && tween < next);
+        assert_eq!(next.between(&middle), Some(tween.clone()));
+        assert!(tween.to_string() > middle.to_string() && tween.to_string() < next.to_string());
+
+        assert!(LexoRank::max(0).gen_next().is_none());
+        assert!(LexoRank::min(0).gen_prev().is_none());
+        let near_max = LexoRank::parse("0|zzzzzy:").unwrap().gen_next().unwrap();
+        assert!(near_max < LexoRank::max(0));
+        assert!(middle.between(&LexoRank::middle(1)).is_none());
+    }
+
+    #[test]
+    fn test_rebalance() {
+        // Inserting right after the same rank over and over grows the decimal part.
+        let first = LexoRank::middle(2);
+        let mut ranks = vec![first.clone(), first.gen_next().unwrap()];
+        for _ in 0..100 {
+            let rank = first.between(&ranks[1]).unwrap();
+            ranks.insert(1, rank);
+        }
+        assert!(ranks.iter().any(|rank| rank.to_string().len() > 20));
+
+        let rebalanced = LexoRank::rebalance(&ranks).unwrap();
+        assert_eq!(rebalanced.len(), ranks.len());
+        assert!(rebalanced.iter().all(|rank| rank.bucket() == 0));
+        assert!(rebalanced.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(rebalanced.iter().all(|rank| rank.to_string().len() == 9));
+
+        assert!(LexoRank::rebalance(&[LexoRank::middle(0), LexoRank::middle(1)]).is_none());
+        assert!(LexoRank::rebalance(&[]).unwrap().is_empty());
+    }
+}
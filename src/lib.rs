@@ -1,34 +1,206 @@
-use std::cmp;
-use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
-use std::fmt::Debug;
-use std::iter::FromIterator;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::sync::Arc;
+use core::cmp;
+use core::cmp::Ordering;
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+
+mod appender;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+mod audit;
+#[cfg(feature = "between2")]
+pub mod between2;
+#[cfg(feature = "bincode")]
+mod bincode_impl;
+mod buffer;
+mod builder;
+mod bulk;
+mod bytes;
+mod checksum;
+mod collation;
+mod compatibility;
+mod composite;
+#[cfg(feature = "diesel")]
+mod diesel_impl;
+mod digits;
+mod duplicates;
+mod encoding;
+mod error;
+mod forbidden;
+mod fraction;
+pub mod fractional_indexing;
+mod gap;
+#[cfg(feature = "graphemes")]
+mod graphemes;
+pub mod hlc;
+mod iter;
+mod key;
+#[cfg(feature = "std")]
+mod keyspace;
+pub mod lexorank;
+mod list;
+mod map;
+mod merge;
+mod merge_alphabets;
+mod mudder;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod position;
+mod postgres;
+mod prefix;
+mod prepender;
+mod presets;
+mod profile;
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl;
+#[cfg(feature = "rand")]
+mod random;
+mod ranges;
+mod rank;
+mod rebalance;
+#[cfg(feature = "redb")]
+mod redb_impl;
+mod replica;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+#[cfg(feature = "schemars")]
+mod schemars_impl;
+#[cfg(feature = "sea-orm")]
+mod sea_orm_impl;
+mod seeded;
+mod sequence;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod shortest;
+mod simulate;
+mod slice;
+#[cfg(feature = "sqlx")]
+mod sqlx_impl;
+mod step;
+mod store;
+mod strategy;
+mod stream;
+mod symbol;
+mod symbols;
+mod timestamp;
+mod tokens;
+mod transcode;
+mod transport;
+mod versioned;
+
+/// The `alloc` items the standard prelude provides, for `no_std` builds.
+mod prelude {
+    pub(crate) use alloc::borrow::ToOwned;
+    pub(crate) use alloc::boxed::Box;
+    pub(crate) use alloc::format;
+    pub(crate) use alloc::string::{String, ToString};
+    pub(crate) use alloc::vec;
+    pub(crate) use alloc::vec::Vec;
+}
 
-use itertools::Itertools;
+pub use crate::appender::Appender;
+pub use crate::audit::AuditReport;
+pub use crate::builder::{AlphabetBuilder, AlphabetError};
+pub use crate::bytes::ByteBetween;
+pub use crate::checksum::{ChecksumError, Checksummed};
+pub use crate::collation::CollationMismatch;
+pub use crate::compatibility::Compatibility;
+pub use crate::composite::{CompositeBetween, CompositeKey};
+pub use crate::error::BetweenError;
+#[cfg(feature = "graphemes")]
+pub use crate::graphemes::GraphemeBetween;
+#[cfg(feature = "rkyv")]
+pub use crate::key::ArchivedKey;
+pub use crate::key::{AsKey, Key, ParseKeyError};
+#[cfg(feature = "std")]
+pub use crate::keyspace::{KeySpace, KeySpaceError};
+pub use crate::list::OrderedKeyList;
+pub use crate::map::OrderedMap;
+pub use crate::merge_alphabets::AlphabetMerge;
+pub use crate::mudder::Mudder;
+pub use crate::postgres::PostgresSafety;
+pub use crate::prefix::Prefixed;
+pub use crate::prepender::Prepender;
+pub use crate::profile::{KeyspaceProfile, ProfileAlphabet, ProfileError};
+#[cfg(feature = "quickcheck")]
+pub use crate::quickcheck_impl::KeyPair;
+pub use crate::ranges::{KeyRangeError, KeyRanges};
+pub use crate::replica::Replica;
+pub use crate::sequence::SequenceBetween;
+#[cfg(feature = "serde")]
+pub use crate::serde_impl::KeySeed;
+pub use crate::simulate::{SimulationReport, Workload};
+pub use crate::slice::SliceError;
+pub use crate::store::StoreKeys;
+#[cfg(feature = "rand")]
+pub use crate::strategy::Randomized;
+pub use crate::strategy::{Bisect, PreferLeft, PreferRight, PreferShort, Strategy};
+pub use crate::symbol::Symbol;
+pub use crate::timestamp::Timestamped;
+pub use crate::tokens::TokenBetween;
+pub use crate::transport::Transport;
+pub use crate::versioned::{VersionError, Versioned};
+pub use num_bigint::BigUint;
 
 /// A struct that provides functionality to find a string that is lexicographically
 /// between two given strings, using a specified set of characters.
+///
+/// The algorithm works on any ordered symbol type `S`, such as `u8`, `u32` code points or an application's own
+/// symbols, through [`Between::between_symbols`] and its companions. Strings are the default, `Between<char>`,
+/// and the rest of the API is specific to them.
+///
+/// Cloning is cheap: the alphabet and its lookup tables are shared between clones rather than copied, so an
+/// instance can be cloned per request or per task.
 #[derive(Debug, Clone)]
-pub struct Between {
-    chars: Vec<char>,
-    chars_set: HashSet<char>,
-    chars_lookup: HashMap<char, usize>,
-    high: char,
-    low: char,
+pub struct Between<S = char> {
+    alphabet: Arc<Alphabet<S>>,
+    high: S,
+    low: S,
+    strategy: Arc<dyn Strategy>,
+    max_len: Option<usize>,
+    forbidden: Arc<[String]>,
 }
 
-impl Between {
+/// The sorted symbols of a [`Between`] and the tables to look up their positions, shared between clones.
+#[derive(Debug)]
+struct Alphabet<S> {
+    chars: Vec<S>,
+    /// Positions by symbol. Without the `std` feature, symbols are found by binary search instead.
+    #[cfg(feature = "std")]
+    lookup: HashMap<S, usize>,
+    table: Option<Box<[Option<u8>; 256]>>,
+    midpoints: Option<Box<[u8]>>,
+}
+
+/// The largest alphabet whose midpoints are precomputed, keeping the table within 4 KiB.
+const MIDPOINT_TABLE_LEN: usize = 64;
+
+impl<S> Between<S>
+where
+    S: Symbol,
+{
     /// Creates a new `Between` instance with a given set of characters.
     ///
     /// # Arguments
     ///
-    /// * `chars` - A vector of characters to be used for generating between strings.
+    /// * `chars` - A vector of characters (or other symbols) to be used for generating between strings.
     ///
     /// # Panics
     ///
     /// Panics if the provided character set has fewer than two distinct characters.
-    pub fn new(chars: Vec<char>) -> Self {
-        let chars: Vec<char> = chars.into_iter().unique().sorted_unstable().collect();
+    pub fn new(chars: Vec<S>) -> Self {
+        let mut chars = chars;
+        chars.sort_unstable();
+        chars.dedup();
         assert!(
             chars.len() >= 2,
             "Expect chars to have at least two distinct characters."
@@ -36,40 +208,217 @@ impl Between {
         let low = chars.first().unwrap();
         let high = chars.last().unwrap();
 
-        let mut chars_lookup: HashMap<char, usize> = HashMap::new();
+        #[cfg(feature = "std")]
+        let mut chars_lookup: HashMap<S, usize> = HashMap::new();
+        #[cfg(feature = "std")]
         for (index, c) in chars.iter().enumerate() {
             chars_lookup.insert(*c, index);
         }
 
+        // Alphabets of symbols that fit in a byte, such as ASCII ones, are looked up without hashing.
+        let mut table = Box::new([None; 256]);
+        let chars_table = chars
+            .iter()
+            .enumerate()
+            .all(|(index, c)| match c.byte() {
+                Some(byte) => {
+                    table[byte as usize] = Some(index as u8);
+                    true
+                }
+                None => false,
+            })
+            .then_some(table);
+
+        // Small alphabets look up the midpoint of every pair of positions instead of computing it.
+        let base = chars.len();
+        let midpoints = (base <= MIDPOINT_TABLE_LEN).then(|| {
+            (0..base * base)
+                .map(|pair| midpoint(pair / base, pair % base) as u8)
+                .collect()
+        });
+
         Between {
             high: *high,
             low: *low,
-            chars_set: chars.iter().cloned().collect(),
-            chars_lookup,
-            chars,
+            alphabet: Arc::new(Alphabet {
+                #[cfg(feature = "std")]
+                lookup: chars_lookup,
+                table: chars_table,
+                midpoints,
+                chars,
+            }),
+            strategy: Arc::new(Bisect),
+            max_len: None,
+            forbidden: Arc::new([]),
         }
     }
 
-    /// Initializes a `Between` instance with a default set of characters.
-    pub fn init() -> Self {
-        Default::default()
-    }
-
     /// Returns a reference to the vector of characters used by this instance.
-    pub fn chars(&self) -> &Vec<char> {
-        &self.chars
+    pub fn chars(&self) -> &Vec<S> {
+        &self.alphabet.chars
     }
 
     /// Returns the highest character in the character set.
-    pub fn high(&self) -> char {
+    pub fn high(&self) -> S {
         self.high
     }
 
     /// Returns the lowest character in the character set.
-    pub fn low(&self) -> char {
+    pub fn low(&self) -> S {
         self.low
     }
 
+    /// Checks if a key given as symbols is valid, i.e., is non-empty and contains only symbols from the set.
+    pub fn valid_symbols(&self, key: &[S]) -> bool {
+        !key.is_empty() && key.iter().all(|symbol| self.position(*symbol).is_some())
+    }
+
+    /// Finds a key, given as symbols, that sorts between two keys.
+    ///
+    /// Keys are compared symbol by symbol, like strings are compared character by character, and the new key
+    /// is found by bisecting as the [`Bisect`] strategy does. Trailing low symbols are ignored, and the maximum
+    /// length does not apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or an empty slice for the start of the keyspace.
+    /// * `that` - The second key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<S>>` that contains the between key if possible, or `None` if a key contains symbols
+    /// outside the set or `this` does not sort before `that`.
+    pub fn between_symbols(&self, this: &[S], that: &[S]) -> Option<Vec<S>> {
+        let between = self.between_positions(this, that)?;
+        Some(
+            between
+                .into_iter()
+                .map(|index| self.alphabet.chars[index])
+                .collect(),
+        )
+    }
+
+    /// Finds a key, given as symbols, that sorts after a given key. See [`Between::between_symbols`].
+    pub fn after_symbols(&self, key: &[S]) -> Option<Vec<S>> {
+        self.between_symbols(key, &[self.high])
+    }
+
+    /// Finds a key, given as symbols, that sorts before a given key. See [`Between::between_symbols`].
+    pub fn before_symbols(&self, key: &[S]) -> Option<Vec<S>> {
+        self.between_symbols(&[self.low], key)
+    }
+
+    /// Bisects two keys, given as symbols, into the positions of the key between them.
+    fn between_positions(&self, this: &[S], that: &[S]) -> Option<Positions> {
+        let this = self.positions(this)?;
+        let that = self.positions(that)?;
+        bisect_positions_with(self.alphabet.chars.len(), &this, &that, |this, that| {
+            self.midpoint(this, that)
+        })
+    }
+
+    /// Returns the middle of two positions in the set, rounding halves up.
+    pub(crate) fn midpoint(&self, this: usize, that: usize) -> usize {
+        match &self.alphabet.midpoints {
+            Some(midpoints) => usize::from(midpoints[this * self.alphabet.chars.len() + that]),
+            None => midpoint(this, that),
+        }
+    }
+
+    /// Returns the positions of a key's symbols in the set, without trailing low symbols.
+    fn positions(&self, key: &[S]) -> Option<Positions> {
+        let end = key
+            .iter()
+            .rposition(|symbol| *symbol != self.low)
+            .map_or(0, |position| position + 1);
+        key[..end]
+            .iter()
+            .map(|symbol| self.position(*symbol))
+            .collect()
+    }
+
+    /// Returns the position of a symbol in the set, or `None` if it is not in the set.
+    pub(crate) fn position(&self, symbol: S) -> Option<usize> {
+        match &self.alphabet.table {
+            Some(table) => symbol
+                .byte()
+                .and_then(|byte| table[byte as usize])
+                .map(usize::from),
+            #[cfg(feature = "std")]
+            None => self.alphabet.lookup.get(&symbol).copied(),
+            #[cfg(not(feature = "std"))]
+            None => self.alphabet.chars.binary_search(&symbol).ok(),
+        }
+    }
+}
+
+impl Between {
+    /// Limits the length of strings generated by [`Between::between`], [`Between::after`] and
+    /// [`Between::before`], for example to fit a database column.
+    ///
+    /// When the strategy picks a string that is too long, the shortest string in the gap is used instead.
+    /// When even that is too long, no string is generated; [`Between::try_between`] reports this as
+    /// [`BetweenError::KeyspaceExhausted`].
+    ///
+    /// # Arguments
+    ///
+    /// * `max_len` - The maximum length, counted in characters.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Returns the maximum length of generated strings, if any.
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Sets the strategy that [`Between::between`], [`Between::after`] and [`Between::before`] use to decide
+    /// where new strings land.
+    ///
+    /// Built-in strategies are serialized along with the alphabet by name; custom strategies have no name and
+    /// cannot be serialized.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The strategy to use.
+    pub fn with_strategy<T>(mut self, strategy: T) -> Self
+    where
+        T: Strategy + 'static,
+    {
+        self.strategy = Arc::new(strategy);
+        self
+    }
+
+    /// Returns the strategy used by this instance.
+    pub fn strategy(&self) -> &dyn Strategy {
+        self.strategy.as_ref()
+    }
+
+    /// Rebuilds a `Between` from the parts the binary formats encode it as, checking them the way
+    /// deserializing with serde does.
+    #[cfg(any(feature = "bincode", feature = "rkyv"))]
+    pub(crate) fn from_config(
+        chars: Vec<char>,
+        strategy: &str,
+        max_len: Option<usize>,
+        forbidden: Vec<String>,
+    ) -> Result<Self, &'static str> {
+        if !chars.iter().any(|c| Some(c) != chars.first()) {
+            return Err("Expect chars to have at least two distinct characters.");
+        }
+        let strategy = strategy::named(strategy).ok_or("Unknown strategy.")?;
+        let mut between = Between::new(chars).with_forbidden(forbidden);
+        between.strategy = strategy;
+        between.max_len = max_len;
+        Ok(between)
+    }
+
+    /// Initializes a `Between` instance with a default set of characters.
+    pub fn init() -> Self {
+        Default::default()
+    }
+
     /// Checks if a given string is valid, i.e., contains only characters from the character set.
     ///
     /// # Arguments
@@ -81,22 +430,59 @@ impl Between {
     /// `true` if the string is valid, `false` otherwise.
     pub fn valid<S>(&self, string: S) -> bool
     where
-        S: Into<String>,
+        S: AsKey,
     {
-        let string: String = string.into();
+        let string = string.as_key();
         if string.is_empty() {
             return false;
         }
-        for c in string.chars() {
-            if !self.chars_set.contains(&c) {
-                return false;
+        string.chars().all(|c| self.position(c).is_some())
+    }
+
+    /// Returns a regular expression matching exactly the strings accepted by [`Between::valid`].
+    ///
+    /// The pattern uses ECMA-262 syntax, as expected by JSON Schema, and collapses runs of
+    /// consecutive characters into ranges (e.g. `^[!0-9A-Z_a-z~]+$` for the default alphabet).
+    pub fn pattern(&self) -> String {
+        fn push_escaped(class: &mut String, c: char) {
+            if matches!(c, '\\' | ']' | '[' | '^' | '-') {
+                class.push('\\');
             }
+            class.push(c);
         }
-        true
+
+        let mut class = String::new();
+        let mut index = 0;
+        while index < self.alphabet.chars.len() {
+            // Find the end of the run of consecutive code points starting at 'index'.
+            let mut end = index;
+            while end + 1 < self.alphabet.chars.len()
+                && (self.alphabet.chars[end] as u32) + 1 == self.alphabet.chars[end + 1] as u32
+            {
+                end += 1;
+            }
+
+            push_escaped(&mut class, self.alphabet.chars[index]);
+            if end - index >= 2 {
+                class.push('-');
+                push_escaped(&mut class, self.alphabet.chars[end]);
+            } else if end > index {
+                push_escaped(&mut class, self.alphabet.chars[end]);
+            }
+            index = end + 1;
+        }
+
+        format!("^[{}]+$", class)
     }
 
     /// Finds a string that is lexicographically between two given strings.
     ///
+    /// Where the string lands in the gap is decided by this instance's [`Strategy`], which defaults to
+    /// [`Bisect`]. Strings containing a substring forbidden by [`Between::with_forbidden`] are avoided.
+    ///
+    /// The bounds can be anything [`AsKey`] accepts. Strings are only borrowed, so `&str`, `String`, `&String`
+    /// and `Cow<str>` all work without copying the input, and single characters work too.
+    ///
     /// # Arguments
     ///
     /// * `this` - The first string.
@@ -107,159 +493,155 @@ impl Between {
     /// An `Option<String>` that contains the between string if possible, or `None` if not.
     pub fn between<S, T>(&self, this: S, that: T) -> Option<String>
     where
-        S: Into<String>,
-        T: Into<String>,
+        S: AsKey,
+        T: AsKey,
     {
-        // Convert the input parameters into Strings.
-        // This allows us to work uniformly with the data regardless of the input types.
-        let this: String = this.into();
-        let that: String = that.into();
-
-        // Trim any trailing occurrences of the lowest character from 'this' and 'that'.
-        // This step is crucial because trailing low characters can complicate comparisons.
-        // For instance, 'abc' and 'abc!' (if '!' is the lowest character) might not compare as expected.
-        let this: String = this.trim_end_matches(self.low).into();
-        let that: String = that.trim_end_matches(self.low).into();
-
-        // Validate the inputs:
-        // - Ensure 'this' is lexicographically less than 'that'.
-        // - Ensure both 'this' and 'that' are valid strings (contain only characters from 'self.chars').
-        // - We allow 'this' to be empty only if 'that' is valid and not empty.
-        if this.cmp(&that) != Ordering::Less
-            || (!this.is_empty() && !self.valid(&this))
-            || !self.valid(&that)
-        {
-            // If any of the above conditions are not met, we cannot find a 'between' string.
-            // Return 'None' to indicate that no valid string can be generated.
-            return None;
+        let (this, that) = (this.as_key(), that.as_key());
+        self.between_using(self.strategy.as_ref(), &this, &that)
+    }
+
+    /// Finds a string between two given strings with a strategy, within the maximum length and avoiding
+    /// forbidden substrings.
+    pub(crate) fn between_using<St>(&self, strategy: &St, this: &str, that: &str) -> Option<String>
+    where
+        St: Strategy + ?Sized,
+    {
+        let key = self.generate(strategy, this, that)?;
+        if self.forbidden.is_empty() {
+            return Some(key);
         }
+        self.avoid_forbidden(this, that, key, |lower, upper| {
+            self.generate(strategy, lower, upper)
+        })
+    }
 
-        // invariant: this < that (in lexographical order/ASCII order)
-        //
-        // - In lexicographical order/ASCII order, you compare character by character on each string until a difference
-        //   is found.
-        // - The ASCII value of the characters are used for the comparison.
-        // - A lower ASCII value means the character is less than the other character with a higher ASCII value.
-        //   In other words, the character is deemed lexicographically smaller than the other character.
-        // - If the characters are the same, then you move on to the next character in the string.
-        // - In lexicographical order, if a string is a prefix of another string (meaning it matches the beginning of
-        //   the longer string), it's considered "smaller".
-
-        // At this point, we have two valid strings 'this' and 'that', with 'this' < 'that'.
-        // Our goal is to construct a new string 'between_string' that is lexicographically
-        // between 'this' and 'that', using only characters from 'self.chars'.
-
-        // Convert 'this' and 'that' into vectors of characters for easier indexing and comparison.
-        let this_chars: Vec<char> = this.chars().collect();
-        let that_chars: Vec<char> = that.chars().collect();
-
-        // Initialize an empty vector to build the 'between_string'.
-        let mut between_string: Vec<char> = vec![];
-
-        // Set up a guard to prevent infinite loops.
-        // The maximum number of iterations is the sum of the lengths of 'this' and 'that'.
-        // This ensures that the loop will terminate even in edge cases.
-        let guard = this.len() + that.len();
+    /// Finds a string between two given strings with a strategy, within the maximum length.
+    fn generate<St>(&self, strategy: &St, this: &str, that: &str) -> Option<String>
+    where
+        St: Strategy + ?Sized,
+    {
+        let key = strategy.between(self, this, that)?;
+        match self.max_len {
+            Some(max_len) if key.chars().count() > max_len => self
+                .shortest_between(this, that)
+                .filter(|key| key.chars().count() <= max_len),
+            _ => Some(key),
+        }
+    }
 
-        // Determine the maximum length between 'this' and 'that'.
-        // This helps us decide when we might need to consider adding new characters.
-        let guard_max_len = cmp::max(this.len(), that.len());
+    /// Finds a string that is lexicographically between two given strings, reporting why if there is none.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// The between string, or a [`BetweenError`] that tells invalid bounds apart from a gap with no room left
+    /// within the maximum length.
+    pub fn try_between<S, T>(&self, this: S, that: T) -> Result<String, BetweenError>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = (this.as_key(), that.as_key());
+        if self.bounds(&this, &that).is_none() {
+            return Err(BetweenError::InvalidBounds);
+        }
+        match self.between(&this, &that) {
+            Some(key) => Ok(key),
+            None => Err(BetweenError::KeyspaceExhausted {
+                this: this.to_string(),
+                that: that.to_string(),
+                max_len: self.max_len,
+            }),
+        }
+    }
 
-        // Initialize the index to 0, to start processing from the first character.
-        let mut index = 0;
+    /// Finds a string between two given strings by bisecting them character by character.
+    ///
+    /// This is the algorithm behind the [`Bisect`] strategy.
+    pub(crate) fn bisect(&self, this: &str, that: &str) -> Option<String> {
+        if (self.high as u32) < 128 {
+            let mut between = String::new();
+            return self
+                .bisect_ascii_into(this.as_bytes(), that.as_bytes(), &mut between)
+                .then_some(between);
+        }
+        let this: SmallVec<[char; INLINE_LEN]> = this.chars().collect();
+        let that: SmallVec<[char; INLINE_LEN]> = that.chars().collect();
+        let between = self.between_positions(&this, &that)?;
+        Some(
+            between
+                .into_iter()
+                .map(|index| self.alphabet.chars[index])
+                .collect(),
+        )
+    }
 
-        // Begin iterating over the characters to build 'between_string'.
-        while index <= guard {
-            // For the current index, get the character positions in 'self.chars' for both 'this' and 'that'.
-
-            let this_char_position: usize = {
-                // Attempt to get the character from 'this' at the current index.
-                // If 'this' is shorter than the current index, we default to 'self.low' (lowest character).
-                let this_char = this_chars.get(index).unwrap_or(&self.low);
-                // Look up the index of 'this_char' in our character set.
-                // Since 'this' is valid, this should not fail.
-                *self.chars_lookup.get(this_char).unwrap()
-            };
+    /// Bisects two strings like `bisect`, appending the result to `out`.
+    ///
+    /// Returns `false` and leaves `out` untouched if there is no string between the bounds.
+    pub(crate) fn bisect_into(&self, this: &str, that: &str, out: &mut String) -> bool {
+        if (self.high as u32) < 128 {
+            return self.bisect_ascii_into(this.as_bytes(), that.as_bytes(), out);
+        }
+        match self.bisect(this, that) {
+            Some(between) => {
+                out.push_str(&between);
+                true
+            }
+            None => false,
+        }
+    }
 
-            let that_char_position: usize = {
-                // Similarly, attempt to get the character from 'that' at the current index.
-                // If 'that' is shorter than the current index, we default to 'self.high' (highest character).
-                let that_char = that_chars.get(index).unwrap_or(&self.high);
-                // Look up the index of 'that_char' in our character set.
-                *self.chars_lookup.get(that_char).unwrap()
-            };
+    /// Bisects two strings of an ASCII alphabet byte by byte, appending the result to `out` in place.
+    ///
+    /// This is [`bisect_positions`] without converting the strings to positions and back.
+    fn bisect_ascii_into(&self, this: &[u8], that: &[u8], out: &mut String) -> bool {
+        let table = match self.alphabet.table.as_ref() {
+            Some(table) => table,
+            None => return false,
+        };
+        let position = |byte: &u8| table[*byte as usize].map(usize::from);
+        let trim = |key: &'_ [u8]| -> usize {
+            key.iter()
+                .rposition(|byte| *byte != self.low as u8)
+                .map_or(0, |end| end + 1)
+        };
+        let this = &this[..trim(this)];
+        let that = &that[..trim(that)];
+        if this >= that
+            || that.is_empty()
+            || !this.iter().chain(that).all(|byte| position(byte).is_some())
+        {
+            return false;
+        }
 
-            // Now, 'this_char_position' and 'that_char_position' represent the positions of the characters
-            // at the current index in 'this' and 'that' within our character set 'self.chars'.
-            // Since 'this' is less than 'that', we should have 'this_char_position' <= 'that_char_position'.
-
-            // Our aim is to select a character to add to 'between_string' that will help us
-            // construct a string that is lexicographically between 'this' and 'that'.
-
-            // invariant: this_char_position <= that_char_position
-
-            let char_candidate: char = {
-                // If there are characters between this_char_position and that_char_position,
-                // then we can pick the midpoint of the character candidate between them.
-                // We also do this if we go past the maximum length of of either this or that.
-
-                // Determine the position of the candidate character to add.
-                let char_position: usize = if ((this_char_position + 1) < that_char_position)
-                    // If there are characters available between 'this_char_position' and 'that_char_position':
-                    // - This means we can choose a character that is greater than 'this_char' but less than 'that_char'.
-                    || index >= guard_max_len
-                // Or if we've reached beyond the maximum length of 'this' and 'that':
-                // - This allows us to append additional characters to make 'between_string' greater than 'this'.
-                {
-                    // invariant: self.chars.len() >= 2
-                    // If (this_char_position + 1) < that_char_position, then:
-                    //    0 <= this_char_position <= max(self.chars.len() - 3, 0)
-                    //    2 <= that_char_position <= self.chars.len() - 1
-                    // This implies self.chars.len() >= 3. As in, this works for character sets of size 3 or more.
-                    //
-                    // For 2 character sets, we rely on: index >= guard_max_len
-
-                    // Calculate the midpoint between 'this_char_position' and 'that_char_position'.
-                    // We use the average and round it to the nearest integer to select a middle character.
-                    ((this_char_position as f64 + that_char_position as f64) / 2.0).round() as usize
-                } else {
-                    // We use this_char_position so that the character candidate will be less than that_char_position
-                    // in lexicographical order/ASCII order.
-
-                    // If there are no characters in between, and we're still within the lengths,
-                    // we use 'this_char_position' to keep 'between_string' as close as possible to 'this'.
-                    this_char_position
-                };
-
-                // Retrieve the character at 'char_position' from 'self.chars'.
-                // This is our candidate character to add to 'between_string'.
-                self.chars[char_position]
+        let base = self.alphabet.chars.len();
+        let guard = this.len() + that.len();
+        let guard_max_len = cmp::max(this.len(), that.len());
+        let start = out.len();
+        out.reserve(guard_max_len + 1);
+        for index in 0..=guard {
+            let this_position = this.get(index).map_or(0, |byte| position(byte).unwrap());
+            let that_position = that
+                .get(index)
+                .map_or(base - 1, |byte| position(byte).unwrap());
+            let char_position = if this_position + 1 < that_position || index >= guard_max_len {
+                self.midpoint(this_position, that_position)
+            } else {
+                this_position
             };
-
-            // Add the candidate character to 'between_string'.
-            between_string.push(char_candidate);
-
-            // Now, we check if 'between_string' satisfies the conditions:
-            // - It is lexicographically greater than 'this_chars'.
-            // - It is lexicographically less than 'that_chars'.
-            // - The last character added is not 'self.low' (to avoid trailing low characters).
-            if (this_chars < between_string)
-                && (between_string < that_chars)
-                && char_candidate != self.low
-            {
-                // If all conditions are met, we have successfully found a valid 'between' string.
-                // Convert 'between_string' from a vector of chars back into a String and return it.
-                return Some(String::from_iter(between_string));
+            out.push(self.alphabet.chars[char_position]);
+            let between = &out.as_bytes()[start..];
+            if this < between && between < that && char_position != 0 {
+                return true;
             }
-
-            // If the conditions are not met, we proceed to the next index.
-            // This allows us to modify the next character in 'between_string' to try to satisfy the conditions.
-            index += 1;
         }
-
-        // If we have exhausted all possibilities within the guard limit and not found a valid 'between' string,
-        // we return 'None' to indicate failure.
-        None
+        out.truncate(start);
+        false
     }
 
     /// Finds a string that is lexicographically after a given string.
@@ -273,9 +655,10 @@ impl Between {
     /// An `Option<String>` that contains the successor string if possible, or `None` if not.
     pub fn after<S>(&self, before_string: S) -> Option<String>
     where
-        S: Into<String>,
+        S: AsKey,
     {
-        self.between(before_string, self.high)
+        let mut high = [0; 4];
+        self.between(before_string, self.high.encode_utf8(&mut high))
     }
 
     /// Finds a string that is lexicographically before a given string.
@@ -289,32 +672,183 @@ impl Between {
     /// An `Option<String>` that contains the predecessor string if possible, or `None` if not.
     pub fn before<S>(&self, after_string: S) -> Option<String>
     where
-        S: Into<String>,
+        S: AsKey,
+    {
+        let mut low = [0; 4];
+        self.between(self.low.encode_utf8(&mut low), after_string)
+    }
+
+    /// Finds a string that is lexicographically after a given string, reporting why if there is none.
+    ///
+    /// See [`Between::try_between`].
+    pub fn try_after<S>(&self, before_string: S) -> Result<String, BetweenError>
+    where
+        S: AsKey,
     {
-        self.between(self.low, after_string)
+        let mut high = [0; 4];
+        self.try_between(before_string, self.high.encode_utf8(&mut high))
+    }
+
+    /// Finds a string that is lexicographically before a given string, reporting why if there is none.
+    ///
+    /// See [`Between::try_between`].
+    pub fn try_before<S>(&self, after_string: S) -> Result<String, BetweenError>
+    where
+        S: AsKey,
+    {
+        let mut low = [0; 4];
+        self.try_between(self.low.encode_utf8(&mut low), after_string)
     }
 }
 
 impl Default for Between {
     /// Provides a default `Between` instance with a predefined set of characters.
     fn default() -> Self {
-        let default_chars: Vec<char> =
-            "!0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~"
-                .chars()
-                .collect();
+        let default_chars: Vec<char> = DEFAULT_CHARS.chars().collect();
         Between::new(default_chars)
     }
 }
 
+/// The characters of the default alphabet, in order.
+pub(crate) const DEFAULT_CHARS: &str =
+    "!0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz~";
+
+/// The number of symbols a working buffer holds before spilling to the heap. Most real keys are shorter.
+pub(crate) const INLINE_LEN: usize = 16;
+
+/// A working buffer of alphabet positions, kept inline for keys of up to [`INLINE_LEN`] symbols.
+pub(crate) type Positions = SmallVec<[usize; INLINE_LEN]>;
+
+/// Finds a key between two keys given as positions in a sorted set of `base` symbols, by bisecting them symbol
+/// by symbol.
+///
+/// `this` and `that` must not end with the lowest position, 0. This is the algorithm behind the [`Bisect`]
+/// strategy, for every kind of symbol.
+pub(crate) fn bisect_positions(base: usize, this: &[usize], that: &[usize]) -> Option<Positions> {
+    bisect_positions_with(base, this, that, midpoint)
+}
+
+/// Returns the middle of two positions, rounding halves up.
+fn midpoint(this: usize, that: usize) -> usize {
+    (this + that).div_ceil(2)
+}
+
+/// Finds a key between two keys like [`bisect_positions`], taking the midpoint of two positions from
+/// `midpoint`, which must agree with the function of the same name.
+fn bisect_positions_with<F>(
+    base: usize,
+    this: &[usize],
+    that: &[usize],
+    midpoint: F,
+) -> Option<Positions>
+where
+    F: Fn(usize, usize) -> usize,
+{
+    // Validate the inputs:
+    // - Ensure 'this' is lexicographically less than 'that'.
+    // - We allow 'this' to be empty only if 'that' is not empty.
+    if this.cmp(that) != Ordering::Less || that.is_empty() {
+        // If any of the above conditions are not met, we cannot find a 'between' key.
+        // Return 'None' to indicate that no valid key can be generated.
+        return None;
+    }
+
+    // invariant: this < that (in lexicographical order)
+    //
+    // - In lexicographical order, you compare symbol by symbol on each key until a difference is found.
+    // - Symbols compare by their positions in the sorted set: a lower position means a smaller symbol.
+    // - If the symbols are the same, then you move on to the next symbol in the key.
+    // - In lexicographical order, if a key is a prefix of another key (meaning it matches the beginning of
+    //   the longer key), it's considered "smaller".
+
+    // At this point, we have two valid keys 'this' and 'that', with 'this' < 'that'.
+    // Our goal is to construct a new key 'between' that is lexicographically between 'this' and 'that'.
+
+    // Initialize an empty buffer to build the 'between' key. Short keys are built without allocating.
+    let mut between = Positions::new();
+
+    // Set up a guard to prevent infinite loops.
+    // The maximum number of iterations is the sum of the lengths of 'this' and 'that'.
+    // This ensures that the loop will terminate even in edge cases.
+    let guard = this.len() + that.len();
+
+    // Determine the maximum length between 'this' and 'that'.
+    // This helps us decide when we might need to consider adding new symbols.
+    let guard_max_len = cmp::max(this.len(), that.len());
+
+    // Begin iterating over the symbols to build 'between', starting from the first one.
+    for index in 0..=guard {
+        // For the current index, get the symbol positions of both 'this' and 'that'.
+        // If 'this' is shorter than the current index, we default to the lowest symbol, and if 'that' is, to
+        // the highest one.
+        let this_position = this.get(index).copied().unwrap_or(0);
+        let that_position = that.get(index).copied().unwrap_or(base - 1);
+
+        // Since 'this' is less than 'that', we should have 'this_position' <= 'that_position'.
+        //
+        // Our aim is to select a symbol to add to 'between' that will help us construct a key that is
+        // lexicographically between 'this' and 'that'.
+
+        // invariant: this_position <= that_position
+
+        // If there are symbols between this_position and that_position, then we can pick the midpoint of
+        // them. We also do this if we go past the maximum length of either this or that.
+        let position: usize = if ((this_position + 1) < that_position)
+            // If there are symbols available between 'this_position' and 'that_position':
+            // - This means we can choose a symbol that is greater than this one but less than that one.
+            || index >= guard_max_len
+        // Or if we've reached beyond the maximum length of 'this' and 'that':
+        // - This allows us to append additional symbols to make 'between' greater than 'this'.
+        {
+            // invariant: base >= 2
+            // If (this_position + 1) < that_position, then:
+            //    0 <= this_position <= max(base - 3, 0)
+            //    2 <= that_position <= base - 1
+            // This implies base >= 3. As in, this works for symbol sets of size 3 or more.
+            //
+            // For 2 symbol sets, we rely on: index >= guard_max_len
+
+            // Calculate the midpoint between 'this_position' and 'that_position'.
+            // We use the average and round it to the nearest integer to select a middle symbol, rounding
+            // halves up. Integer arithmetic keeps this exact for any set size, and the sum cannot overflow
+            // since both positions are indices into the set. Small sets look it up in a precomputed table.
+            midpoint(this_position, that_position)
+        } else {
+            // If there are no symbols in between, and we're still within the lengths, we use
+            // 'this_position' to keep 'between' as close as possible to 'this', and less than 'that'.
+            this_position
+        };
+
+        // Add the candidate symbol to 'between'.
+        between.push(position);
+
+        // Now, we check if 'between' satisfies the conditions:
+        // - It is lexicographically greater than 'this'.
+        // - It is lexicographically less than 'that'.
+        // - The last symbol added is not the lowest one (to avoid trailing low symbols).
+        if this < &between[..] && &between[..] < that && position != 0 {
+            // If all conditions are met, we have successfully found a valid 'between' key.
+            return Some(between);
+        }
+
+        // If the conditions are not met, we proceed to the next index.
+        // This allows us to modify the next symbol in 'between' to try to satisfy the conditions.
+    }
+
+    // If we have exhausted all possibilities within the guard limit and not found a valid 'between' key,
+    // we return 'None' to indicate failure.
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter::FromIterator;
 
-    use crate::Between;
+    use crate::{Between, BetweenError, Symbol};
 
     #[test]
     fn panics_on_invalid_chars() {
-        let result = std::panic::catch_unwind(|| Between::new(vec![]));
+        let result = std::panic::catch_unwind(|| Between::<char>::new(vec![]));
         assert!(result.is_err());
 
         let result = std::panic::catch_unwind(|| Between::new(vec!['a']));
@@ -362,6 +896,15 @@ mod tests {
         assert!(between.valid("ab$c") == false);
     }
 
+    #[test]
+    fn test_pattern() {
+        let between = Between::init();
+        assert_eq!(between.pattern(), "^[!0-9A-Z_a-z~]+$");
+
+        let between = Between::new(vec!['a', 'b', '^', '\\']);
+        assert_eq!(between.pattern(), "^[\\\\\\^ab]+$");
+    }
+
     #[test]
     fn test_two_char_sets() {
         let between = Between::new("01".chars().collect());
@@ -380,7 +923,7 @@ mod tests {
         assert!(result == "0001", "{}", result);
         assert!(between.low().to_string() < result);
         assert!(result < between.high().to_string());
-        assert!("0" < &result);
+        assert!("0" < result.as_str());
         assert!(result < "001".to_string());
 
         assert!(between.between("001", '0').is_none());
@@ -639,6 +1182,77 @@ mod tests {
         assert_eq!(between.before("100").unwrap(), "05");
     }
 
+    #[test]
+    fn test_midpoint_rounds_half_up() {
+        let between = Between::new(vec!['a', 'b', 'c', 'd']);
+
+        // The midpoint of positions 0 and 3 is 1.5, which rounds up to 'c'.
+        assert_eq!(between.between("a", "d").unwrap(), "c");
+        assert_eq!(between.between("b", "d").unwrap(), "c");
+        assert_eq!(between.after("a").unwrap(), "c");
+    }
+
+    #[test]
+    fn test_max_len() {
+        let between = Between::init().with_max_len(2);
+        assert_eq!(between.max_len(), Some(2));
+
+        assert_eq!(between.between("A", "B").unwrap(), "AV");
+        assert_eq!(between.after("A~").unwrap(), "a");
+        // Bisecting would give "A~V", but a shorter string fits.
+        assert_eq!(between.between("Az", "B").unwrap(), "A~");
+        assert!(between.between("A~", "B").is_none());
+        assert!(between.before("!0").is_none());
+    }
+
+    #[test]
+    fn test_try_between() {
+        let between = Between::init().with_max_len(2);
+
+        assert_eq!(between.try_between("A", "B").unwrap(), "AV");
+        assert_eq!(between.try_after("A").unwrap(), "a");
+        assert_eq!(between.try_before("A").unwrap(), "5");
+        assert_eq!(
+            between.try_between("B", "A"),
+            Err(BetweenError::InvalidBounds)
+        );
+        assert_eq!(
+            between.try_between("A", "$"),
+            Err(BetweenError::InvalidBounds)
+        );
+
+        let error = between.try_between("A~", "B").unwrap_err();
+        assert_eq!(
+            error,
+            BetweenError::KeyspaceExhausted {
+                this: "A~".to_string(),
+                that: "B".to_string(),
+                max_len: Some(2)
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "no key of at most 2 characters between \"A~\" and \"B\""
+        );
+        assert!(matches!(
+            between.try_before("!0"),
+            Err(BetweenError::KeyspaceExhausted { .. })
+        ));
+
+        // Without a maximum length, the gap can still run out of strings without a forbidden substring.
+        let between = Between::new(vec!['a', 'b']).with_forbidden(["a", "b"]);
+        let error = between.try_between("a", "b").unwrap_err();
+        assert_eq!(
+            error,
+            BetweenError::KeyspaceExhausted {
+                this: "a".to_string(),
+                that: "b".to_string(),
+                max_len: None
+            }
+        );
+        assert_eq!(error.to_string(), "no key between \"a\" and \"b\"");
+    }
+
     #[test]
     fn test_unicode_characters() {
         let between = Between::new(vec!['α', 'β', 'γ', 'δ', 'ε']);
@@ -652,4 +1266,158 @@ mod tests {
         // Test before a Unicode string
         assert_eq!(between.before("α").is_none(), true);
     }
+
+    #[test]
+    fn test_generic_symbols() {
+        // Code points as integers give the same keys as the characters they stand for.
+        let chars = Between::init();
+        let code_points = Between::new(chars.chars().iter().map(|c| *c as u32).collect());
+        let to_code_points = |key: &str| key.chars().map(|c| c as u32).collect::<Vec<u32>>();
+        for (this, that) in [("A", "B"), ("", "V"), ("Az", "B!!1"), ("0", "1")] {
+            let expected = to_code_points(&chars.between(this, that).unwrap());
+            let actual = code_points
+                .between_symbols(&to_code_points(this), &to_code_points(that))
+                .unwrap();
+            assert_eq!(actual, expected);
+        }
+
+        // Application-defined symbols.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        enum Note {
+            C,
+            D,
+            E,
+            F,
+            G,
+        }
+        impl Symbol for Note {}
+        let notes = Between::new(vec![Note::G, Note::C, Note::E, Note::D, Note::F]);
+        assert_eq!((notes.low(), notes.high()), (Note::C, Note::G));
+        assert_eq!(
+            notes.between_symbols(&[Note::D], &[Note::F]).unwrap(),
+            vec![Note::E]
+        );
+        assert_eq!(
+            notes.between_symbols(&[Note::D], &[Note::E]).unwrap(),
+            vec![Note::D, Note::E]
+        );
+        assert_eq!(notes.after_symbols(&[]).unwrap(), vec![Note::E]);
+        assert!(notes.before_symbols(&[Note::C, Note::C]).is_none());
+        assert!(notes.valid_symbols(&[Note::C, Note::G]));
+        assert!(!notes.valid_symbols(&[]));
+    }
+
+    #[test]
+    fn test_lookup_table() {
+        // Alphabets of characters below U+0100 use the table, and others hash.
+        let ascii = Between::init();
+        let latin = Between::new(vec!['a', 'é', 'z', 'ÿ']);
+        let greek = Between::greek();
+        assert!(ascii.alphabet.table.is_some());
+        assert!(latin.alphabet.table.is_some());
+        assert!(greek.alphabet.table.is_none());
+        assert!(Between::new(vec![1u32, 1000]).alphabet.table.is_none());
+
+        assert_eq!(latin.between("a", "z").unwrap(), "aé");
+        assert!(latin.valid("ÿé"));
+        assert!(!latin.valid("b"));
+        assert!(!ascii.valid("é"));
+        assert!(!greek.valid("a"));
+        assert_eq!(greek.between("α", "γ").unwrap(), "β");
+    }
+
+    #[test]
+    fn test_ascii_fast_path_matches_symbols() {
+        for between in [
+            Between::init(),
+            Between::base10(),
+            Between::new(vec!['a', 'b']),
+        ] {
+            let mut keys: Vec<String> = between.after_iter("").take(40).collect();
+            keys.extend(between.before_iter(keys[0].as_str()).take(40));
+            keys.sort();
+            for (index, this) in keys.iter().enumerate() {
+                for that in keys.iter().skip(index + 1).step_by(7) {
+                    let this_chars: Vec<char> = this.chars().collect();
+                    let that_chars: Vec<char> = that.chars().collect();
+                    let expected = between
+                        .between_symbols(&this_chars, &that_chars)
+                        .map(String::from_iter);
+                    assert_eq!(between.bisect(this.as_str(), that.as_str()), expected);
+                }
+            }
+        }
+        assert!(Between::init().bisect("A", "$").is_none());
+        assert!(Between::init().bisect("B", "A").is_none());
+    }
+
+    #[test]
+    fn test_borrowed_bounds() {
+        use std::borrow::Cow;
+
+        let between = Between::init();
+        let this = String::from("A");
+        let that = Cow::Borrowed("B");
+        let key = between.between(&this, &that).unwrap();
+        assert_eq!(between.between(this.as_str(), that.clone()).unwrap(), key);
+        assert_eq!(between.between(this, String::from("B")).unwrap(), key);
+        assert!(between.valid(&key));
+        assert!(between.after(&key).unwrap() > key);
+        assert!(between.before(&key).unwrap() < key);
+    }
+
+    #[test]
+    fn test_keys_longer_than_inline_buffer() {
+        use crate::INLINE_LEN;
+
+        let greek = Between::greek();
+        let this: String = "α".repeat(INLINE_LEN * 2) + "β";
+        let that: String = "α".repeat(INLINE_LEN * 2) + "γ";
+        let key = greek.between(&this, &that).unwrap();
+        assert!(this < key && key < that);
+        assert_eq!(key.chars().count(), INLINE_LEN * 2 + 2);
+
+        let bytes = crate::ByteBetween::full();
+        let this = vec![1; INLINE_LEN * 3];
+        let key = bytes.after(&this).unwrap();
+        assert!(this < key);
+    }
+
+    #[test]
+    fn test_clone_shares_alphabet() {
+        use std::sync::Arc;
+
+        let between = Between::cjk().with_forbidden(["丅"]);
+        let clone = between.clone();
+        assert!(Arc::ptr_eq(&between.alphabet, &clone.alphabet));
+        assert!(Arc::ptr_eq(&between.forbidden, &clone.forbidden));
+        assert_eq!(clone.between("一", "丁"), between.between("一", "丁"));
+    }
+
+    #[test]
+    fn test_midpoint_table() {
+        use crate::{bisect_positions, MIDPOINT_TABLE_LEN};
+
+        let small = Between::new((0..MIDPOINT_TABLE_LEN as u32).collect());
+        let large = Between::new((0..=MIDPOINT_TABLE_LEN as u32).collect());
+        assert!(small.alphabet.midpoints.is_some());
+        assert!(large.alphabet.midpoints.is_none());
+
+        for between in [Between::greek(), Between::base62()] {
+            let keys: Vec<String> = between.after_iter("").take(30).collect();
+            for this in &keys {
+                for that in keys.iter().filter(|that| this < *that) {
+                    let this_chars: Vec<char> = this.chars().collect();
+                    let that_chars: Vec<char> = that.chars().collect();
+                    let expected = bisect_positions(
+                        between.chars().len(),
+                        &between.positions(&this_chars).unwrap(),
+                        &between.positions(&that_chars).unwrap(),
+                    )
+                    .map(|positions| positions.iter().map(|p| between.chars()[*p]).collect());
+                    assert_eq!(between.between(this, that), expected);
+                }
+            }
+        }
+    }
 }
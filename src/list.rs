@@ -0,0 +1,297 @@
+use crate::position::move_neighbors;
+use crate::prelude::*;
+use crate::{Between, BetweenError, Key};
+
+/// A list of values that keeps a sortable [`Key`] for each one, generating keys as values are inserted and moved.
+///
+/// Keys are generated with [`Between::between`], so the instance's strategy and maximum length apply. When a
+/// new key would exceed the maximum length, the list rebalances first, rewriting as few keys as possible (see
+/// [`Between::plan_rebalance`]). Since that can change the keys of other values, callers that persist keys
+/// should write back the keys of every index returned by [`OrderedKeyList::take_changed`].
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, OrderedKeyList};
+///
+/// let mut list = OrderedKeyList::new(Between::init());
+/// list.push_back("b").unwrap();
+/// list.push_front("a").unwrap();
+/// list.push_back("c").unwrap();
+/// list.move_item(2, 0).unwrap();
+///
+/// let values: Vec<&str> = list.iter().map(|(_, value)| *value).collect();
+/// assert_eq!(values, vec!["c", "a", "b"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderedKeyList<T> {
+    between: Between,
+    items: Vec<(Key, T)>,
+    changed: Vec<usize>,
+}
+
+impl<T> OrderedKeyList<T> {
+    /// Creates an empty list generating keys with the given `Between` instance.
+    pub fn new(between: Between) -> Self {
+        OrderedKeyList {
+            between,
+            items: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+
+    /// Returns the `Between` instance used to generate keys.
+    pub fn between(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the number of values in the list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the list contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the key and value at `index`, if any.
+    pub fn get(&self, index: usize) -> Option<(&Key, &T)> {
+        self.items.get(index).map(|(key, value)| (key, value))
+    }
+
+    /// Returns an iterator over the keys and values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &T)> + '_ {
+        self.items.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Appends a value to the end of the list.
+    ///
+    /// # Returns
+    ///
+    /// The new value's key, or [`BetweenError::KeyspaceExhausted`] if the list does not fit within the
+    /// maximum length even after rebalancing, in which case the value is dropped.
+    pub fn push_back(&mut self, value: T) -> Result<&Key, BetweenError> {
+        self.insert_at(self.items.len(), value)
+    }
+
+    /// Inserts a value at the front of the list.
+    ///
+    /// # Returns
+    ///
+    /// The new value's key, or [`BetweenError::KeyspaceExhausted`] if the list does not fit within the
+    /// maximum length even after rebalancing, in which case the value is dropped.
+    pub fn push_front(&mut self, value: T) -> Result<&Key, BetweenError> {
+        self.insert_at(0, value)
+    }
+
+    /// Inserts a value so that it ends up at `index`, shifting later values back.
+    ///
+    /// # Returns
+    ///
+    /// The new value's key, or [`BetweenError::KeyspaceExhausted`] if the list does not fit within the
+    /// maximum length even after rebalancing, in which case the value is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the list.
+    pub fn insert_at(&mut self, index: usize, value: T) -> Result<&Key, BetweenError> {
+        assert!(index <= self.items.len(), "insertion index out of bounds");
+        let prev = index.checked_sub(1);
+        let next = Some(index).filter(|next| *next < self.items.len());
+        let key = self.key_between(prev, next)?;
+        self.insert_entry(index, key, value);
+        Ok(&self.items[index].0)
+    }
+
+    /// Moves the value at `from` so that it ends up at `to`, giving it a new key.
+    ///
+    /// # Returns
+    ///
+    /// The moved value's key, or [`BetweenError::KeyspaceExhausted`] if the list does not fit within the
+    /// maximum length even after rebalancing, in which case the value stays where it was.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` or `to` is out of bounds.
+    pub fn move_item(&mut self, from: usize, to: usize) -> Result<&Key, BetweenError> {
+        assert!(
+            from < self.items.len() && to < self.items.len(),
+            "move index out of bounds"
+        );
+        // Find the value's new neighbors while it is still in the list, so nothing changes on failure.
+        let (prev, next) = move_neighbors(self.items.len(), from, to).unwrap();
+        if prev == Some(from) {
+            return Ok(&self.items[from].0);
+        }
+        let key = self.key_between(prev, next)?;
+        let (_, value) = self.remove_entry(from);
+        self.insert_entry(to, key, value);
+        Ok(&self.items[to].0)
+    }
+
+    /// Removes the value at `index` and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.remove_entry(index).1
+    }
+
+    /// Replaces every key with a fresh, evenly spaced one (see [`Between::rebalance`]).
+    pub fn rebalance(&mut self) {
+        let keys = self.between.spread(self.items.len());
+        for (index, ((old, _), key)) in self.items.iter_mut().zip(keys).enumerate() {
+            *old = Key(key);
+            self.changed.push(index);
+        }
+    }
+
+    /// Returns the indices of the values whose keys were created or changed since the last call, in ascending
+    /// order, and forgets them.
+    pub fn take_changed(&mut self) -> Vec<usize> {
+        let mut changed = core::mem::take(&mut self.changed);
+        changed.sort_unstable();
+        changed.dedup();
+        changed
+    }
+
+    /// Generates a key between the values at `prev` and `next` (the ends of the list if `None`), rebalancing
+    /// if the gap between them is used up.
+    fn key_between(
+        &mut self,
+        prev: Option<usize>,
+        next: Option<usize>,
+    ) -> Result<Key, BetweenError> {
+        match self.try_key_between(prev, next) {
+            Err(error @ BetweenError::KeyspaceExhausted { .. }) => {
+                let max_len = match self.between.max_len() {
+                    Some(max_len) => max_len,
+                    None => return Err(error),
+                };
+                let keys: Vec<&str> = self.items.iter().map(|(key, _)| key.as_str()).collect();
+                match self.between.plan_rebalance(&keys, max_len) {
+                    Some(patches) => {
+                        for (index, key) in patches {
+                            self.items[index].0 = Key(key);
+                            self.changed.push(index);
+                        }
+                        self.try_key_between(prev, next)
+                    }
+                    // The list cannot be given room, so report the gap that was used up.
+                    None => Err(error),
+                }
+            }
+            result => result,
+        }
+    }
+
+    fn try_key_between(
+        &self,
+        prev: Option<usize>,
+        next: Option<usize>,
+    ) -> Result<Key, BetweenError> {
+        let high = self.between.high().to_string();
+        let prev = prev.map_or("", |prev| self.items[prev].0.as_str());
+        let next = next.map_or(high.as_str(), |next| self.items[next].0.as_str());
+        self.between.try_between(prev, next).map(Key)
+    }
+
+    fn insert_entry(&mut self, index: usize, key: Key, value: T) {
+        self.items.insert(index, (key, value));
+        self.shift_changed(index, true);
+        self.changed.push(index);
+    }
+
+    fn remove_entry(&mut self, index: usize) -> (Key, T) {
+        let entry = self.items.remove(index);
+        self.changed.retain(|changed| *changed != index);
+        self.shift_changed(index, false);
+        entry
+    }
+
+    /// Keeps the indices of changed values pointing at the same values after an insert or removal at `index`.
+    fn shift_changed(&mut self, index: usize, inserted: bool) {
+        for changed in self.changed.iter_mut().filter(|changed| **changed >= index) {
+            if inserted {
+                *changed += 1;
+            } else {
+                *changed -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, BetweenError, OrderedKeyList};
+
+    fn is_sorted<T>(list: &OrderedKeyList<T>) -> bool {
+        let keys: Vec<&str> = list.iter().map(|(key, _)| key.as_str()).collect();
+        keys.windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    fn values<T: Copy>(list: &OrderedKeyList<T>) -> Vec<T> {
+        list.iter().map(|(_, value)| *value).collect()
+    }
+
+    #[test]
+    fn test_ordered_key_list() {
+        let mut list = OrderedKeyList::new(Between::init());
+        assert!(list.is_empty());
+
+        list.push_back(2).unwrap();
+        list.push_back(4).unwrap();
+        list.push_front(1).unwrap();
+        list.insert_at(2, 3).unwrap();
+        assert_eq!(values(&list), vec![1, 2, 3, 4]);
+        assert!(is_sorted(&list));
+        assert_eq!(list.take_changed(), vec![0, 1, 2, 3]);
+
+        list.move_item(0, 3).unwrap();
+        assert_eq!(values(&list), vec![2, 3, 4, 1]);
+        assert_eq!(list.take_changed(), vec![3]);
+        list.move_item(2, 0).unwrap();
+        assert_eq!(values(&list), vec![4, 2, 3, 1]);
+        list.move_item(1, 2).unwrap();
+        assert_eq!(values(&list), vec![4, 3, 2, 1]);
+        assert!(is_sorted(&list));
+
+        assert_eq!(list.remove(1), 3);
+        assert_eq!(values(&list), vec![4, 2, 1]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.take_changed(), vec![0, 1]);
+        assert!(list.take_changed().is_empty());
+    }
+
+    #[test]
+    fn test_ordered_key_list_rebalances() {
+        let mut list = OrderedKeyList::new(Between::init().with_max_len(3));
+
+        // Inserting at the same spot over and over would need ever longer keys.
+        for value in 0..500 {
+            list.insert_at(list.len() / 2, value).unwrap();
+            assert!(is_sorted(&list));
+        }
+        assert!(list.iter().all(|(key, _)| key.as_str().len() <= 3));
+
+        list.rebalance();
+        assert!(is_sorted(&list));
+        assert!(list.iter().all(|(key, _)| key.as_str().len() <= 2));
+        assert_eq!(list.take_changed().len(), 500);
+    }
+
+    #[test]
+    fn test_ordered_key_list_exhausted() {
+        let mut list = OrderedKeyList::new(Between::new(vec!['0', '1']).with_max_len(2));
+
+        // With 2 characters there are 3 keys of at most 2 characters, and every gap needs room.
+        list.push_back(1).unwrap();
+        assert!(matches!(
+            list.push_back(2),
+            Err(BetweenError::KeyspaceExhausted { .. })
+        ));
+        assert_eq!(values(&list), vec![1]);
+    }
+}
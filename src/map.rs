@@ -0,0 +1,240 @@
+use alloc::collections::BTreeMap;
+use core::ops::Bound;
+
+use crate::prelude::*;
+use crate::{Between, BetweenError, Key, ParseKeyError};
+
+/// A map from keys to values, backed by a `BTreeMap`, that iterates in the order of its keys' alphabet.
+///
+/// Keys are stored without trailing lowest characters, which makes their string order match the alphabet
+/// order [`Between::between`] uses: `"A"` and `"A!"` are the same key under the default alphabet, even though
+/// they are different strings. New keys are generated with `insert_first`, `insert_last` and
+/// `insert_between`, following the instance's strategy and maximum length.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, OrderedMap};
+///
+/// let mut map = OrderedMap::new(Between::init());
+/// let b = map.insert_last("b").unwrap();
+/// let a = map.insert_first("a").unwrap();
+/// map.insert_between(a.as_str(), b.as_str(), "ab").unwrap();
+///
+/// let values: Vec<&str> = map.values().copied().collect();
+/// assert_eq!(values, vec!["a", "ab", "b"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrderedMap<V> {
+    between: Between,
+    entries: BTreeMap<Key, V>,
+}
+
+impl<V> OrderedMap<V> {
+    /// Creates an empty map generating keys with the given `Between` instance.
+    pub fn new(between: Between) -> Self {
+        OrderedMap {
+            between,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the `Between` instance used to generate and validate keys.
+    pub fn between(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value for `key`, ignoring any trailing lowest characters.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.entries.get(key.trim_end_matches(self.between.low()))
+    }
+
+    /// Returns the first entry, in alphabet order.
+    pub fn first(&self) -> Option<(&Key, &V)> {
+        self.entries.iter().next()
+    }
+
+    /// Returns the last entry, in alphabet order.
+    pub fn last(&self) -> Option<(&Key, &V)> {
+        self.entries.iter().next_back()
+    }
+
+    /// Returns an iterator over the entries, in alphabet order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&Key, &V)> + '_ {
+        self.entries.iter()
+    }
+
+    /// Returns an iterator over the keys, in alphabet order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &Key> + '_ {
+        self.entries.keys()
+    }
+
+    /// Returns an iterator over the values, in the order of their keys.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + '_ {
+        self.entries.values()
+    }
+
+    /// Inserts a value under an existing key, such as one loaded from storage.
+    ///
+    /// # Returns
+    ///
+    /// The value previously stored under the key, if any, or a [`ParseKeyError`] if the key is empty or
+    /// contains characters outside the alphabet.
+    pub fn insert(&mut self, key: &str, value: V) -> Result<Option<V>, ParseKeyError> {
+        let key = Key::parse(key.trim_end_matches(self.between.low()), &self.between)?;
+        Ok(self.entries.insert(key, value))
+    }
+
+    /// Removes the entry for `key`, ignoring any trailing lowest characters, and returns its value.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.entries
+            .remove(key.trim_end_matches(self.between.low()))
+    }
+
+    /// Inserts a value before every other entry.
+    ///
+    /// # Returns
+    ///
+    /// The new value's key, or [`BetweenError::KeyspaceExhausted`] if no key fits within the maximum length.
+    pub fn insert_first(&mut self, value: V) -> Result<Key, BetweenError> {
+        let next = self.first().map(|(key, _)| key.as_str().to_string());
+        let high = self.between.high().to_string();
+        self.insert_new("", next.as_deref().unwrap_or(&high), value)
+    }
+
+    /// Inserts a value after every other entry.
+    ///
+    /// # Returns
+    ///
+    /// The new value's key, or [`BetweenError::KeyspaceExhausted`] if no key fits within the maximum length.
+    pub fn insert_last(&mut self, value: V) -> Result<Key, BetweenError> {
+        let prev = self.last().map(|(key, _)| key.as_str().to_string());
+        let high = self.between.high().to_string();
+        self.insert_new(prev.as_deref().unwrap_or(""), &high, value)
+    }
+
+    /// Inserts a value under a new key that sorts strictly between `prev` and `next`.
+    ///
+    /// The bounds are usually the keys of adjacent entries, but need not be in the map. The new key lands
+    /// right after `prev`, before any entry that sorts between `prev` and `next`, so it never replaces an
+    /// entry. Pass `""` as `prev` to insert before `next` with no lower bound.
+    ///
+    /// # Returns
+    ///
+    /// The new value's key, or a [`BetweenError`] if the bounds are invalid under the same rules as
+    /// [`Between::between`], no key fits within the maximum length, or the key is already in the map.
+    pub fn insert_between(
+        &mut self,
+        prev: &str,
+        next: &str,
+        value: V,
+    ) -> Result<Key, BetweenError> {
+        self.insert_new(prev, next, value)
+    }
+
+    /// Generates a key between `prev` and the first entry after it, bounded by `next`, and inserts the value.
+    fn insert_new(&mut self, prev: &str, next: &str, value: V) -> Result<Key, BetweenError> {
+        if self.between.bounds(prev, next).is_none() {
+            return Err(BetweenError::InvalidBounds);
+        }
+        let prev = prev.trim_end_matches(self.between.low());
+        let successor = self
+            .entries
+            .range::<str, _>((Bound::Excluded(prev), Bound::Unbounded))
+            .next()
+            .map(|(key, _)| key.as_str());
+        let next = match successor {
+            Some(successor) if successor < next.trim_end_matches(self.between.low()) => successor,
+            _ => next,
+        };
+        let key = Key(self.between.try_between(prev, next)?);
+        if self.entries.contains_key(&key) {
+            return Err(BetweenError::Collision { key: key.0 });
+        }
+        self.entries.insert(key.clone(), value);
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, BetweenError, OrderedMap, ParseKeyError};
+
+    #[test]
+    fn test_ordered_map() {
+        let mut map = OrderedMap::new(Between::init());
+        assert!(map.is_empty());
+
+        let b = map.insert_last(2).unwrap();
+        let c = map.insert_last(3).unwrap();
+        let a = map.insert_first(1).unwrap();
+        map.insert_between(b.as_str(), c.as_str(), 25).unwrap();
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![1, 2, 25, 3]);
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.first().unwrap().0, &a);
+        assert_eq!(map.last().unwrap().0, &c);
+
+        assert_eq!(map.get(a.as_str()), Some(&1));
+        assert_eq!(map.remove(b.as_str()), Some(2));
+        assert!(map.get(b.as_str()).is_none());
+
+        assert!(matches!(
+            map.insert_between(c.as_str(), a.as_str(), 0),
+            Err(BetweenError::InvalidBounds)
+        ));
+    }
+
+    #[test]
+    fn test_insert_between_keeps_entries_in_range() {
+        let mut map = OrderedMap::new(Between::init());
+        let a = map.insert_last("a").unwrap();
+        let c = map.insert_last("c").unwrap();
+
+        // Both calls get the same bounds, so the second key must land before the first.
+        let mid = map.insert_between(a.as_str(), c.as_str(), "mid").unwrap();
+        let mid2 = map.insert_between(a.as_str(), c.as_str(), "mid2").unwrap();
+        assert!(a < mid2 && mid2 < mid && mid < c);
+        assert_eq!(map.len(), 4);
+        assert_eq!(
+            map.values().copied().collect::<Vec<_>>(),
+            vec!["a", "mid2", "mid", "c"]
+        );
+
+        // Bounds that are not in the map still leave existing entries alone.
+        map.insert_between("", "~", "first").unwrap();
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.first().unwrap().1, &"first");
+        assert!(matches!(
+            map.insert_between(a.as_str(), "Z}", "invalid"),
+            Err(BetweenError::InvalidBounds)
+        ));
+    }
+
+    #[test]
+    fn test_ordered_map_alphabet_order() {
+        let mut map = OrderedMap::new(Between::init());
+
+        // "A!" is the same key as "A", so it replaces it rather than sorting after it.
+        assert_eq!(map.insert("A0", "a0").unwrap(), None);
+        assert_eq!(map.insert("A", "a").unwrap(), None);
+        assert_eq!(map.insert("A!", "a!").unwrap(), Some("a"));
+        assert_eq!(map.get("A!!"), Some(&"a!"));
+        assert_eq!(map.values().copied().collect::<Vec<_>>(), vec!["a!", "a0"]);
+
+        assert_eq!(map.insert("!!", "empty"), Err(ParseKeyError::Empty));
+        assert!(matches!(
+            map.insert("A$", "invalid"),
+            Err(ParseKeyError::InvalidCharacter { .. })
+        ));
+    }
+}
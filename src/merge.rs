@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use crate::Between;
+
+impl Between {
+    /// Merges two independently keyed lists into one consistent ordering.
+    ///
+    /// This reconciles lists that were edited apart, e.g. after a split-brain. Keys keep their values and the
+    /// lists are interleaved by key. Where a key of `b` collides with a key of `a` (including keys that differ
+    /// only in trailing lowest characters), the item from `a` keeps its key and the one from `b` follows it
+    /// with a fresh key, so only colliding items are rewritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first list of keys, in ascending order.
+    /// * `b` - The second list of keys, in ascending order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the keys of both lists in merged order, or `None` if a key is
+    /// invalid, either list is not strictly ascending, or a collision leaves no room for a fresh key below the
+    /// next key (or below the highest character, at the end of the list).
+    pub fn merge<S, T>(&self, a: &[S], b: &[T]) -> Option<Vec<String>>
+    where
+        S: AsRef<str>,
+        T: AsRef<str>,
+    {
+        let a_indices = self.sorted_indices(a)?;
+        let b_indices = self.sorted_indices(b)?;
+
+        // Interleave the lists, grouping keys of equal value with the one from 'a' first.
+        let mut groups: Vec<Vec<&str>> = Vec::with_capacity(a.len() + b.len());
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() || j < b.len() {
+            let group = match (a_indices.get(i), b_indices.get(j)) {
+                (Some(x), Some(y)) if x == y => {
+                    i += 1;
+                    j += 1;
+                    vec![a[i - 1].as_ref(), b[j - 1].as_ref()]
+                }
+                (Some(x), Some(y)) if x < y => {
+                    i += 1;
+                    vec![a[i - 1].as_ref()]
+                }
+                (Some(_), None) => {
+                    i += 1;
+                    vec![a[i - 1].as_ref()]
+                }
+                _ => {
+                    j += 1;
+                    vec![b[j - 1].as_ref()]
+                }
+            };
+            groups.push(group);
+        }
+
+        let high = self.high.to_string();
+        let mut merged = Vec::with_capacity(a.len() + b.len());
+        for (position, group) in groups.iter().enumerate() {
+            merged.push(group[0].to_string());
+            if group.len() > 1 {
+                let next = groups
+                    .get(position + 1)
+                    .map_or(high.as_str(), |next| next[0]);
+                merged.extend(self.n_between(group[0], next, group.len() - 1)?);
+            }
+        }
+        Some(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_merge() {
+        let between = Between::init();
+
+        assert_eq!(
+            between.merge(&["A", "C", "E"], &["B", "D"]).unwrap(),
+            vec!["A", "B", "C", "D", "E"]
+        );
+
+        // The colliding key from 'b' gets a fresh key right after the one from 'a'.
+        let merged = between.merge(&["A", "C"], &["C", "D"]).unwrap();
+        assert_eq!(merged.len(), 4);
+        assert_eq!(&merged[..2], &["A", "C"]);
+        assert_eq!(merged[3], "D");
+        assert!("C" < merged[2].as_str() && merged[2].as_str() < "D");
+
+        // Canonical collisions count too, and a collision at the end uses the rest of the keyspace.
+        let merged = between.merge(&["A!", "z"], &["A", "z"]).unwrap();
+        assert_eq!(merged[0], "A!");
+        assert!("A" < merged[1].as_str() && merged[1].as_str() < "z");
+        assert_eq!(merged[2], "z");
+        assert!("z" < merged[3].as_str() && merged[3].as_str() < "~");
+    }
+
+    #[test]
+    fn test_merge_invalid() {
+        let between = Between::init();
+
+        assert_eq!(
+            between.merge::<&str, &str>(&[], &[]).unwrap(),
+            Vec::<String>::new()
+        );
+        assert!(between.merge(&["B", "A"], &["C"]).is_none());
+        assert!(between.merge(&["A"], &["$"]).is_none());
+        // Nothing sorts after a colliding highest character.
+        assert!(between.merge(&["~"], &["~"]).is_none());
+    }
+}
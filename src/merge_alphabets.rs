@@ -0,0 +1,127 @@
+use alloc::sync::Arc;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// A report of how existing keys fare under a merged alphabet, produced by [`Between::merge_alphabets`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlphabetMerge {
+    /// The characters the merged alphabet adds to this one, in ascending order.
+    pub added: Vec<char>,
+    /// The characters the merged alphabet adds to the other one, in ascending order.
+    pub other_added: Vec<char>,
+    /// Whether each of this alphabet's keys is valid under the merged alphabet, in the order given.
+    pub valid: Vec<bool>,
+    /// Whether each of the other alphabet's keys is valid under the merged alphabet, in the order given.
+    pub other_valid: Vec<bool>,
+}
+
+impl AlphabetMerge {
+    /// Returns `true` if every key given from either side is valid under the merged alphabet.
+    pub fn all_valid(&self) -> bool {
+        self.valid
+            .iter()
+            .chain(&self.other_valid)
+            .all(|valid| *valid)
+    }
+}
+
+impl Between {
+    /// Merges this alphabet with another, for example when combining two systems that each generated keys with
+    /// their own alphabet.
+    ///
+    /// The merged alphabet has every character of both, in ascending order, and keeps this instance's strategy,
+    /// maximum length and forbidden substrings. It is a superset of both alphabets, so every key valid under
+    /// either one stays valid and sorts the same; the report flags the given keys that were not valid to begin
+    /// with.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The alphabet to merge with.
+    /// * `keys` - Existing keys generated with this alphabet.
+    /// * `other_keys` - Existing keys generated with the other alphabet.
+    ///
+    /// # Returns
+    ///
+    /// The merged `Between` instance, along with an `AlphabetMerge` report.
+    pub fn merge_alphabets<A, B>(
+        &self,
+        other: &Between,
+        keys: &[A],
+        other_keys: &[B],
+    ) -> (Between, AlphabetMerge)
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        let mut merged = Between::new(
+            self.alphabet
+                .chars
+                .iter()
+                .chain(&other.alphabet.chars)
+                .copied()
+                .collect(),
+        );
+        merged.strategy = Arc::clone(&self.strategy);
+        merged.max_len = self.max_len;
+        merged.forbidden = self.forbidden.clone();
+
+        let added_to = |between: &Between| -> Vec<char> {
+            merged
+                .alphabet
+                .chars
+                .iter()
+                .filter(|c| between.position(**c).is_none())
+                .copied()
+                .collect()
+        };
+        let report = AlphabetMerge {
+            added: added_to(self),
+            other_added: added_to(other),
+            valid: keys.iter().map(|key| merged.valid(key.as_ref())).collect(),
+            other_valid: other_keys
+                .iter()
+                .map(|key| merged.valid(key.as_ref()))
+                .collect(),
+        };
+        (merged, report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_merge_alphabets() {
+        let digits = Between::base10().with_max_len(8);
+        let hex = Between::base16();
+        let (merged, report) = digits.merge_alphabets(&hex, &["5", "55"], &["a", "f0f", "g"]);
+
+        assert_eq!(merged.chars(), hex.chars());
+        assert_eq!(merged.max_len(), Some(8));
+        assert_eq!(report.added, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+        assert!(report.other_added.is_empty());
+        assert_eq!(report.valid, vec![true, true]);
+        assert_eq!(report.other_valid, vec![true, true, false]);
+        assert!(!report.all_valid());
+    }
+
+    #[test]
+    fn test_merged_alphabet_keeps_order() {
+        let lower = Between::new(('a'..='m').collect());
+        let upper = Between::new(('N'..='Z').collect());
+        let mut keys: Vec<String> = lower.after_iter("").take(20).collect();
+        let other_keys: Vec<String> = upper.after_iter("").take(20).collect();
+        let (merged, report) = lower.merge_alphabets(&upper, &keys, &other_keys);
+        assert!(report.all_valid());
+        assert_eq!(merged.chars().len(), 26);
+
+        keys.extend(other_keys);
+        keys.sort();
+        for pair in keys.windows(2) {
+            let key = merged.between(pair[0].as_str(), pair[1].as_str()).unwrap();
+            assert!(pair[0] < key && key < pair[1]);
+        }
+    }
+}
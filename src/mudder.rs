@@ -0,0 +1,184 @@
+use num_bigint::BigUint;
+use num_traits::{Float, ToPrimitive, Zero};
+
+use crate::prelude::*;
+use crate::{Between, Strategy};
+
+/// How many characters longer than `a` the default upper bound is, as in mudder.
+const DEFAULT_END_PADDING: usize = 6;
+
+/// Picks the string mudder.js would pick, treating the instance's alphabet as mudder's symbol table.
+///
+/// With [`Between::base62`], `between.between(a, b)` returns exactly the string
+/// `mudder.base62.mudder(a, b, 1)` returns. See [`Between::mudder`] for several strings at once.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Mudder;
+
+impl Strategy for Mudder {
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+        between.bounds(this, that)?;
+        between.mudder(this, that, 1)?.pop()
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("Mudder")
+    }
+}
+
+impl Between {
+    /// Generates `n` strings between two given strings exactly as mudder.js does, treating this instance's
+    /// alphabet as mudder's symbol table.
+    ///
+    /// With [`Between::base62`], this returns what `mudder.base62.mudder(a, b, n)` returns, including its
+    /// conventions: an empty `a` stands for the lowest character, an empty `b` for the highest character
+    /// repeated six times more than the length of `a`, and if `a` sorts after `b` the strings are returned in
+    /// descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first string.
+    /// * `b` - The second string.
+    /// * `n` - The number of strings to generate.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the strings, or `None` if a string contains characters outside
+    /// the alphabet or the strings are equal once padded with the lowest character.
+    pub fn mudder(&self, a: &str, b: &str, n: usize) -> Option<Vec<String>> {
+        let a = if a.is_empty() {
+            self.low.to_string()
+        } else {
+            a.to_string()
+        };
+        let b = if b.is_empty() {
+            self.high
+                .to_string()
+                .repeat(a.chars().count() + DEFAULT_END_PADDING)
+        } else {
+            b.to_string()
+        };
+        let a = self.indices(&a)?;
+        let b = self.indices(&b)?;
+
+        let strings = self.linspace(&a, &b, n)?;
+        let mut all = Vec::with_capacity(n + 2);
+        all.push(a);
+        all.extend(strings);
+        all.push(b);
+        Some(
+            chop_successive(all)[1..=n]
+                .iter()
+                .map(|digits| {
+                    digits
+                        .iter()
+                        .map(|index| self.alphabet.chars[*index])
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the digits of the `n` points dividing the gap between `a` and `b` into `n + 1` equal parts,
+    /// each with its remainder rounded to as many digits as it takes to tell the parts apart.
+    fn linspace(&self, a: &[usize], b: &[usize], n: usize) -> Option<Vec<Vec<usize>>> {
+        let len = a.len().max(b.len());
+        let low = self.scaled(a, len);
+        let high = self.scaled(b, len);
+        if low == high {
+            return None;
+        }
+        let divisions = n + 1;
+        Some(
+            (1..=n)
+                .map(|i| {
+                    let value = &low * (divisions - i) + &high * i;
+                    let quotient = &value / divisions;
+                    let remainder = (value % divisions).to_u64().unwrap();
+                    let mut digits = self.unscaled(&quotient, len);
+                    digits.extend(self.round_fraction(remainder, divisions as u64));
+                    digits
+                })
+                .collect(),
+        )
+    }
+
+    /// Rounds `numerator / denominator` to the digits mudder's `roundFraction` produces, floating point
+    /// arithmetic included.
+    fn round_fraction(&self, numerator: u64, denominator: u64) -> Vec<usize> {
+        let base = self.alphabet.chars.len();
+        // Called through `Float` so that `no_std` builds fall back to `libm`.
+        let places = Float::ceil(Float::ln(denominator as f64) / Float::ln(base as f64)) as usize;
+        let scale = Float::powi(base as f64, places as i32);
+        let mut scaled =
+            BigUint::from(Float::round(numerator as f64 / denominator as f64 * scale) as u64);
+        let mut digits = Vec::new();
+        while !scaled.is_zero() {
+            digits.push((&scaled % base).to_usize().unwrap());
+            scaled /= base;
+        }
+        if digits.is_empty() {
+            digits.push(0);
+        }
+        digits.resize(digits.len().max(places), 0);
+        digits.reverse();
+        digits
+    }
+}
+
+/// Shortens each string to the first digit that is non-zero and differs from the string before it, as
+/// mudder's `chopSuccessiveDigits` does, working from the lower end.
+fn chop_successive(mut strings: Vec<Vec<usize>>) -> Vec<Vec<usize>> {
+    let reversed = strings[0] >= strings[1];
+    if reversed {
+        strings.reverse();
+    }
+    let mut chopped = vec![strings[0].clone()];
+    chopped.extend(strings.windows(2).map(|pair| {
+        let (rock, water) = (&pair[0], &pair[1]);
+        match (0..water.len()).find(|i| water[*i] != 0 && rock.get(*i) != Some(&water[*i])) {
+            Some(i) => water[..=i].to_vec(),
+            None => water.clone(),
+        }
+    }));
+    if reversed {
+        chopped.reverse();
+    }
+    chopped
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, Mudder};
+
+    #[test]
+    fn test_mudder() {
+        let between = Between::base62();
+
+        assert_eq!(between.mudder("", "", 1).unwrap(), vec!["U"]);
+        assert_eq!(between.mudder("", "", 3).unwrap(), vec!["F", "U", "k"]);
+        assert_eq!(between.mudder("a", "b", 1).unwrap(), vec!["aV"]);
+        assert_eq!(between.mudder("b", "a", 1).unwrap(), vec!["aV"]);
+
+        let keys = between.mudder("a", "b", 100).unwrap();
+        assert_eq!(keys.len(), 100);
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys
+            .iter()
+            .all(|key| "a" < key.as_str() && key.as_str() < "b"));
+        let mut descending = between.mudder("b", "a", 100).unwrap();
+        descending.reverse();
+        assert_eq!(descending, keys);
+
+        assert!(between.mudder("a", "a0", 1).is_none());
+        assert!(between.mudder("a", "$", 1).is_none());
+    }
+
+    #[test]
+    fn test_mudder_strategy() {
+        let between = Between::base62().with_strategy(Mudder);
+
+        assert_eq!(between.between("a", "b").unwrap(), "aV");
+        assert_eq!(between.after("").unwrap(), "U");
+        assert!(between.between("b", "a").is_none());
+    }
+}
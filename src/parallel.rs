@@ -0,0 +1,97 @@
+use rayon::prelude::*;
+
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Finds `n` evenly spaced strings between two given strings like [`Between::n_between`], generating them
+    /// on all cores.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `n` - The number of strings to generate.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the same strings, in the same order, as [`Between::n_between`].
+    pub fn par_n_between<S, T>(&self, this: S, that: T, n: usize) -> Option<Vec<String>>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        if n == 0 {
+            return Some(vec![]);
+        }
+        let spacing = self.spacing(&this, &that, n);
+        Some(
+            (1..=n)
+                .into_par_iter()
+                .map(|i| spacing.key(self, i))
+                .collect(),
+        )
+    }
+
+    /// Generates a fresh set of short, evenly spaced strings to replace every string of a list like
+    /// [`Between::rebalance`], generating them on all cores.
+    pub fn par_rebalance<S>(&self, existing: &[S]) -> Option<Vec<String>>
+    where
+        S: AsRef<str>,
+    {
+        self.sorted_indices(existing)?;
+        let mut high = [0; 4];
+        self.par_n_between("", self.high.encode_utf8(&mut high), existing.len())
+    }
+
+    /// Checks many strings with [`Between::valid`] on all cores.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings to validate.
+    ///
+    /// # Returns
+    ///
+    /// The indices of the invalid strings, in ascending order. An empty vector means every string is valid.
+    pub fn par_invalid<S>(&self, keys: &[S]) -> Vec<usize>
+    where
+        S: AsRef<str> + Sync,
+    {
+        keys.par_iter()
+            .enumerate()
+            .filter(|(_, key)| !self.valid(key))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_par_matches_sequential() {
+        let between = Between::init();
+        assert_eq!(
+            between.par_n_between("A", "B", 1000),
+            between.n_between("A", "B", 1000)
+        );
+        assert_eq!(between.par_n_between("A", "B", 0), Some(vec![]));
+        assert!(between.par_n_between("B", "A", 3).is_none());
+
+        let existing: Vec<String> = between.after_iter("").take(500).collect();
+        assert_eq!(
+            between.par_rebalance(&existing),
+            between.rebalance(&existing)
+        );
+        assert!(between.par_rebalance(&["b", "a"]).is_none());
+    }
+
+    #[test]
+    fn test_par_invalid() {
+        let between = Between::base10();
+        let keys: Vec<String> = (0..1000).map(|i| i.to_string()).collect();
+        assert!(between.par_invalid(&keys).is_empty());
+        assert_eq!(between.par_invalid(&["1", "", "2", "x"]), vec![1, 3]);
+    }
+}
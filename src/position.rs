@@ -0,0 +1,213 @@
+use core::cmp::Ordering;
+
+use crate::prelude::*;
+use crate::Between;
+
+impl Between {
+    /// Finds a string for a new item inserted into a sorted list at a given index.
+    ///
+    /// The new string sorts after `keys[index - 1]` and before `keys[index]`. Inserting at index 0 or at the
+    /// end of the list (including into an empty list) uses the start or end of the keyspace as the missing
+    /// neighbor, like [`Between::before`] and [`Between::after`].
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings of the list, in ascending order.
+    /// * `index` - The index the new item will have, from 0 to `keys.len()`.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the new string, or `None` if `index` is greater than `keys.len()` or
+    /// the neighbors are invalid under the same rules as [`Between::between`].
+    pub fn key_for_index<S>(&self, keys: &[S], index: usize) -> Option<String>
+    where
+        S: AsRef<str>,
+    {
+        if index > keys.len() {
+            return None;
+        }
+        let prev = index.checked_sub(1).map(|prev| keys[prev].as_ref());
+        let next = keys.get(index).map(|next| next.as_ref());
+        self.between_neighbors(prev, next)
+    }
+
+    /// Finds the new string for an item moved within a sorted list, as when dragging and dropping it.
+    ///
+    /// The item at `from` ends up at index `to` of the list as it is after the move: moving an item down
+    /// places it after the item currently at `to`, and moving it up places it before that item. Only the moved
+    /// item needs a new string.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings of the list, in ascending order.
+    /// * `from` - The current index of the moved item.
+    /// * `to` - The index of the moved item after the move.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the moved item's new string (its current string if `from` equals
+    /// `to`), or `None` if `from` or `to` is out of bounds or the new neighbors are invalid under the same
+    /// rules as [`Between::between`].
+    pub fn key_for_move<S>(&self, keys: &[S], from: usize, to: usize) -> Option<String>
+    where
+        S: AsRef<str>,
+    {
+        let (prev, next) = move_neighbors(keys.len(), from, to)?;
+        if prev == Some(from) {
+            return Some(keys[from].as_ref().to_string());
+        }
+        let prev = prev.map(|prev| keys[prev].as_ref());
+        let next = next.map(|next| keys[next].as_ref());
+        self.between_neighbors(prev, next)
+    }
+
+    /// Finds new strings for several items moved together within a sorted list.
+    ///
+    /// The moved items need not be contiguous. They keep their relative order and are placed together at
+    /// index `to` of the list that remains once they are taken out, so for example a `to` of 0 moves them to
+    /// the front. The new strings are the shortest evenly spaced ones that fit (see [`Between::n_between`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings of the list, in ascending order.
+    /// * `moved` - The indices of the items to move, in any order.
+    /// * `to` - Where to place the moved items among the remaining ones, from 0 to their number.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<(usize, String)>>` that contains each moved item's index paired with its new string, in
+    /// ascending order, or `None` if an index is out of bounds or repeated, `to` is out of bounds, or the new
+    /// neighbors are invalid under the same rules as [`Between::between`].
+    pub fn keys_for_move<S>(
+        &self,
+        keys: &[S],
+        moved: &[usize],
+        to: usize,
+    ) -> Option<Vec<(usize, String)>>
+    where
+        S: AsRef<str>,
+    {
+        let mut moved = moved.to_vec();
+        moved.sort_unstable();
+        if moved.windows(2).any(|pair| pair[0] == pair[1])
+            || moved.last().is_some_and(|last| *last >= keys.len())
+        {
+            return None;
+        }
+        let remaining: Vec<&str> = (0..keys.len())
+            .filter(|index| moved.binary_search(index).is_err())
+            .map(|index| keys[index].as_ref())
+            .collect();
+        if to > remaining.len() {
+            return None;
+        }
+
+        let high = self.high.to_string();
+        let prev = to.checked_sub(1).map_or("", |prev| remaining[prev]);
+        let next = remaining.get(to).copied().unwrap_or(high.as_str());
+        let fresh = self.n_between(prev, next, moved.len())?;
+        Some(moved.into_iter().zip(fresh).collect())
+    }
+
+    /// Finds a string between two optional neighbors, where a missing neighbor is the end of the keyspace.
+    pub(crate) fn between_neighbors(
+        &self,
+        prev: Option<&str>,
+        next: Option<&str>,
+    ) -> Option<String> {
+        match next {
+            Some(next) => self.between(prev.unwrap_or(""), next),
+            None => {
+                let mut high = [0; 4];
+                self.between(prev.unwrap_or(""), self.high.encode_utf8(&mut high))
+            }
+        }
+    }
+}
+
+/// Returns the indices of the neighbors an item moved from `from` to `to` ends up between, in a list of `len`
+/// items as it is before the move.
+///
+/// A move to the same place returns the item itself as its predecessor.
+pub(crate) fn move_neighbors(
+    len: usize,
+    from: usize,
+    to: usize,
+) -> Option<(Option<usize>, Option<usize>)> {
+    if from >= len || to >= len {
+        return None;
+    }
+    Some(match to.cmp(&from) {
+        Ordering::Equal => (Some(from), from.checked_add(1).filter(|next| *next < len)),
+        Ordering::Less => (to.checked_sub(1), Some(to)),
+        Ordering::Greater => (Some(to), Some(to + 1).filter(|next| *next < len)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_key_for_index() {
+        let between = Between::init();
+        let keys = vec!["A", "B", "V"];
+
+        assert_eq!(between.key_for_index(&keys, 0).unwrap(), "5");
+        assert_eq!(between.key_for_index(&keys, 1).unwrap(), "AV");
+        assert_eq!(between.key_for_index(&keys, 2).unwrap(), "L");
+        assert_eq!(between.key_for_index(&keys, 3).unwrap(), "k");
+        assert_eq!(between.key_for_index::<&str>(&[], 0).unwrap(), "V");
+
+        assert!(between.key_for_index(&keys, 4).is_none());
+        assert!(between.key_for_index(&["B", "A"], 1).is_none());
+        assert!(between.key_for_index(&["~"], 1).is_none());
+    }
+
+    #[test]
+    fn test_key_for_move() {
+        let between = Between::init();
+        let keys = vec!["A", "B", "C", "D"];
+
+        // Moving down places the item after the one currently at 'to'.
+        assert_eq!(between.key_for_move(&keys, 0, 2).unwrap(), "CV");
+        assert_eq!(between.key_for_move(&keys, 0, 3).unwrap(), "b");
+        // Moving up places it before the one currently at 'to'.
+        assert_eq!(between.key_for_move(&keys, 3, 1).unwrap(), "AV");
+        assert_eq!(between.key_for_move(&keys, 3, 0).unwrap(), "5");
+        // Adjacent moves swap the two items.
+        assert_eq!(between.key_for_move(&keys, 1, 2).unwrap(), "CV");
+        assert_eq!(between.key_for_move(&keys, 2, 1).unwrap(), "AV");
+        assert_eq!(between.key_for_move(&keys, 2, 2).unwrap(), "C");
+
+        assert!(between.key_for_move(&keys, 4, 0).is_none());
+        assert!(between.key_for_move(&keys, 0, 4).is_none());
+    }
+
+    #[test]
+    fn test_keys_for_move() {
+        let between = Between::init();
+        let keys = vec!["A", "B", "C", "D", "E"];
+
+        // Move "A" and "C" between "D" and "E", keeping their order.
+        let moved = between.keys_for_move(&keys, &[2, 0], 2).unwrap();
+        assert_eq!(
+            moved.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            vec![0, 2]
+        );
+        assert!(moved[0].1.as_str() > "D" && moved[0].1 < moved[1].1 && moved[1].1.as_str() < "E");
+        assert!(moved.iter().all(|(_, key)| key.len() == 2));
+
+        // Move the last two items to the front.
+        let moved = between.keys_for_move(&keys, &[3, 4], 0).unwrap();
+        assert!(moved[0].1 < moved[1].1 && moved[1].1.as_str() < "A");
+        // Move to the end.
+        let moved = between.keys_for_move(&keys, &[0], 4).unwrap();
+        assert!(moved[0].1.as_str() > "E");
+        assert!(between.keys_for_move(&keys, &[], 0).unwrap().is_empty());
+
+        assert!(between.keys_for_move(&keys, &[1, 1], 0).is_none());
+        assert!(between.keys_for_move(&keys, &[5], 0).is_none());
+        assert!(between.keys_for_move(&keys, &[0], 5).is_none());
+    }
+}
@@ -0,0 +1,209 @@
+use core::fmt;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// The ASCII punctuation and symbols in the order of the ICU root collation, which sorts them before
+/// digits and letters.
+const ICU_ASCII_SYMBOLS: &str = "_-,;:!?.'\"()[]{}@*/\\&#%`^+<=>|~$";
+
+/// How a family of PostgreSQL collations orders characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Model {
+    /// `"C"`, `"POSIX"`, `"ucs_basic"` and the builtin provider's collations: code point order, which is
+    /// the order of Rust strings.
+    CodePoint,
+    /// ICU collations such as `"und-x-icu"` and `"unicode"`: punctuation, then symbols, then digits, then
+    /// letters, with letters compared case-insensitively first.
+    Icu,
+    /// libc (glibc) collations such as `"en_US.utf8"`: letters and digits compared case-insensitively
+    /// first, ignoring punctuation and symbols.
+    Libc,
+}
+
+impl Model {
+    fn of(collation: &str) -> Self {
+        let name = collation.trim_matches('"').to_ascii_lowercase();
+        match name.as_str() {
+            "c" | "posix" | "ucs_basic" | "c.utf8" | "c.utf-8" | "pg_c_utf8"
+            | "pg_unicode_fast" => Model::CodePoint,
+            "unicode" | "und" => Model::Icu,
+            _ if name.ends_with("-x-icu") || name.contains("-u-") => Model::Icu,
+            _ => Model::Libc,
+        }
+    }
+
+    /// Returns the weight the collation first compares a character by, or `None` if it ignores the
+    /// character at first.
+    fn weight(self, c: char) -> Option<(u8, u32)> {
+        let folded = c.to_lowercase().next().unwrap_or(c) as u32;
+        match self {
+            Model::CodePoint => Some((0, c as u32)),
+            Model::Icu => Some(if c.is_whitespace() {
+                (0, c as u32)
+            } else if let Some(index) = ICU_ASCII_SYMBOLS.find(c) {
+                (1, index as u32)
+            } else if c.is_numeric() {
+                (3, c as u32)
+            } else if c.is_alphabetic() {
+                (4, folded)
+            } else {
+                (2, c as u32)
+            }),
+            Model::Libc if c.is_numeric() => Some((0, c as u32)),
+            Model::Libc if c.is_alphabetic() => Some((1, folded)),
+            Model::Libc => None,
+        }
+    }
+}
+
+/// The result of [`Between::check_postgres_safe`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostgresSafety {
+    /// The collation checked.
+    pub collation: String,
+    /// The pairs of characters `(lesser, greater)` of the alphabet, with `lesser < greater` as Rust chars,
+    /// that the collation does not keep in that order in every key: it sorts them the other way, treats
+    /// them as equal, or ignores one of them.
+    pub offending: Vec<(char, char)>,
+    /// The characters of the alphabet, in order, left after dropping each one the collation ignores or sorts
+    /// before a character kept so far: a subset that sorts the same under the collation as in Rust, and the
+    /// whole alphabet if it is safe.
+    pub safe_chars: String,
+}
+
+impl PostgresSafety {
+    /// Returns `true` if every key of the alphabet sorts the same under the collation as in Rust.
+    pub fn is_safe(&self) -> bool {
+        self.offending.is_empty()
+    }
+}
+
+impl fmt::Display for PostgresSafety {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (lesser, greater) = match self.offending.first() {
+            Some(pair) => pair,
+            None => return write!(f, "keys sort the same under collation {:?}", self.collation),
+        };
+        write!(
+            f,
+            "{} character pairs sort differently under collation {:?}, such as {:?} and {:?}; \
+             declare the column with COLLATE \"C\", or use the alphabet {:?}",
+            self.offending.len(),
+            self.collation,
+            lesser,
+            greater,
+            self.safe_chars
+        )
+    }
+}
+
+impl Between {
+    /// Checks whether keys of this alphabet sort the same under a PostgreSQL collation as they do in Rust,
+    /// so that `ORDER BY` on a column of keys returns the list in order.
+    ///
+    /// Natural-language collations, the default for most databases, do not compare strings character by
+    /// character: libc ones such as `"en_US.utf8"` ignore punctuation at first and compare letters
+    /// case-insensitively, and ICU ones such as `"und-x-icu"` sort punctuation before digits before letters.
+    /// This checks the alphabet against a model of the collation's family, so use
+    /// [`Between::verify_collation`] with the database's own comparison for certainty. Columns declared with
+    /// `COLLATE "C"` are always safe.
+    ///
+    /// # Arguments
+    ///
+    /// * `collation` - The collation name, such as `"C"`, `"en_US.utf8"` or `"und-x-icu"`. Names that are
+    ///   neither byte-order nor ICU collations are treated as libc ones.
+    ///
+    /// # Returns
+    ///
+    /// A [`PostgresSafety`] listing the offending character pairs, and a safe subset of the alphabet to use
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use between::Between;
+    ///
+    /// assert!(Between::init().check_postgres_safe("C").is_safe());
+    ///
+    /// let safety = Between::init().check_postgres_safe("en_US.utf8");
+    /// assert!(safety.offending.contains(&('B', 'a')));
+    /// assert_eq!(safety.safe_chars, "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    /// ```
+    pub fn check_postgres_safe(&self, collation: &str) -> PostgresSafety {
+        let model = Model::of(collation);
+        let chars = &self.alphabet.chars;
+        let weights: Vec<Option<(u8, u32)>> = chars.iter().map(|c| model.weight(*c)).collect();
+
+        let in_order = |lesser: &Option<(u8, u32)>, greater: &Option<(u8, u32)>| matches!((lesser, greater), (Some(lesser), Some(greater)) if lesser < greater);
+        let mut offending = Vec::new();
+        // Weights that increase along the alphabet keep every pair in order, so large alphabets are
+        // usually checked in one pass.
+        let sorted = weights.first().is_some_and(Option::is_some)
+            && weights.windows(2).all(|pair| in_order(&pair[0], &pair[1]));
+        for (i, lesser) in weights.iter().enumerate().filter(|_| !sorted) {
+            for (j, greater) in weights.iter().enumerate().skip(i + 1) {
+                if !in_order(lesser, greater) {
+                    offending.push((chars[i], chars[j]));
+                }
+            }
+        }
+
+        // Keep each character whose weight is above every one kept so far.
+        let mut safe_chars = String::new();
+        let mut last = None;
+        for (c, weight) in chars.iter().zip(&weights) {
+            if weight.is_some() && (last.is_none() || *weight > last) {
+                safe_chars.push(*c);
+                last = *weight;
+            }
+        }
+
+        PostgresSafety {
+            collation: collation.to_string(),
+            offending,
+            safe_chars,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_byte_order_collations_are_safe() {
+        for collation in ["C", "\"POSIX\"", "ucs_basic", "C.UTF-8", "pg_c_utf8"] {
+            let safety = Between::cjk().check_postgres_safe(collation);
+            assert!(safety.is_safe(), "{}", collation);
+            assert_eq!(
+                safety.safe_chars.chars().count(),
+                Between::cjk().chars().len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_natural_language_collations() {
+        let between = Between::init();
+        let libc = between.check_postgres_safe("en_US.utf8");
+        assert!(libc.offending.contains(&('!', '0')));
+        assert!(libc.offending.contains(&('Z', '_')));
+        assert!(!libc.offending.contains(&('0', 'A')));
+
+        let icu = between.check_postgres_safe("und-x-icu");
+        assert!(icu.offending.contains(&('Z', '_')) && icu.offending.contains(&('B', 'a')));
+        assert!(!icu.offending.contains(&('!', '0')));
+        assert_eq!(icu.safe_chars, "!0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+        // The recommended alphabets are safe themselves.
+        for (safety, collation) in [(libc, "en_US.utf8"), (icu, "und-x-icu")] {
+            let safe = Between::new(safety.safe_chars.chars().collect());
+            assert!(safe.check_postgres_safe(collation).is_safe());
+            assert!(safety.to_string().contains("COLLATE \"C\""));
+        }
+        assert!(Between::base36()
+            .check_postgres_safe("en_US.utf8")
+            .is_safe());
+    }
+}
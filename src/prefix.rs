@@ -0,0 +1,179 @@
+use crate::prelude::*;
+use crate::Between;
+
+/// A handle that generates keys beginning with a fixed prefix, so several logical lists can share one
+/// indexed column. Created with [`Between::with_prefix`].
+///
+/// The keys of a prefix are the strings strictly between the prefix and the prefix followed by the highest
+/// character, so they sort together, after any shorter prefix and before any greater one. Two prefixes only
+/// interleave if one is a prefix of the other (such as `"A"` and `"AB"`), so use fixed-length prefixes or
+/// end each with the same separator character. The maximum length, if any, applies to the part after the
+/// prefix.
+///
+/// # Example
+///
+/// ```
+/// use between::Between;
+///
+/// let board = Between::init().with_prefix("B1").unwrap();
+/// let first = board.after("").unwrap();
+/// let second = board.after(&first).unwrap();
+/// assert!(first.starts_with("B1") && first < second);
+/// assert!(board.contains(&second));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prefixed {
+    between: Between,
+    prefix: String,
+}
+
+impl Between {
+    /// Creates a handle whose generated keys all begin with `prefix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix. Its characters must belong to the alphabet.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Prefixed>` that contains the handle, or `None` if the prefix is empty or contains
+    /// characters outside the alphabet.
+    pub fn with_prefix<S>(self, prefix: S) -> Option<Prefixed>
+    where
+        S: Into<String>,
+    {
+        let prefix: String = prefix.into();
+        if prefix.is_empty() || !self.valid(prefix.as_str()) {
+            return None;
+        }
+        Some(Prefixed {
+            between: self,
+            prefix,
+        })
+    }
+}
+
+impl Prefixed {
+    /// Returns the prefix of the generated keys.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the `Between` instance used to generate the part of the keys after the prefix.
+    pub fn inner(&self) -> &Between {
+        &self.between
+    }
+
+    /// Checks if a key belongs to this prefix, i.e. if this handle could have generated it.
+    pub fn contains(&self, key: &str) -> bool {
+        self.suffix(key).is_some()
+    }
+
+    /// Returns the part of a key after the prefix, or `None` if the key does not belong to this prefix.
+    pub fn suffix<'k>(&self, key: &'k str) -> Option<&'k str> {
+        let suffix = key.strip_prefix(self.prefix.as_str())?;
+        let canonical = suffix.trim_end_matches(self.between.low());
+        let high = self.between.high().to_string();
+        if canonical.is_empty() || canonical >= high.as_str() || !self.between.valid(suffix) {
+            return None;
+        }
+        Some(suffix)
+    }
+
+    /// Finds a key of this prefix between two given keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `""` for the start of the prefix's keys.
+    /// * `that` - The second key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the new key, or `None` if a key does not belong to this prefix or
+    /// the bounds are invalid under the same rules as [`Between::between`].
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        let this = if this.is_empty() {
+            ""
+        } else {
+            self.suffix(this)?
+        };
+        let that = self.suffix(that)?;
+        self.prefixed(self.between.between(this, that))
+    }
+
+    /// Finds a key of this prefix after a given key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to find a successor for, or `""` for the start of the prefix's keys.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the new key, or `None` if the key does not belong to this prefix or
+    /// there is no room after it.
+    pub fn after(&self, key: &str) -> Option<String> {
+        let key = if key.is_empty() {
+            ""
+        } else {
+            self.suffix(key)?
+        };
+        self.prefixed(self.between.after(key))
+    }
+
+    /// Finds a key of this prefix before a given key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the new key, or `None` if the key does not belong to this prefix or
+    /// there is no room before it.
+    pub fn before(&self, key: &str) -> Option<String> {
+        self.prefixed(self.between.before(self.suffix(key)?))
+    }
+
+    fn prefixed(&self, suffix: Option<String>) -> Option<String> {
+        suffix.map(|suffix| format!("{}{}", self.prefix, suffix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_with_prefix() {
+        let between = Between::init();
+        let board = between.clone().with_prefix("B1").unwrap();
+        assert_eq!(board.prefix(), "B1");
+
+        let first = board.after("").unwrap();
+        assert_eq!(first, "B1V");
+        let second = board.after(&first).unwrap();
+        let tween = board.between(&first, &second).unwrap();
+        let before = board.before(&first).unwrap();
+        assert!(before < first && first < tween && tween < second);
+        assert!([&before, &first, &tween, &second]
+            .iter()
+            .all(|key| board.contains(key) && between.valid(key.as_str())));
+
+        assert!(between.clone().with_prefix("").is_none());
+        assert!(between.with_prefix("B$").is_none());
+    }
+
+    #[test]
+    fn test_prefixes_do_not_interleave() {
+        let between = Between::init();
+        let one = between.clone().with_prefix("B1").unwrap();
+        let two = between.with_prefix("B2").unwrap();
+
+        let mut key = one.after("").unwrap();
+        for _ in 0..100 {
+            key = one.after(&key).unwrap();
+            assert!(key.as_str() < "B2");
+        }
+        assert!(!two.contains(&key));
+        assert!(!one.contains("B1"));
+        assert!(!one.contains("B1~"));
+        assert!(two.after(&key).is_none());
+        assert!(one.between("B1A", "B2A").is_none());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::appender::counter;
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+/// Generates strictly decreasing strings for prepending to the start of a list, with logarithmic length growth.
+///
+/// This is the mirror image of [`Appender`](crate::Appender). Every key is the first key of the list with its
+/// last character decremented, followed by a counter whose characters are flipped (`low` and `high` swapped) so
+/// that later keys sort first. Flipped counters that end in the lowest character get the highest one appended,
+/// so keys never need trimming, and after `n` prepends they are about `log(n) / log(chars.len())` characters
+/// longer than the first key.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, Prepender};
+///
+/// let between = Between::init();
+/// let keys: Vec<String> = Prepender::new(&between, "V").unwrap().take(3).collect();
+/// assert_eq!(keys, vec!["U~~", "U~z", "U~y"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prepender<'a> {
+    between: &'a Between,
+    prefix: Vec<usize>,
+    count: u64,
+}
+
+impl<'a> Prepender<'a> {
+    /// Creates a new `Prepender` generating strings that sort before `first`.
+    ///
+    /// # Arguments
+    ///
+    /// * `between` - The `Between` instance whose alphabet to use.
+    /// * `first` - The current first string of the list, or an empty string if the list is empty.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Prepender>`, or `None` if `first` contains characters outside the alphabet or nothing sorts
+    /// before it.
+    pub fn new<S>(between: &'a Between, first: S) -> Option<Self>
+    where
+        S: AsKey,
+    {
+        let first = first.as_key();
+        let mut prefix = if first.is_empty() {
+            vec![between.alphabet.chars.len() - 1]
+        } else {
+            between.indices(first.trim_end_matches(between.low))?
+        };
+        // Every key is 'prefix' followed by more characters, so it sorts between 'prefix' and 'first'.
+        *prefix.last_mut()? -= 1;
+        Some(Prepender {
+            between,
+            prefix,
+            count: 0,
+        })
+    }
+}
+
+impl<'a> Iterator for Prepender<'a> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let high = self.between.alphabet.chars.len() - 1;
+        let mut key = self.prefix.clone();
+        // Counters are prefix-free, so flipping every character reverses their order, and terminating
+        // the ones that end in the lowest character does not change it.
+        key.extend(
+            counter(self.between.alphabet.chars.len(), self.count)?
+                .into_iter()
+                .map(|index| high - index),
+        );
+        if key.last() == Some(&0) {
+            key.push(high);
+        }
+        self.count = self.count.checked_add(1)?;
+        Some(self.between.key_from_indices(&key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, Prepender};
+
+    #[test]
+    fn test_prepender_is_strictly_decreasing() {
+        let between = Between::init();
+
+        let keys: Vec<String> = Prepender::new(&between, "V")
+            .unwrap()
+            .take(10_000)
+            .collect();
+        assert!(keys.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(keys.iter().all(|key| key.as_str() < "V"));
+        assert!(keys.iter().all(|key| !key.ends_with(between.low())));
+        assert!(keys.iter().all(|key| key.len() <= 6));
+
+        let keys: Vec<String> = Prepender::new(&between, "").unwrap().take(100).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(keys.iter().all(|key| between.after(key.as_str()).is_some()));
+
+        let keys: Vec<String> = Prepender::new(&between, "!0").unwrap().take(100).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(keys.iter().all(|key| key.as_str() < "!0"));
+
+        assert!(Prepender::new(&between, "!").is_none());
+        assert!(Prepender::new(&between, "$").is_none());
+    }
+
+    #[test]
+    fn test_prepender_length_grows_logarithmically() {
+        let between = Between::new(vec!['0', '1']);
+
+        let keys: Vec<String> = Prepender::new(&between, "1").unwrap().take(4096).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(keys.iter().all(|key| !key.ends_with('0')));
+        // One character of prefix, then the same counters as an appender.
+        assert_eq!(keys.iter().map(String::len).max().unwrap(), 26);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::prelude::*;
+use crate::Between;
+
+impl Between {
+    /// Initializes a `Between` instance with the 62 digits and ASCII letters, `0-9A-Za-z`.
+    ///
+    /// This is the alphabet of mudder's `base62` and of the `fractional-indexing` libraries.
+    pub fn base62() -> Self {
+        Between::new(('0'..='9').chain('A'..='Z').chain('a'..='z').collect())
+    }
+
+    /// Initializes a `Between` instance with the 36 digits and lowercase ASCII letters, `0-9a-z`.
+    ///
+    /// This suits storage that compares strings case-insensitively, and is the alphabet of LexoRank.
+    pub fn base36() -> Self {
+        Between::new(('0'..='9').chain('a'..='z').collect())
+    }
+
+    /// Initializes a `Between` instance with the 16 lowercase hexadecimal digits, `0-9a-f`.
+    pub fn base16() -> Self {
+        Between::new(('0'..='9').chain('a'..='f').collect())
+    }
+
+    /// Initializes a `Between` instance with the 10 decimal digits, `0-9`.
+    pub fn base10() -> Self {
+        Between::new(('0'..='9').collect())
+    }
+
+    /// Initializes a `Between` instance with the 64 characters of the URL-safe base64 alphabet,
+    /// `-0-9A-Z_a-z`, which need no escaping in URLs and file names.
+    pub fn base64url() -> Self {
+        Between::new(
+            ('0'..='9')
+                .chain('A'..='Z')
+                .chain('a'..='z')
+                .chain(['-', '_'])
+                .collect(),
+        )
+    }
+
+    /// Initializes a `Between` instance with Crockford's base32 alphabet, `0-9A-Z` without `I`, `L`, `O` and
+    /// `U`, which avoids characters people confuse when reading or typing keys.
+    ///
+    /// Use [`Between::normalize_crockford`] to read keys typed by people.
+    pub fn crockford32() -> Self {
+        Between::new(
+            ('0'..='9')
+                .chain('A'..='Z')
+                .filter(|c| !matches!(c, 'I' | 'L' | 'O' | 'U'))
+                .collect(),
+        )
+    }
+
+    /// Normalizes a Crockford base32 key typed by a person, as Crockford's decoding rules prescribe.
+    ///
+    /// Letters are made uppercase, `I` and `L` are read as `1`, `O` is read as `0`, and hyphens are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key as typed.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the key in the alphabet of [`Between::crockford32`], or `None` if it
+    /// contains `U` or characters outside the alphabet.
+    pub fn normalize_crockford(key: &str) -> Option<String> {
+        key.chars()
+            .filter(|c| *c != '-')
+            .map(|c| match c.to_ascii_uppercase() {
+                'I' | 'L' => Some('1'),
+                'O' => Some('0'),
+                'U' => None,
+                c if c.is_ascii_digit() || c.is_ascii_uppercase() => Some(c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Initializes a `Between` instance with the 94 printable ASCII characters, `!` to `~`.
+    ///
+    /// The space is left out, since storage layers often trim it.
+    pub fn ascii_printable() -> Self {
+        Between::new(('!'..='~').collect())
+    }
+
+    /// Initializes a `Between` instance with the 66 letters of the Russian Cyrillic alphabet, `А-я` plus `Ё`
+    /// and `ё`.
+    ///
+    /// Keys sort by code point, so `Ё` (U+0401) sorts before `А` and `ё` (U+0451) after `я`, unlike in a
+    /// Russian dictionary.
+    pub fn cyrillic() -> Self {
+        Between::new(('А'..='я').chain(['Ё', 'ё']).collect())
+    }
+
+    /// Initializes a `Between` instance with the 49 letters of the modern Greek alphabet, `Α-Ω` and `α-ω`
+    /// including the final sigma `ς`.
+    pub fn greek() -> Self {
+        Between::new(
+            ('Α'..='Ω')
+                .chain('α'..='ω')
+                .filter(|c| c.is_alphabetic())
+                .collect(),
+        )
+    }
+
+    /// Initializes a `Between` instance with the 86 Hiragana letters, `ぁ` (U+3041) to `ゖ` (U+3096).
+    pub fn hiragana() -> Self {
+        Between::new(('ぁ'..='ゖ').collect())
+    }
+
+    /// Initializes a `Between` instance with the 11,172 precomposed Hangul syllables, `가` (U+AC00) to `힣`
+    /// (U+D7A3).
+    pub fn hangul() -> Self {
+        Between::new(('가'..='힣').collect())
+    }
+
+    /// Initializes a `Between` instance with the 20,992 characters of the CJK Unified Ideographs block, `一`
+    /// (U+4E00) to U+9FFF.
+    ///
+    /// With this many characters, keys stay very short, but each takes three bytes in UTF-8.
+    pub fn cjk() -> Self {
+        Between::new(('\u{4E00}'..='\u{9FFF}').collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_presets() {
+        let presets = [
+            (Between::base62(), 62, '0', 'z'),
+            (Between::base36(), 36, '0', 'z'),
+            (Between::base16(), 16, '0', 'f'),
+            (Between::base10(), 10, '0', '9'),
+            (Between::base64url(), 64, '-', 'z'),
+            (Between::crockford32(), 32, '0', 'Z'),
+            (Between::ascii_printable(), 94, '!', '~'),
+        ];
+        for (between, len, low, high) in presets {
+            assert_eq!(between.chars().len(), len);
+            assert_eq!((between.low(), between.high()), (low, high));
+            let key = between.between("1", "2").unwrap();
+            assert!("1" < key.as_str() && key.as_str() < "2");
+        }
+
+        assert!(Between::base16().valid("0af"));
+        assert!(!Between::base16().valid("0aF"));
+        assert!(!Between::ascii_printable().valid(" "));
+    }
+
+    #[test]
+    fn test_script_presets() {
+        let presets = [
+            (Between::cyrillic(), 66, 'Ё', 'ё'),
+            (Between::greek(), 49, 'Α', 'ω'),
+            (Between::hiragana(), 86, 'ぁ', 'ゖ'),
+            (Between::hangul(), 11172, '가', '힣'),
+            (Between::cjk(), 20992, '一', '\u{9FFF}'),
+        ];
+        for (between, len, low, high) in presets {
+            assert_eq!(between.chars().len(), len);
+            assert_eq!((between.low(), between.high()), (low, high));
+            let mut keys: Vec<String> = between.after_iter("").take(100).collect();
+            let sorted = keys.clone();
+            keys.sort();
+            assert_eq!(keys, sorted);
+        }
+
+        assert_eq!(Between::cyrillic().between("А", "Б").unwrap(), "Аа");
+        assert!(!Between::greek().valid("\u{3A2}"));
+        assert_eq!(Between::cjk().between("一", "三").unwrap(), "丅");
+    }
+
+    #[test]
+    fn test_crockford() {
+        let between = Between::crockford32();
+        assert!(
+            !between.valid("I")
+                && !between.valid("L")
+                && !between.valid("O")
+                && !between.valid("U")
+        );
+
+        assert_eq!(Between::normalize_crockford("1o-il").unwrap(), "1011");
+        assert_eq!(Between::normalize_crockford("abz").unwrap(), "ABZ");
+        assert!(between.valid(
+            Between::normalize_crockford("hello-world")
+                .unwrap()
+                .as_str()
+        ));
+        assert!(Between::normalize_crockford("u").is_none());
+        assert!(Between::normalize_crockford("a!").is_none());
+    }
+}
@@ -0,0 +1,385 @@
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::prelude::*;
+use crate::{strategy, Between};
+
+/// Returns the preset a [`KeyspaceProfile`] names.
+fn preset(name: &str) -> Option<Between> {
+    let between = match name {
+        "default" => Between::init(),
+        "base62" => Between::base62(),
+        "base36" => Between::base36(),
+        "base16" => Between::base16(),
+        "base10" => Between::base10(),
+        "base64url" => Between::base64url(),
+        "crockford32" => Between::crockford32(),
+        "ascii_printable" => Between::ascii_printable(),
+        "url_safe" => Between::url_safe(),
+        "filename_safe" => Between::filename_safe(),
+        "cyrillic" => Between::cyrillic(),
+        "greek" => Between::greek(),
+        "hiragana" => Between::hiragana(),
+        "hangul" => Between::hangul(),
+        "cjk" => Between::cjk(),
+        _ => return None,
+    };
+    Some(between)
+}
+
+/// The alphabet of a [`KeyspaceProfile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileAlphabet {
+    /// A preset, by the name of its constructor, e.g. `"base62"` for [`Between::base62`] or `"default"` for
+    /// [`Between::init`].
+    Preset(String),
+    /// Custom characters, in any order.
+    Custom(String),
+}
+
+/// An error returned when a [`KeyspaceProfile`] cannot be parsed or turned into a [`Between`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileError {
+    /// A field is not a `name=value` pair, is repeated, or has a value that is not valid for it.
+    Malformed {
+        /// The offending field, as written.
+        field: String,
+    },
+    /// A field name is not one of `preset`, `chars`, `strategy`, `max_len` and `jitter`.
+    UnknownField {
+        /// The field name.
+        name: String,
+    },
+    /// Neither `preset` nor `chars` was given, or both were.
+    MissingAlphabet,
+    /// The preset is not one of the built-in ones.
+    UnknownPreset {
+        /// The preset name.
+        name: String,
+    },
+    /// The custom alphabet has fewer than two distinct characters.
+    InvalidAlphabet,
+    /// The strategy is not one of the built-in ones, or needs a feature this build lacks.
+    UnknownStrategy {
+        /// The strategy name.
+        name: String,
+    },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::Malformed { field } => write!(f, "malformed field {:?}", field),
+            ProfileError::UnknownField { name } => write!(f, "unknown field {:?}", name),
+            ProfileError::MissingAlphabet => f.write_str("expect exactly one of preset and chars"),
+            ProfileError::UnknownPreset { name } => write!(f, "unknown preset {:?}", name),
+            ProfileError::InvalidAlphabet => {
+                f.write_str("expect chars to have at least two distinct characters")
+            }
+            ProfileError::UnknownStrategy { name } => write!(f, "unknown strategy {:?}", name),
+        }
+    }
+}
+
+impl Error for ProfileError {}
+
+/// A keyspace configuration that services written in different languages can share as one canonical
+/// string.
+///
+/// The string is a URL query, such as `preset=base62&strategy=Bisect&max_len=12&jitter=false`, which every
+/// platform can parse with its standard library. Values are percent-encoded, so custom alphabets may use any
+/// character, and `+` is read as a space, as in HTML forms. [`KeyspaceProfile::to_string`] always writes the
+/// fields in the same order, so equal profiles produce equal strings.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, KeyspaceProfile};
+///
+/// let profile: KeyspaceProfile = "preset=base62&max_len=12".parse().unwrap();
+/// assert_eq!(
+///     profile.to_string(),
+///     "preset=base62&strategy=Bisect&max_len=12&jitter=false"
+/// );
+///
+/// let between = profile.between().unwrap();
+/// assert_eq!(between.chars(), Between::base62().chars());
+/// assert_eq!(between.max_len(), Some(12));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyspaceProfile {
+    /// The alphabet keys are written with.
+    pub alphabet: ProfileAlphabet,
+    /// The name of the strategy picking keys in a gap, as returned by [`Strategy::name`](crate::Strategy::name).
+    pub strategy: String,
+    /// The maximum length of generated keys, in characters.
+    pub max_len: Option<usize>,
+    /// Whether inserts pick a random key in the gap, as [`Between::between_jittered`] does, rather than
+    /// using the strategy, so concurrent inserts rarely collide.
+    pub jitter: bool,
+}
+
+impl KeyspaceProfile {
+    /// Creates a profile for the given alphabet, with the default strategy, no maximum length and no jitter.
+    pub fn new(alphabet: ProfileAlphabet) -> Self {
+        KeyspaceProfile {
+            alphabet,
+            strategy: "Bisect".to_string(),
+            max_len: None,
+            jitter: false,
+        }
+    }
+
+    /// Creates a profile describing a `Between` instance, with its characters as a custom alphabet.
+    ///
+    /// # Returns
+    ///
+    /// The profile, or `None` if the instance uses a custom strategy, which has no name. Forbidden substrings
+    /// are not part of a profile.
+    pub fn of(between: &Between) -> Option<Self> {
+        Some(KeyspaceProfile {
+            alphabet: ProfileAlphabet::Custom(between.chars().iter().collect()),
+            strategy: between.strategy().name()?.to_string(),
+            max_len: between.max_len(),
+            jitter: false,
+        })
+    }
+
+    /// Parses a profile from its string form. See [`KeyspaceProfile`].
+    pub fn parse(profile: &str) -> Result<Self, ProfileError> {
+        profile.parse()
+    }
+
+    /// Builds the `Between` instance the profile describes.
+    ///
+    /// # Returns
+    ///
+    /// The instance, or a [`ProfileError`] if the preset or strategy is unknown, the custom alphabet has
+    /// fewer than two distinct characters, or jitter is requested without the `rand` feature, which provides
+    /// the [`Randomized`](crate::Randomized) strategy jittered inserts use.
+    pub fn between(&self) -> Result<Between, ProfileError> {
+        let mut between = match &self.alphabet {
+            ProfileAlphabet::Preset(name) => {
+                preset(name).ok_or_else(|| ProfileError::UnknownPreset { name: name.clone() })?
+            }
+            ProfileAlphabet::Custom(chars) => {
+                let mut chars: Vec<char> = chars.chars().collect();
+                chars.sort_unstable();
+                chars.dedup();
+                if chars.len() < 2 {
+                    return Err(ProfileError::InvalidAlphabet);
+                }
+                Between::new(chars)
+            }
+        };
+        let name = if self.jitter {
+            "Randomized"
+        } else {
+            self.strategy.as_str()
+        };
+        between.strategy = strategy::named(name).ok_or_else(|| ProfileError::UnknownStrategy {
+            name: name.to_string(),
+        })?;
+        between.max_len = self.max_len;
+        Ok(between)
+    }
+}
+
+impl fmt::Display for KeyspaceProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.alphabet {
+            ProfileAlphabet::Preset(name) => write!(f, "preset={}", Encoded(name))?,
+            ProfileAlphabet::Custom(chars) => write!(f, "chars={}", Encoded(chars))?,
+        }
+        write!(f, "&strategy={}", Encoded(&self.strategy))?;
+        if let Some(max_len) = self.max_len {
+            write!(f, "&max_len={}", max_len)?;
+        }
+        write!(f, "&jitter={}", self.jitter)
+    }
+}
+
+impl FromStr for KeyspaceProfile {
+    type Err = ProfileError;
+
+    fn from_str(profile: &str) -> Result<Self, Self::Err> {
+        let mut alphabet = None;
+        let mut strategy = None;
+        let mut max_len = None;
+        let mut jitter = None;
+        for field in profile.split('&') {
+            let malformed = || ProfileError::Malformed {
+                field: field.to_string(),
+            };
+            let (name, value) = field.split_once('=').ok_or_else(malformed)?;
+            let value = decode(value).ok_or_else(malformed)?;
+            let first = match name {
+                "preset" | "chars" => {
+                    let value = match name {
+                        "preset" => ProfileAlphabet::Preset(value),
+                        _ => ProfileAlphabet::Custom(value),
+                    };
+                    alphabet.replace(value).is_none()
+                }
+                "strategy" => strategy.replace(value).is_none(),
+                "max_len" => max_len
+                    .replace(value.parse().map_err(|_| malformed())?)
+                    .is_none(),
+                "jitter" => jitter
+                    .replace(value.parse().map_err(|_| malformed())?)
+                    .is_none(),
+                _ => {
+                    return Err(ProfileError::UnknownField {
+                        name: name.to_string(),
+                    })
+                }
+            };
+            if !first {
+                return Err(match name {
+                    "preset" | "chars" => ProfileError::MissingAlphabet,
+                    _ => malformed(),
+                });
+            }
+        }
+        let mut profile = KeyspaceProfile::new(alphabet.ok_or(ProfileError::MissingAlphabet)?);
+        if let Some(strategy) = strategy {
+            profile.strategy = strategy;
+        }
+        profile.max_len = max_len;
+        profile.jitter = jitter.unwrap_or(false);
+        Ok(profile)
+    }
+}
+
+/// Writes a value percent-encoded, leaving only RFC 3986 unreserved characters as they are.
+struct Encoded<'a>(&'a str);
+
+impl fmt::Display for Encoded<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                write!(f, "{}", byte as char)?;
+            } else {
+                write!(f, "%{:02X}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Decodes a value the way `application/x-www-form-urlencoded` does, with `+` standing for a space, or returns
+/// `None` if an escape is malformed or the value is not valid UTF-8 once decoded.
+fn decode(value: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        if byte == b'%' {
+            let hex = tail.get(..2)?;
+            // 'from_str_radix' would also accept a sign, as in "%+1".
+            if !hex.iter().all(u8::is_ascii_hexdigit) {
+                return None;
+            }
+            bytes.push(u8::from_str_radix(core::str::from_utf8(hex).ok()?, 16).ok()?);
+            rest = &tail[2..];
+        } else if byte == b'+' {
+            bytes.push(b' ');
+            rest = tail;
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, KeyspaceProfile, PreferLeft, ProfileAlphabet, ProfileError};
+
+    #[test]
+    fn test_profile_round_trip() {
+        let between = Between::new("a&b=c%é ".chars().collect())
+            .with_strategy(PreferLeft)
+            .with_max_len(8);
+        let profile = KeyspaceProfile::of(&between).unwrap();
+        let string = profile.to_string();
+        assert_eq!(
+            string,
+            "chars=%20%25%26%3Dabc%C3%A9&strategy=PreferLeft&max_len=8&jitter=false"
+        );
+        let parsed = KeyspaceProfile::parse(&string).unwrap();
+        assert_eq!(parsed, profile);
+
+        let rebuilt = parsed.between().unwrap();
+        assert_eq!(rebuilt.chars(), between.chars());
+        assert_eq!(rebuilt.max_len(), Some(8));
+        assert_eq!(rebuilt.between("a", "c"), between.between("a", "c"));
+
+        // JavaScript's 'URLSearchParams' and Python's 'urlencode' write a space as '+' and a '+' as "%2B".
+        let parsed = KeyspaceProfile::parse("chars=a+b%2B&max_len=4").unwrap();
+        let rebuilt = parsed.between().unwrap();
+        assert_eq!(rebuilt.chars(), &vec![' ', '+', 'a', 'b']);
+        assert_eq!(KeyspaceProfile::parse(&parsed.to_string()).unwrap(), parsed);
+
+        let preset = KeyspaceProfile::parse("jitter=false&preset=crockford32").unwrap();
+        assert_eq!(
+            preset.alphabet,
+            ProfileAlphabet::Preset("crockford32".to_string())
+        );
+        assert_eq!(
+            preset.between().unwrap().chars(),
+            Between::crockford32().chars()
+        );
+    }
+
+    #[test]
+    fn test_profile_errors() {
+        let parse = KeyspaceProfile::parse;
+        assert_eq!(parse("max_len=3"), Err(ProfileError::MissingAlphabet));
+        assert_eq!(
+            parse("preset=base10&chars=ab"),
+            Err(ProfileError::MissingAlphabet)
+        );
+        assert!(matches!(
+            parse("preset=base10&max_len=x"),
+            Err(ProfileError::Malformed { .. })
+        ));
+        assert!(matches!(
+            parse("preset=base10&jitter"),
+            Err(ProfileError::Malformed { .. })
+        ));
+        assert!(matches!(
+            parse("chars=%C3"),
+            Err(ProfileError::Malformed { .. })
+        ));
+        for escape in ["%+1", "%-1", "%1", "%G0"] {
+            assert!(matches!(
+                parse(&format!("chars=ab{}", escape)),
+                Err(ProfileError::Malformed { .. })
+            ));
+        }
+        assert!(matches!(
+            parse("preset=base10&colour=red"),
+            Err(ProfileError::UnknownField { .. })
+        ));
+
+        let between = |profile: &str| parse(profile).unwrap().between();
+        assert!(matches!(
+            between("preset=base63"),
+            Err(ProfileError::UnknownPreset { .. })
+        ));
+        assert_eq!(
+            between("chars=aa").unwrap_err(),
+            ProfileError::InvalidAlphabet
+        );
+        assert!(matches!(
+            between("chars=ab&strategy=Nope"),
+            Err(ProfileError::UnknownStrategy { .. })
+        ));
+        assert_eq!(
+            between("chars=ab&jitter=true").is_ok(),
+            cfg!(feature = "rand")
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::prelude::*;
+use crate::{Between, Key};
+
+/// Generates a key of the default alphabet, like [`Key`]'s `FromStr` accepts, of up to `g.size()`
+/// characters. Keys shrink to their shorter prefixes, then to themselves with one character removed.
+impl Arbitrary for Key {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let between = Between::init();
+        let len = 1 + usize::arbitrary(g) % g.size().max(1);
+        Key((0..len)
+            .map(|_| *g.choose(&between.alphabet.chars).unwrap())
+            .collect())
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let chars: Vec<char> = self.0.chars().collect();
+        let prefixes = (1..chars.len()).map({
+            let chars = chars.clone();
+            move |len| chars[..len].iter().collect::<String>()
+        });
+        // Removing the only character would leave an invalid, empty key.
+        let removable = if chars.len() > 1 { chars.len() } else { 0 };
+        let removals = (0..removable).map(move |index| {
+            chars
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != index)
+                .map(|(_, c)| c)
+                .collect::<String>()
+        });
+        let mut seen = Vec::new();
+        Box::new(prefixes.chain(removals).filter_map(move |key| {
+            if seen.contains(&key) {
+                return None;
+            }
+            seen.push(key.clone());
+            Some(Key(key))
+        }))
+    }
+}
+
+/// Two keys of the default alphabet with a key between them, for properties of [`Between::between`].
+///
+/// Neither key ends in the lowest character, which [`Between::between`] ignores, so `lesser` sorts strictly
+/// before `greater` both as strings and as bounds. Pairs shrink by shrinking either key while keeping these
+/// invariants.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, KeyPair};
+/// use quickcheck::quickcheck;
+///
+/// fn between_is_between(pair: KeyPair) -> bool {
+///     let key = Between::init().between(&pair.lesser, &pair.greater).unwrap();
+///     pair.lesser.as_str() < key.as_str() && key.as_str() < pair.greater.as_str()
+/// }
+///
+/// quickcheck(between_is_between as fn(KeyPair) -> bool);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPair {
+    /// The lesser key.
+    pub lesser: Key,
+    /// The greater key.
+    pub greater: Key,
+}
+
+impl KeyPair {
+    /// Checks the invariants of a pair.
+    fn valid(lesser: &Key, greater: &Key) -> bool {
+        let low = Between::init().low();
+        !lesser.0.ends_with(low) && !greater.0.ends_with(low) && lesser < greater
+    }
+}
+
+impl Arbitrary for KeyPair {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let high = Between::init().high();
+        let mut canonical = || loop {
+            let key = Key::arbitrary(g);
+            let trimmed = key.0.trim_end_matches(Between::init().low());
+            if !trimmed.is_empty() {
+                return trimmed.to_string();
+            }
+        };
+        let (mut lesser, mut greater) = (canonical(), canonical());
+        if lesser == greater {
+            greater.push(high);
+        } else if lesser > greater {
+            core::mem::swap(&mut lesser, &mut greater);
+        }
+        KeyPair {
+            lesser: Key(lesser),
+            greater: Key(greater),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let (lesser, greater) = (self.lesser.clone(), self.greater.clone());
+        let shrunk_lesser = self.lesser.shrink().map({
+            let greater = greater.clone();
+            move |lesser| KeyPair {
+                lesser,
+                greater: greater.clone(),
+            }
+        });
+        let shrunk_greater = self.greater.shrink().map(move |greater| KeyPair {
+            lesser: lesser.clone(),
+            greater,
+        });
+        Box::new(
+            shrunk_lesser
+                .chain(shrunk_greater)
+                .filter(|pair| KeyPair::valid(&pair.lesser, &pair.greater)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    use crate::{Between, Key, KeyPair};
+
+    #[test]
+    fn test_arbitrary_pairs_have_a_key_between() {
+        fn prop(pair: KeyPair) -> bool {
+            let between = Between::init();
+            KeyPair::valid(&pair.lesser, &pair.greater)
+                && between.valid(&pair.lesser)
+                && between
+                    .between(&pair.lesser, &pair.greater)
+                    .is_some_and(|key| {
+                        pair.lesser.as_str() < key.as_str() && key.as_str() < pair.greater.as_str()
+                    })
+        }
+        QuickCheck::new()
+            .tests(500)
+            .quickcheck(prop as fn(KeyPair) -> bool);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let key: Key = "ABC".parse().unwrap();
+        let shrunk: Vec<String> = key.shrink().map(Key::into_string).collect();
+        assert_eq!(shrunk, vec!["A", "AB", "BC", "AC"]);
+
+        let mut g = Gen::new(20);
+        for _ in 0..100 {
+            let pair = KeyPair::arbitrary(&mut g);
+            assert!(pair
+                .shrink()
+                .all(|shrunk| KeyPair::valid(&shrunk.lesser, &shrunk.greater)));
+        }
+    }
+}
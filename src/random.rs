@@ -0,0 +1,178 @@
+use num_bigint::{BigUint, RandBigInt};
+use rand::Rng;
+
+use crate::seeded::JITTER_SLOTS;
+use crate::{AsKey, Between};
+
+/// The least number of strings a gap must hold before it is sampled uniformly.
+const SAMPLE_SLOTS: u64 = 1 << 32;
+
+impl Between {
+    /// Finds a random string between two given strings.
+    ///
+    /// [`Between::between`] is deterministic, so two clients inserting between the same two strings at the
+    /// same time always generate the same key. This instead picks uniformly at random among the strings
+    /// of the shortest length at which the gap holds at least 4096 of them, so concurrent inserts collide
+    /// with a probability of at most 1 in 4096 while still sorting strictly between `this` and `that`.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `rng` - The random number generator to use.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`].
+    pub fn between_jittered<S, T, R>(&self, this: S, that: T, rng: &mut R) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+        R: Rng + ?Sized,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+
+        Some(self.sample(&this, &that, &BigUint::from(JITTER_SLOTS), rng))
+    }
+
+    /// Generates a random string of at most `len` characters, uniformly distributed over the keyspace.
+    ///
+    /// Every non-empty string of at most `len` characters that does not end in the lowest character is
+    /// equally likely. This is useful for load tests, sharding experiments and property tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The maximum length of the string.
+    /// * `rng` - The random number generator to use.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the random string, or `None` if `len` is zero.
+    pub fn random_key<R>(&self, len: usize, rng: &mut R) -> Option<String>
+    where
+        R: Rng + ?Sized,
+    {
+        if len == 0 {
+            return None;
+        }
+        // Every value below 'chars.len() ^ len' except zero is a distinct string.
+        let limit = self.scaled(&[1], len + 1);
+        let value = rng.gen_biguint_range(&BigUint::from(1u32), &limit);
+        Some(self.key_from_indices(&self.unscaled(&value, len)))
+    }
+
+    /// Generates a random string between two given strings, uniformly distributed over the gap.
+    ///
+    /// Unlike [`Between::between_jittered`], which prefers short strings, this samples at a fine enough
+    /// granularity (at least 2^32 candidates) that the results are spread uniformly over the gap.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `rng` - The random number generator to use.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`].
+    pub fn random_in_range<S, T, R>(&self, this: S, that: T, rng: &mut R) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+        R: Rng + ?Sized,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        Some(self.sample(&this, &that, &BigUint::from(SAMPLE_SLOTS), rng))
+    }
+
+    /// Picks a uniformly random string strictly between two bounds, at the shortest length at which the gap
+    /// holds more than `slots` strings.
+    fn sample<R>(&self, this: &[usize], that: &[usize], slots: &BigUint, rng: &mut R) -> String
+    where
+        R: Rng + ?Sized,
+    {
+        // The candidates are the integers strictly between 'low' and 'high'.
+        let (len, low, high) = self.gap_holding(this, that, slots);
+        let value = rng.gen_biguint_range(&(low + 1u32), &high);
+        self.key_from_indices(&self.unscaled(&value, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::Between;
+
+    #[test]
+    fn test_between_jittered() {
+        let between = Between::init();
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let mut keys: Vec<String> = (0..1000)
+            .map(|_| between.between_jittered("A", "B", &mut rng).unwrap())
+            .collect();
+        assert!(keys
+            .iter()
+            .all(|key| "A" < key.as_str() && key.as_str() < "B"));
+        assert!(keys.iter().all(|key| !key.ends_with(between.low())));
+        assert!(keys.iter().all(|key| key.len() <= 3));
+        keys.sort();
+        keys.dedup();
+        assert!(keys.len() > 850);
+
+        assert!(between.between_jittered("B", "A", &mut rng).is_none());
+        assert!(between.between_jittered("A", "A!", &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_random_key() {
+        let between = Between::new(vec!['0', '1']);
+        let mut rng = StdRng::seed_from_u64(1);
+
+        // There are 7 strings of at most 3 characters that don't end in '0', all equally likely.
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..7000 {
+            let key = between.random_key(3, &mut rng).unwrap();
+            assert!(!key.is_empty() && key.len() <= 3 && !key.ends_with('0'));
+            *counts.entry(key).or_insert(0) += 1;
+        }
+        assert_eq!(counts.len(), 7);
+        assert!(counts.values().all(|count| (800..1200).contains(count)));
+
+        assert!(between.random_key(0, &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_random_in_range() {
+        let between = Between::init();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let keys: Vec<String> = (0..1000)
+            .map(|_| between.random_in_range("A", "B", &mut rng).unwrap())
+            .collect();
+        assert!(keys
+            .iter()
+            .all(|key| "A" < key.as_str() && key.as_str() < "B"));
+        // Uniform over the gap, so about half of the keys fall in each half.
+        let below = keys.iter().filter(|key| key.as_str() < "AV").count();
+        assert!((400..600).contains(&below));
+
+        assert!(between.random_in_range("B", "A", &mut rng).is_none());
+    }
+
+    #[test]
+    fn test_between_jittered_narrow_gap() {
+        let between = Between::new(vec!['0', '1']);
+        let mut rng = StdRng::seed_from_u64(7);
+
+        for _ in 0..100 {
+            let key = between.between_jittered("01", "011", &mut rng).unwrap();
+            assert!("01" < key.as_str() && key.as_str() < "011");
+            assert!(!key.ends_with('0'));
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use alloc::collections::BTreeMap;
+use core::error::Error;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::{Between, BetweenError};
+
+/// A set of named, non-overlapping ranges of the keyspace, e.g. one per tenant sharing a sort column.
+///
+/// Each range is the open interval between its start and end, so keys allocated in it sort strictly between
+/// the two and can never reach another range. Adjacent ranges may share a boundary. A range is exhausted once
+/// no key fits within the instance's maximum length (see [`Between::with_max_len`]).
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, KeyRanges};
+///
+/// let mut ranges = KeyRanges::new(Between::init());
+/// ranges.reserve("acme", "", "V").unwrap();
+/// ranges.reserve("globex", "V", "~").unwrap();
+///
+/// let key = ranges.allocate("globex", None, None).unwrap();
+/// assert_eq!(ranges.range_of(&key), Some("globex"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyRanges {
+    between: Between,
+    ranges: BTreeMap<String, (String, String)>,
+}
+
+impl KeyRanges {
+    /// Creates an empty set of ranges generating keys with the given `Between` instance.
+    pub fn new(between: Between) -> Self {
+        KeyRanges {
+            between,
+            ranges: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the `Between` instance used to generate keys.
+    pub fn between(&self) -> &Between {
+        &self.between
+    }
+
+    /// Reserves the range strictly between `start` and `end` under `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the range.
+    /// * `start` - The lower bound, or `""` to start at the beginning of the keyspace.
+    /// * `end` - The upper bound. Use the highest character to extend to the end of the keyspace.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if the range was reserved, or a [`KeyRangeError`] if the name is taken, the bounds are invalid
+    /// under the same rules as [`Between::between`], or the range overlaps another.
+    pub fn reserve<N>(&mut self, name: N, start: &str, end: &str) -> Result<(), KeyRangeError>
+    where
+        N: Into<String>,
+    {
+        let name: String = name.into();
+        if self.ranges.contains_key(&name) {
+            return Err(KeyRangeError::DuplicateRange);
+        }
+        self.between
+            .bounds(start, end)
+            .ok_or(KeyRangeError::Between(BetweenError::InvalidBounds))?;
+        let (start, end) = (self.canonical(start), self.canonical(end));
+        if let Some((other, _)) = self
+            .ranges
+            .iter()
+            .find(|(_, (other_start, other_end))| start < *other_end && *other_start < end)
+        {
+            return Err(KeyRangeError::Overlap {
+                name: other.clone(),
+            });
+        }
+        self.ranges.insert(name, (start, end));
+        Ok(())
+    }
+
+    /// Removes a range, returning its bounds.
+    pub fn release(&mut self, name: &str) -> Option<(String, String)> {
+        self.ranges.remove(name)
+    }
+
+    /// Returns the bounds of a range, if it exists.
+    pub fn range(&self, name: &str) -> Option<(&str, &str)> {
+        self.ranges
+            .get(name)
+            .map(|(start, end)| (start.as_str(), end.as_str()))
+    }
+
+    /// Returns the name of the range a key falls in, if any.
+    pub fn range_of(&self, key: &str) -> Option<&str> {
+        let key = self.canonical(key);
+        self.ranges
+            .iter()
+            .find(|(_, (start, end))| *start < key && key < *end)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Finds a string between two given strings inside a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the range.
+    /// * `this` - The first string, or `None` for the start of the range.
+    /// * `that` - The second string, or `None` for the end of the range.
+    ///
+    /// # Returns
+    ///
+    /// The new string, or a [`KeyRangeError`] if the range does not exist, a bound lies outside it, the bounds
+    /// are invalid, or the range has no room left between them within the maximum length.
+    pub fn allocate(
+        &self,
+        name: &str,
+        this: Option<&str>,
+        that: Option<&str>,
+    ) -> Result<String, KeyRangeError> {
+        let (start, end) = self.ranges.get(name).ok_or(KeyRangeError::UnknownRange)?;
+        let this = this.unwrap_or(start);
+        let that = that.unwrap_or(end);
+        if self.canonical(this) < *start || self.canonical(that) > *end {
+            return Err(KeyRangeError::OutOfRange);
+        }
+        Ok(self.between.try_between(this, that)?)
+    }
+
+    fn canonical(&self, key: &str) -> String {
+        key.trim_end_matches(self.between.low()).to_string()
+    }
+}
+
+/// An error returned by [`KeyRanges`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyRangeError {
+    /// A range with the same name already exists.
+    DuplicateRange,
+    /// No range has the given name.
+    UnknownRange,
+    /// The range overlaps an existing one.
+    Overlap {
+        /// The name of the existing range.
+        name: String,
+    },
+    /// A bound lies outside the range.
+    OutOfRange,
+    /// No key could be generated, e.g. because the range is exhausted.
+    Between(BetweenError),
+}
+
+impl From<BetweenError> for KeyRangeError {
+    fn from(error: BetweenError) -> Self {
+        KeyRangeError::Between(error)
+    }
+}
+
+impl fmt::Display for KeyRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyRangeError::DuplicateRange => f.write_str("range already exists"),
+            KeyRangeError::UnknownRange => f.write_str("range does not exist"),
+            KeyRangeError::Overlap { name } => write!(f, "range overlaps range {:?}", name),
+            KeyRangeError::OutOfRange => f.write_str("bound lies outside the range"),
+            KeyRangeError::Between(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for KeyRangeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            KeyRangeError::Between(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, BetweenError, KeyRangeError, KeyRanges};
+
+    #[test]
+    fn test_key_ranges() {
+        let mut ranges = KeyRanges::new(Between::init());
+        ranges.reserve("a", "", "V").unwrap();
+        ranges.reserve("b", "V", "k").unwrap();
+
+        let mut key = ranges.allocate("b", None, None).unwrap();
+        for _ in 0..100 {
+            key = ranges.allocate("b", Some(&key), None).unwrap();
+            assert_eq!(ranges.range_of(&key), Some("b"));
+        }
+        let first = ranges.allocate("a", None, None).unwrap();
+        assert_eq!(ranges.range_of(&first), Some("a"));
+        assert_eq!(ranges.range_of("V"), None);
+        assert_eq!(ranges.range_of("z"), None);
+
+        assert_eq!(ranges.range("b"), Some(("V", "k")));
+        assert_eq!(ranges.release("b"), Some(("V".into(), "k".into())));
+        assert_eq!(ranges.range_of(&key), None);
+    }
+
+    #[test]
+    fn test_key_ranges_errors() {
+        let mut ranges = KeyRanges::new(Between::init());
+        ranges.reserve("a", "A", "M").unwrap();
+
+        assert_eq!(
+            ranges.reserve("a", "M", "Z"),
+            Err(KeyRangeError::DuplicateRange)
+        );
+        assert_eq!(
+            ranges.reserve("b", "L", "Z"),
+            Err(KeyRangeError::Overlap { name: "a".into() })
+        );
+        assert_eq!(
+            ranges.reserve("b", "Z", "M"),
+            Err(KeyRangeError::Between(BetweenError::InvalidBounds))
+        );
+        assert_eq!(
+            ranges.allocate("b", None, None),
+            Err(KeyRangeError::UnknownRange)
+        );
+        assert_eq!(
+            ranges.allocate("a", Some("B"), Some("N")),
+            Err(KeyRangeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_key_ranges_exhausted() {
+        let mut ranges = KeyRanges::new(Between::init().with_max_len(2));
+        ranges.reserve("a", "A", "B").unwrap();
+
+        // A range of one character holds at most 64 keys of at most 2 characters.
+        let mut key = "A".to_string();
+        let mut allocated = 0;
+        let error = loop {
+            match ranges.allocate("a", Some(&key), None) {
+                Ok(next) => key = next,
+                Err(error) => break error,
+            }
+            assert_eq!(ranges.range_of(&key), Some("a"));
+            allocated += 1;
+        };
+        assert!(allocated > 0 && allocated <= 64);
+        assert!(matches!(
+            error,
+            KeyRangeError::Between(BetweenError::KeyspaceExhausted { .. })
+        ));
+    }
+}
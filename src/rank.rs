@@ -0,0 +1,110 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Converts a string into its rank among all strings of at most `len` characters.
+    ///
+    /// Ranks run from 1 to `chars.len() ^ len - 1` and sort the same way as the strings they stand for, so
+    /// keys can be stored as integers (for example in OLAP systems) and converted back losslessly with
+    /// [`Between::index_to_key`]. Trailing lowest characters are ignored, like [`Between::between`] does.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The string to convert.
+    /// * `len` - The maximum length of strings in the keyspace.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<BigUint>` that contains the rank, or `None` if `key` is invalid, consists only of the
+    /// lowest character, or is longer than `len` characters.
+    pub fn key_to_index<S>(&self, key: S, len: usize) -> Option<BigUint>
+    where
+        S: AsKey,
+    {
+        let key = key.as_key();
+        let indices = self.indices(key.trim_end_matches(self.low))?;
+        if indices.is_empty() || indices.len() > len {
+            return None;
+        }
+        Some(self.scaled(&indices, len))
+    }
+
+    /// Converts a rank among all strings of at most `len` characters back into the string.
+    ///
+    /// This is the inverse of [`Between::key_to_index`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The rank to convert.
+    /// * `len` - The maximum length of strings in the keyspace.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the string, or `None` if `index` is zero or not less than
+    /// `chars.len() ^ len`.
+    pub fn index_to_key(&self, index: &BigUint, len: usize) -> Option<String> {
+        if index.is_zero() || *index >= self.scaled(&[1], len + 1) {
+            return None;
+        }
+        Some(self.key_from_indices(&self.unscaled(index, len)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigUint;
+
+    use crate::Between;
+
+    #[test]
+    fn test_key_to_index() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.key_to_index("1", 3).unwrap(), BigUint::from(100u32));
+        assert_eq!(
+            between.key_to_index("12", 3).unwrap(),
+            BigUint::from(120u32)
+        );
+        assert_eq!(
+            between.key_to_index("1200", 3).unwrap(),
+            BigUint::from(120u32)
+        );
+        assert_eq!(
+            between.key_to_index("999", 3).unwrap(),
+            BigUint::from(999u32)
+        );
+
+        assert!(between.key_to_index("1234", 3).is_none());
+        assert!(between.key_to_index("000", 3).is_none());
+        assert!(between.key_to_index("1a", 3).is_none());
+
+        assert_eq!(
+            between.index_to_key(&BigUint::from(120u32), 3).unwrap(),
+            "12"
+        );
+        assert_eq!(
+            between.index_to_key(&BigUint::from(1u32), 3).unwrap(),
+            "001"
+        );
+        assert!(between.index_to_key(&BigUint::from(0u32), 3).is_none());
+        assert!(between.index_to_key(&BigUint::from(1000u32), 3).is_none());
+    }
+
+    #[test]
+    fn test_rank_round_trips_and_preserves_order() {
+        let between = Between::init();
+
+        let keys = between.n_between("A", "B", 500).unwrap();
+        let ranks: Vec<BigUint> = keys
+            .iter()
+            .map(|key| between.key_to_index(key.as_str(), 5).unwrap())
+            .collect();
+        assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+        for (key, rank) in keys.iter().zip(&ranks) {
+            assert_eq!(&between.index_to_key(rank, 5).unwrap(), key);
+        }
+    }
+}
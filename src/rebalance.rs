@@ -0,0 +1,314 @@
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::Between;
+
+impl Between {
+    /// Shortens the strings of a sorted list where possible, without changing their order.
+    ///
+    /// The list is walked from first to last, and every string is replaced by [`Between::shorten`] against
+    /// its (possibly already replaced) predecessor and its successor. Only replacements that make a string
+    /// shorter are returned, so applying them rewrites as few rows as possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings, in strictly ascending order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<(usize, String)>>` that contains, in ascending order, the index of each string to
+    /// replace paired with its replacement, or `None` if any string is invalid or the list is not strictly
+    /// ascending.
+    pub fn compact<S>(&self, keys: &[S]) -> Option<Vec<(usize, String)>>
+    where
+        S: AsRef<str>,
+    {
+        self.sorted_indices(keys)?;
+
+        let mut replacements = Vec::new();
+        let mut prev = String::new();
+        for (index, key) in keys.iter().enumerate() {
+            let key = key.as_ref();
+            let next = keys.get(index + 1).map_or("", |next| next.as_ref());
+            // Only a last string starting with the highest character has no shorter form, as nothing sorts
+            // after it.
+            match self.shorten(key, prev.as_str(), next) {
+                Some(shorter) if shorter.chars().count() < key.chars().count() => {
+                    replacements.push((index, shorter.clone()));
+                    prev = shorter;
+                }
+                _ => prev = key.to_string(),
+            }
+        }
+        Some(replacements)
+    }
+
+    /// Generates a fresh set of short, evenly spaced strings to replace every string of a list.
+    ///
+    /// After heavy churn, keys get long and clustered. The `i`-th new string replaces `existing[i]`, so the
+    /// list keeps its order, and the new strings are spread across the whole keyspace like
+    /// [`Between::spread`]. This suits a one-shot migration that rewrites every row; see also
+    /// [`Between::compact`], which rewrites fewer.
+    ///
+    /// # Arguments
+    ///
+    /// * `existing` - The strings to replace, in strictly ascending order.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains one new string per existing one, in ascending order, or `None`
+    /// if any string is invalid or the list is not strictly ascending.
+    pub fn rebalance<S>(&self, existing: &[S]) -> Option<Vec<String>>
+    where
+        S: AsRef<str>,
+    {
+        self.sorted_indices(existing)?;
+        Some(self.spread(existing.len()))
+    }
+
+    /// Plans the fewest rewrites that bring every string of a sorted list within a maximum length, while
+    /// leaving room to insert a string of at most that length into every gap.
+    ///
+    /// Full rebalances rewrite every row. This instead keeps as many strings as possible: a string is kept if
+    /// it is short enough, and the kept strings around each run of rewritten ones leave room for the rewritten
+    /// strings with a free slot before, between and after each of them. The gaps before the first string and
+    /// after the last one count too, so prepending and appending remain possible. Planning takes time
+    /// proportional to the length of the list times the number of strings rewritten.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The strings, in strictly ascending order.
+    /// * `max_len` - The target maximum length, in characters.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<(usize, String)>>` that contains, in ascending order, the index of each string to
+    /// rewrite paired with its new value, or `None` if any string is invalid, the list is not strictly
+    /// ascending, or the list does not fit within `max_len` at all.
+    pub fn plan_rebalance<S>(&self, keys: &[S], max_len: usize) -> Option<Vec<(usize, String)>>
+    where
+        S: AsRef<str>,
+    {
+        let indices = self.sorted_indices(keys)?;
+        if max_len == 0 {
+            return None;
+        }
+
+        // Every string short enough to keep is exact at 'max_len'. The empty string and the highest
+        // character stand in for the ends of the list, and are always kept.
+        let mut values: Vec<Option<BigUint>> = Vec::with_capacity(keys.len() + 2);
+        values.push(Some(BigUint::from(0u32)));
+        values.extend(indices.iter().map(|key| {
+            if key.len() <= max_len {
+                Some(self.scaled(key, max_len))
+            } else {
+                None
+            }
+        }));
+        values.push(Some(self.scaled(&[self.alphabet.chars.len() - 1], max_len)));
+
+        // 'kept[i]' is the most strings that can be kept up to and including 'i', if 'i' is kept, along
+        // with the previous kept position. Keeping 'j' and 'i' with 'r' strings rewritten in between needs
+        // '2r + 1' free values strictly between them.
+        let mut kept: Vec<Option<(usize, usize)>> = vec![None; values.len()];
+        kept[0] = Some((0, 0));
+        for i in 1..values.len() {
+            let value = match &values[i] {
+                Some(value) => value,
+                None => continue,
+            };
+            for j in (0..i).rev() {
+                // At most 'j' strings can be kept up to 'j', so no earlier 'j' can do better.
+                if kept[i].is_some_and(|(best, _)| j < best) {
+                    break;
+                }
+                let (count, _) = match kept[j] {
+                    Some(kept) => kept,
+                    None => continue,
+                };
+                let previous = values[j].as_ref().unwrap();
+                let needed = BigUint::from(2 * (i - j - 1) + 2);
+                if value > previous
+                    && value - previous >= needed
+                    && kept[i].is_none_or(|(best, _)| count + 1 > best)
+                {
+                    kept[i] = Some((count + 1, j));
+                }
+            }
+        }
+
+        // Walk back from the end, refilling every run of rewritten strings.
+        let mut patches = Vec::new();
+        let mut i = values.len() - 1;
+        while i > 0 {
+            let (_, j) = kept[i]?;
+            if i - j > 1 {
+                let this = self.key_from_indices(if j == 0 { &[] } else { &indices[j - 1] });
+                let that = if i == values.len() - 1 {
+                    self.high.to_string()
+                } else {
+                    self.key_from_indices(&indices[i - 1])
+                };
+                let fresh = self.n_between(this, that, 2 * (i - j - 1) + 1)?;
+                for (offset, key) in fresh.into_iter().skip(1).step_by(2).enumerate() {
+                    patches.push((j + offset, key));
+                }
+            }
+            i = j;
+        }
+        patches.sort();
+        Some(patches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_compact() {
+        let between = Between::init();
+
+        let keys = vec!["A", "AVVV", "AVVW", "B~~~", "k"];
+        let replacements = between.compact(&keys).unwrap();
+        assert_eq!(
+            replacements,
+            vec![
+                (1, "AF".to_string()),
+                (2, "B".to_string()),
+                (3, "T".to_string())
+            ]
+        );
+
+        let mut compacted: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+        for (index, key) in replacements {
+            compacted[index] = key;
+        }
+        assert!(compacted.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_rebalance() {
+        let between = Between::init();
+
+        let existing: Vec<String> = between.after_iter("").take(200).collect();
+        assert_eq!(existing.last().unwrap().len(), 34);
+
+        let keys = between.rebalance(&existing).unwrap();
+        assert_eq!(keys.len(), 200);
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| key.len() <= 2));
+        assert!(between.rebalance::<&str>(&[]).unwrap().is_empty());
+
+        assert!(between.rebalance(&["B", "A"]).is_none());
+        assert!(between.rebalance(&["A", "A"]).is_none());
+        assert!(between.rebalance(&["A", "$"]).is_none());
+    }
+
+    /// Applies a patch list to a list of strings.
+    fn apply(keys: &[&str], patches: &[(usize, String)]) -> Vec<String> {
+        let mut keys: Vec<String> = keys.iter().map(|key| key.to_string()).collect();
+        for (index, key) in patches {
+            keys[*index] = key.clone();
+        }
+        keys
+    }
+
+    /// Checks that a list is within 'max_len' with room for one more string in every gap.
+    fn has_headroom(between: &Between, keys: &[String], max_len: usize) -> bool {
+        let mut bounds = vec![String::new()];
+        bounds.extend(keys.iter().cloned());
+        bounds.push(between.high().to_string());
+        keys.iter().all(|key| key.len() <= max_len)
+            && bounds.windows(2).all(|pair| {
+                between
+                    .capacity(pair[0].as_str(), pair[1].as_str(), max_len)
+                    .is_some_and(|capacity| capacity >= 1u32.into())
+            })
+    }
+
+    #[test]
+    fn test_plan_rebalance() {
+        let between = Between::init();
+
+        let keys = vec!["A", "AV", "AVVVV", "AVVVW", "B", "k"];
+        let patches = between.plan_rebalance(&keys, 3).unwrap();
+        assert_eq!(
+            patches.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert!(has_headroom(&between, &apply(&keys, &patches), 3));
+
+        // Nothing to do when there is already room everywhere.
+        assert!(between.plan_rebalance(&["A", "B"], 2).unwrap().is_empty());
+        // "A0" is too long, and moving it leaves "A" as it is.
+        let patches = between.plan_rebalance(&["A", "A0"], 1).unwrap();
+        assert_eq!(patches.len(), 1);
+        assert!(has_headroom(&between, &apply(&["A", "A0"], &patches), 1));
+
+        // 'n' strings need '2n + 1' values.
+        let keys: Vec<String> = Between::new(vec!['0', '1']).keys(2).collect();
+        assert!(Between::new(vec!['0', '1'])
+            .plan_rebalance(&keys, 2)
+            .is_none());
+        assert!(between.plan_rebalance(&["B", "A"], 2).is_none());
+    }
+
+    #[test]
+    fn test_plan_rebalance_is_minimal() {
+        let between = Between::new(vec!['a', 'b', 'c', 'd']);
+        let all: Vec<String> = between.keys(3).collect();
+
+        // Compare against every way of choosing which strings to keep, for a few lists.
+        for start in (0..all.len() - 5).step_by(7) {
+            let keys: Vec<&str> = all[start..start + 5].iter().map(String::as_str).collect();
+            let patches = between.plan_rebalance(&keys, 2).unwrap();
+            assert!(has_headroom(&between, &apply(&keys, &patches), 2));
+
+            // Keeping a subset works if the kept strings are short enough and every gap between kept
+            // strings (or the ends) fits the strings rewritten in it, each with a free slot on both sides.
+            let fewest = (0u32..32)
+                .filter(|kept| {
+                    let mut bounds = vec![(0, String::new())];
+                    bounds.extend(
+                        (0..5)
+                            .filter(|i| kept & (1 << i) != 0)
+                            .map(|i| (i + 1, keys[i].to_string())),
+                    );
+                    bounds.push((6, between.high().to_string()));
+                    bounds.iter().all(|(_, key)| key.len() <= 2)
+                        && bounds.windows(2).all(|pair| {
+                            let rewritten = pair[1].0 - pair[0].0 - 1;
+                            between
+                                .capacity(pair[0].1.as_str(), pair[1].1.as_str(), 2)
+                                .is_some_and(|capacity| {
+                                    capacity >= (2 * rewritten as u32 + 1).into()
+                                })
+                        })
+                })
+                .map(|kept| 5 - kept.count_ones() as usize)
+                .min()
+                .unwrap();
+            assert_eq!(patches.len(), fewest);
+        }
+    }
+
+    #[test]
+    fn test_compact_edge_cases() {
+        let between = Between::init();
+
+        assert!(between.compact::<&str>(&[]).unwrap().is_empty());
+        assert!(between.compact(&["A", "B", "C"]).unwrap().is_empty());
+        assert_eq!(
+            between.compact(&["~V~"]).unwrap(),
+            Vec::<(usize, String)>::new()
+        );
+        assert_eq!(
+            between.compact(&["AAA"]).unwrap(),
+            vec![(0, "V".to_string())]
+        );
+
+        assert!(between.compact(&["B", "A"]).is_none());
+        assert!(between.compact(&["A", "$"]).is_none());
+    }
+}
@@ -0,0 +1,83 @@
+use core::cmp::Ordering;
+
+use redb::{TypeName, Value};
+
+use crate::Key;
+
+/// Stores a `Key` as its UTF-8 bytes. Keys are not re-validated against an alphabet when read, since the
+/// database is expected to be written by this crate.
+impl Value for Key {
+    type SelfType<'a> = Key;
+    type AsBytes<'a> = &'a [u8];
+
+    fn fixed_width() -> Option<usize> {
+        None
+    }
+
+    fn from_bytes<'a>(data: &'a [u8]) -> Key
+    where
+        Self: 'a,
+    {
+        Key(core::str::from_utf8(data).unwrap().to_string())
+    }
+
+    fn as_bytes<'a, 'b: 'a>(value: &'a Key) -> &'a [u8]
+    where
+        Self: 'b,
+    {
+        value.as_str().as_bytes()
+    }
+
+    fn type_name() -> TypeName {
+        TypeName::new("between::Key")
+    }
+}
+
+/// Orders tables by `Key` the way keys compare: UTF-8 byte order is the same as character order.
+impl redb::Key for Key {
+    fn compare(data1: &[u8], data2: &[u8]) -> Ordering {
+        data1.cmp(data2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use redb::backends::InMemoryBackend;
+    use redb::{Database, ReadableTable, TableDefinition};
+
+    use crate::{Between, Key};
+
+    const ITEMS: TableDefinition<Key, u64> = TableDefinition::new("items");
+
+    #[test]
+    fn test_table_iterates_in_key_order() {
+        let db = Database::builder()
+            .create_with_backend(InMemoryBackend::new())
+            .unwrap();
+        let between = Between::init();
+        let keys: Vec<Key> = between
+            .after_iter("")
+            .take(100)
+            .map(|key| Key::new(key, &between).unwrap())
+            .collect();
+
+        let write = db.begin_write().unwrap();
+        {
+            let mut table = write.open_table(ITEMS).unwrap();
+            for (index, key) in keys.iter().enumerate().rev() {
+                table.insert(key, index as u64).unwrap();
+            }
+        }
+        write.commit().unwrap();
+
+        let read = db.begin_read().unwrap();
+        let table = read.open_table(ITEMS).unwrap();
+        let stored: Vec<Key> = table
+            .iter()
+            .unwrap()
+            .map(|entry| entry.unwrap().0.value())
+            .collect();
+        assert_eq!(stored, keys);
+        assert_eq!(table.get(&keys[42]).unwrap().unwrap().value(), 42);
+    }
+}
@@ -0,0 +1,201 @@
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+/// A handle that ends every generated key with a fixed-width encoding of a replica's site id, so replicas
+/// inserting at the same position at the same time never generate the same key. Created with
+/// [`Between::with_replica`].
+///
+/// As with Logoot identifiers, concurrent inserts between the same two keys get the same base and differ
+/// only in their site ids, so they interleave deterministically, ordered by site id. The site id is written
+/// with every character but the lowest, which keeps keys free of trailing lowest characters.
+///
+/// # Example
+///
+/// ```
+/// use between::Between;
+///
+/// let one = Between::init().with_replica(1).unwrap();
+/// let two = Between::init().with_replica(2).unwrap();
+///
+/// let a = one.between("A", "B").unwrap();
+/// let b = two.between("A", "B").unwrap();
+/// assert!("A" < a.as_str() && a < b && b.as_str() < "B");
+/// assert_eq!(two.site_of(&b), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Replica {
+    between: Between,
+    site: u32,
+    suffix: Vec<usize>,
+}
+
+impl Between {
+    /// Creates a handle that ends every generated key with the given site id.
+    ///
+    /// # Arguments
+    ///
+    /// * `site` - The id of the replica, unique among the replicas sharing a list.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Replica>` that contains the handle, or `None` if the alphabet has only two characters,
+    /// which leaves a single character to write site ids with.
+    pub fn with_replica(self, site: u32) -> Option<Replica> {
+        if self.alphabet.chars.len() < 3 {
+            return None;
+        }
+        let suffix = encode_site(site, self.alphabet.chars.len() - 1);
+        Some(Replica {
+            between: self,
+            site,
+            suffix,
+        })
+    }
+}
+
+impl Replica {
+    /// Returns the site id the handle writes into keys.
+    pub fn site(&self) -> u32 {
+        self.site
+    }
+
+    /// Returns the `Between` instance used to validate bounds.
+    pub fn inner(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the site id written at the end of a key, if the key was generated by a replica handle with the
+    /// same alphabet.
+    pub fn site_of(&self, key: &str) -> Option<u32> {
+        let indices = self.between.indices(key)?;
+        let start = indices.len().checked_sub(self.suffix.len())?;
+        decode_site(&indices[start..], self.between.alphabet.chars.len() - 1)
+    }
+
+    /// Finds a string between two given strings, ending with this replica's site id.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`] or the string would exceed the maximum length.
+    pub fn between<S, T>(&self, this: S, that: T) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.between.bounds(&this.as_key(), &that.as_key())?;
+
+        // Every string starting with a base strictly inside the gap at some length sorts inside the gap, so
+        // the site id cannot push the key past either bound.
+        let (len, low, high) = self.between.gap_holding(&this, &that, &BigUint::one());
+        let base = (low + high) / 2u32;
+        let mut indices = self.between.unscaled(&base, len);
+        indices.extend(&self.suffix);
+        if self
+            .between
+            .max_len()
+            .is_some_and(|max_len| indices.len() > max_len)
+        {
+            return None;
+        }
+        Some(
+            indices
+                .iter()
+                .map(|index| self.between.alphabet.chars[*index])
+                .collect(),
+        )
+    }
+
+    /// Finds a string after a given string, ending with this replica's site id.
+    pub fn after<S>(&self, this: S) -> Option<String>
+    where
+        S: AsKey,
+    {
+        self.between(this, self.between.high())
+    }
+
+    /// Finds a string before a given string, ending with this replica's site id.
+    pub fn before<T>(&self, that: T) -> Option<String>
+    where
+        T: AsKey,
+    {
+        self.between("", that)
+    }
+}
+
+/// Writes `site` as fixed-width digits in base `base`, offset by one to skip the lowest character.
+fn encode_site(site: u32, base: usize) -> Vec<usize> {
+    let mut width = 1;
+    while (base as u64).pow(width as u32) <= u32::MAX as u64 {
+        width += 1;
+    }
+    let mut digits = vec![0; width];
+    let mut value = site as u64;
+    for digit in digits.iter_mut().rev() {
+        *digit = (value % base as u64) as usize + 1;
+        value /= base as u64;
+    }
+    digits
+}
+
+fn decode_site(digits: &[usize], base: usize) -> Option<u32> {
+    digits.iter().try_fold(0u32, |value, digit| {
+        let digit = digit.checked_sub(1)?;
+        value.checked_mul(base as u32)?.checked_add(digit as u32)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_replica() {
+        let replicas: Vec<_> = [7, 3, u32::MAX, 0]
+            .iter()
+            .map(|site| Between::init().with_replica(*site).unwrap())
+            .collect();
+
+        // Concurrent inserts between the same keys are distinct and ordered by site id.
+        let mut keys: Vec<(u32, String)> = replicas
+            .iter()
+            .map(|replica| (replica.site(), replica.between("A", "B").unwrap()))
+            .collect();
+        keys.sort_by(|a, b| a.1.cmp(&b.1));
+        assert_eq!(
+            keys.iter().map(|(site, _)| *site).collect::<Vec<_>>(),
+            vec![0, 3, 7, u32::MAX]
+        );
+        for (site, key) in &keys {
+            assert!("A" < key.as_str() && key.as_str() < "B");
+            assert!(!key.ends_with('!'));
+            assert_eq!(replicas[0].site_of(key), Some(*site));
+        }
+
+        // Inserting between keys of different replicas stays in order.
+        let tween = replicas[0].between(&keys[1].1, &keys[2].1).unwrap();
+        assert!(keys[1].1 < tween && tween < keys[2].1);
+        let first = replicas[1].before(&keys[0].1).unwrap();
+        let last = replicas[1].after(&keys[3].1).unwrap();
+        assert!(first < keys[0].1 && keys[3].1 < last);
+    }
+
+    #[test]
+    fn test_replica_invalid() {
+        let replica = Between::init().with_replica(1).unwrap();
+        assert!(replica.between("B", "A").is_none());
+        assert!(replica.between("A", "$").is_none());
+        assert!(Between::new(vec!['0', '1']).with_replica(1).is_none());
+
+        let replica = Between::init().with_max_len(6).with_replica(1).unwrap();
+        assert!(replica.between("A", "B").is_none());
+    }
+}
@@ -0,0 +1,169 @@
+use core::error::Error;
+use core::fmt;
+
+use rkyv::option::ArchivedOption;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::string::ArchivedString;
+use rkyv::tuple::ArchivedTuple4;
+use rkyv::vec::ArchivedVec;
+use rkyv::{Archive, Archived, Deserialize, Place, Resolver, Serialize};
+
+use crate::{ArchivedKey, Between};
+
+impl ArchivedKey {
+    /// Returns the archived key as a string slice, without copying or re-validating it.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl AsRef<str> for ArchivedKey {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// The configuration a `Between` is archived as: its alphabet, the name of its strategy, its maximum length and
+/// its forbidden substrings.
+type Config = (Vec<char>, String, Option<usize>, Vec<String>);
+
+/// The archived form of [`Config`], spelled out so that it can be deserialized into a `Between`.
+type ArchivedConfig = ArchivedTuple4<
+    ArchivedVec<Archived<char>>,
+    ArchivedString,
+    ArchivedOption<Archived<usize>>,
+    ArchivedVec<ArchivedString>,
+>;
+
+impl Between {
+    /// Returns the configuration this instance is archived as, with an empty strategy name for custom
+    /// strategies, which serializing rejects.
+    fn config(&self) -> Config {
+        (
+            self.alphabet.chars.clone(),
+            self.strategy.name().unwrap_or_default().to_string(),
+            self.max_len,
+            self.forbidden.to_vec(),
+        )
+    }
+}
+
+/// Archives a `Between` as its configuration, like the serde implementation.
+impl Archive for Between {
+    type Archived = ArchivedConfig;
+    type Resolver = Resolver<Config>;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        self.config().resolve(resolver, out);
+    }
+}
+
+/// Fails for custom strategies, which have no [`Strategy::name`](crate::Strategy::name).
+impl<S> Serialize<S> for Between
+where
+    S: Fallible + ?Sized,
+    S::Error: Source,
+    Config: Serialize<S>,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        if self.strategy.name().is_none() {
+            return Err(S::Error::new(InvalidConfig(
+                "Custom strategies have no name and cannot be serialized.",
+            )));
+        }
+        self.config().serialize(serializer)
+    }
+}
+
+impl<D> Deserialize<Between, D> for ArchivedConfig
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+    ArchivedConfig: Deserialize<Config, D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<Between, D::Error> {
+        let (chars, strategy, max_len, forbidden) =
+            Deserialize::<Config, D>::deserialize(self, deserializer)?;
+        Between::from_config(chars, &strategy, max_len, forbidden)
+            .map_err(|message| D::Error::new(InvalidConfig(message)))
+    }
+}
+
+/// The error returned when a `Between` cannot be archived, or its archived configuration is invalid.
+#[derive(Debug)]
+struct InvalidConfig(&'static str);
+
+impl fmt::Display for InvalidConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl Error for InvalidConfig {}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::rancor::Error;
+
+    use crate::{ArchivedKey, Between, Key, PreferLeft, Strategy};
+
+    #[test]
+    fn test_key_round_trip() {
+        let key: Key = "AV".parse().unwrap();
+
+        let bytes = rkyv::to_bytes::<Error>(&key).unwrap();
+        let archived = rkyv::access::<ArchivedKey, Error>(&bytes).unwrap();
+        assert_eq!(archived.as_str(), "AV");
+        assert_eq!(rkyv::deserialize::<Key, Error>(archived).unwrap(), key);
+    }
+
+    #[test]
+    fn test_between_round_trip() {
+        let between = Between::new(vec!['c', 'a', 'b']);
+
+        let bytes = rkyv::to_bytes::<Error>(&between).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Between>, Error>(&bytes).unwrap();
+        let decoded: Between = rkyv::deserialize::<Between, Error>(archived).unwrap();
+        assert_eq!(decoded.chars(), &vec!['a', 'b', 'c']);
+        assert_eq!(decoded.between("a", "c").unwrap(), "b");
+        assert_eq!(decoded.strategy().name(), Some("Bisect"));
+        assert_eq!(decoded.max_len(), None);
+        assert!(decoded.forbidden().is_empty());
+
+        let between = Between::base62()
+            .with_strategy(PreferLeft)
+            .with_max_len(12)
+            .with_forbidden(["xx"]);
+        let bytes = rkyv::to_bytes::<Error>(&between).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Between>, Error>(&bytes).unwrap();
+        let decoded: Between = rkyv::deserialize::<Between, Error>(archived).unwrap();
+        assert_eq!(decoded.chars(), between.chars());
+        assert_eq!(decoded.strategy().name(), Some("PreferLeft"));
+        assert_eq!(decoded.max_len(), Some(12));
+        assert_eq!(decoded.forbidden(), ["xx"]);
+        assert_eq!(decoded.between("a", "b"), between.between("a", "b"));
+    }
+
+    #[test]
+    fn test_between_rejects_invalid_config() {
+        let config = (
+            vec!['a', 'b'],
+            "Nope".to_string(),
+            None::<usize>,
+            Vec::<String>::new(),
+        );
+        let bytes = rkyv::to_bytes::<Error>(&config).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<Between>, Error>(&bytes).unwrap();
+        assert!(rkyv::deserialize::<Between, Error>(archived).is_err());
+
+        #[derive(Debug)]
+        struct Custom;
+        impl Strategy for Custom {
+            fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+                between.shortest_between(this, that)
+            }
+        }
+        let between = Between::init().with_strategy(Custom);
+        assert!(rkyv::to_bytes::<Error>(&between).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{Between, Key};
+
+impl Between {
+    /// Returns a JSON Schema describing the keys accepted by this instance.
+    ///
+    /// The schema is a string schema whose `pattern` is [`Between::pattern`].
+    pub fn key_schema(&self) -> Schema {
+        json_schema!({
+            "type": "string",
+            "minLength": 1,
+            "pattern": self.pattern(),
+        })
+    }
+}
+
+/// Describes a `Key` using the default alphabet.
+///
+/// Use [`Between::key_schema`] to describe keys of a custom alphabet.
+impl JsonSchema for Key {
+    fn schema_name() -> Cow<'static, str> {
+        "Key".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        Between::init().key_schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use schemars::schema_for;
+
+    use crate::{Between, Key};
+
+    #[test]
+    fn test_key_schema() {
+        let schema = schema_for!(Key);
+        assert_eq!(schema.get("type").unwrap(), "string");
+        assert_eq!(schema.get("pattern").unwrap(), "^[!0-9A-Z_a-z~]+$");
+    }
+
+    #[test]
+    fn test_custom_key_schema() {
+        let between = Between::new(vec!['a', 'b', '-', ']']);
+        let schema = between.key_schema();
+        assert_eq!(schema.get("pattern").unwrap(), "^[\\-\\]ab]+$");
+    }
+}
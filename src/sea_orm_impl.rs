@@ -0,0 +1,73 @@
+use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, ValueType, ValueTypeErr};
+use sea_orm::{ColIdx, QueryResult, TryGetError, TryGetable, Value};
+
+use crate::Key;
+
+impl From<Key> for Value {
+    fn from(key: Key) -> Self {
+        key.0.into()
+    }
+}
+
+/// Reads a `Key` from a text column as-is. Use [`Key::validate`] to check it against an alphabet.
+impl TryGetable for Key {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        String::try_get_by(res, index).map(Key)
+    }
+}
+
+/// Converts a `Value` into a `Key`, so keys can be used as entity fields and in active models.
+impl ValueType for Key {
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        <String as ValueType>::try_from(value).map(Key)
+    }
+
+    fn type_name() -> String {
+        "Key".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+}
+
+impl Nullable for Key {
+    fn null() -> Value {
+        <String as Nullable>::null()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::sea_query::{ColumnType, ValueType};
+    use sea_orm::Value;
+
+    use crate::{Between, Key};
+
+    #[test]
+    fn test_value_round_trip() {
+        let key: Key = "42".parse().unwrap();
+        let value: Value = key.clone().into();
+        assert_eq!(value, Value::from("42"));
+        assert_eq!(<Key as ValueType>::try_from(value).unwrap(), key);
+        assert_eq!(
+            <Option<Key> as ValueType>::try_from(Value::from(None::<Key>)).unwrap(),
+            None
+        );
+        assert_eq!(<Key as ValueType>::column_type(), ColumnType::Text);
+
+        // Keys are decoded as-is, whatever their alphabet.
+        let key = <Key as ValueType>::try_from(Value::from("4$")).unwrap();
+        assert_eq!(key.as_str(), "4$");
+        assert!(key.validate(&Between::base10()).is_err());
+    }
+
+    #[test]
+    fn test_value_type_rejects_other_types() {
+        assert!(<Key as ValueType>::try_from(Value::from(1)).is_err());
+    }
+}
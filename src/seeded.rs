@@ -0,0 +1,84 @@
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+/// The least number of strings a gap must hold before one of them is picked by jitter.
+pub(crate) const JITTER_SLOTS: u32 = 4096;
+
+impl Between {
+    /// Finds a string between two given strings, offset within the gap by a caller-provided seed.
+    ///
+    /// This is a deterministic alternative to `between_jittered` that needs no random number generator:
+    /// replicas that pass different seeds (for example, a site id combined with a counter) generate
+    /// different keys with a probability of at least 4095 in 4096, while the same seed and bounds always
+    /// generate the same key. Like `between_jittered`, the key is picked among the strings of the shortest
+    /// length at which the gap holds at least 4096 of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `seed` - The seed that determines the key's position in the gap.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`].
+    pub fn between_seeded<S, T>(&self, this: S, that: T, seed: u64) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+
+        // The candidates are the integers strictly between 'low' and 'high'.
+        let (len, low, high) = self.gap_holding(&this, &that, &BigUint::from(JITTER_SLOTS));
+        let candidates = high - &low - 1u32;
+        let value = low + 1u32 + BigUint::from(mix(seed)) % candidates;
+        Some(self.key_from_indices(&self.unscaled(&value, len)))
+    }
+}
+
+/// Scrambles `seed` so that nearby seeds (such as consecutive counters) land far apart.
+///
+/// This is the SplitMix64 output function.
+pub(crate) fn mix(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_between_seeded_is_deterministic() {
+        let between = Between::init();
+
+        let key = between.between_seeded("A", "B", 42).unwrap();
+        assert!("A" < key.as_str() && key.as_str() < "B");
+        assert!(!key.ends_with(between.low()));
+        assert_eq!(between.between_seeded("A", "B", 42).unwrap(), key);
+
+        assert!(between.between_seeded("B", "A", 42).is_none());
+        assert!(between.between_seeded("A", "$", 42).is_none());
+    }
+
+    #[test]
+    fn test_between_seeded_spreads_seeds() {
+        let between = Between::init();
+
+        let mut keys: Vec<String> = (0..1000)
+            .map(|seed| between.between_seeded("A", "B", seed).unwrap())
+            .collect();
+        assert!(keys
+            .iter()
+            .all(|key| "A" < key.as_str() && key.as_str() < "B" && key.len() <= 3));
+        keys.sort();
+        keys.dedup();
+        assert!(keys.len() > 850);
+    }
+}
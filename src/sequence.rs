@@ -0,0 +1,210 @@
+use core::cmp::Ordering;
+
+use crate::prelude::*;
+
+/// Generates keys that are sequences of integers compared element by element, like the position identifiers
+/// of sequence CRDTs, with no string encoding at all.
+///
+/// Each element lies in `0..=max`. Keys are found by bisecting, like the default [`Bisect`](crate::Bisect)
+/// strategy, and trailing zeros are ignored the way [`Between::between`](crate::Between::between) ignores
+/// trailing low characters.
+///
+/// # Example
+///
+/// ```
+/// use between::SequenceBetween;
+///
+/// let between = SequenceBetween::new(99);
+/// assert_eq!(between.between(&[10], &[20]).unwrap(), vec![15]);
+/// assert_eq!(between.between(&[10], &[11]).unwrap(), vec![10, 50]);
+///
+/// let bytes = between.encode(&[10, 50]);
+/// assert_eq!(bytes, vec![10, 50]);
+/// assert_eq!(between.decode(&bytes).unwrap(), vec![10, 50]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceBetween {
+    max: u32,
+}
+
+impl SequenceBetween {
+    /// Creates a new `SequenceBetween` instance whose elements lie in `0..=max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is 0, since elements need at least two values.
+    pub fn new(max: u32) -> Self {
+        assert!(max >= 1, "Expect max to allow at least two values.");
+        SequenceBetween { max }
+    }
+
+    /// Initializes a `SequenceBetween` instance whose elements take every `u32` value.
+    pub fn full() -> Self {
+        SequenceBetween::new(u32::MAX)
+    }
+
+    /// Returns the largest value of an element.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Checks if a key is non-empty and every element is at most the maximum.
+    pub fn valid(&self, key: &[u32]) -> bool {
+        !key.is_empty() && key.iter().all(|element| *element <= self.max)
+    }
+
+    /// Finds a key that sorts between two given keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `&[]` for the start of the keyspace.
+    /// * `that` - The second key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<u32>>` that contains the between key if possible, or `None` if an element exceeds the
+    /// maximum or `this` does not sort before `that`.
+    pub fn between(&self, this: &[u32], that: &[u32]) -> Option<Vec<u32>> {
+        let this = trim(this);
+        let that = trim(that);
+        if this.cmp(that) != Ordering::Less
+            || that.is_empty()
+            || this.iter().chain(that).any(|element| *element > self.max)
+        {
+            return None;
+        }
+
+        // The same bisection as the one behind 'Bisect', in 64-bit arithmetic so that elements never
+        // overflow.
+        let guard = this.len() + that.len();
+        let guard_max_len = this.len().max(that.len());
+        let mut between = Vec::new();
+        for index in 0..=guard {
+            let low = u64::from(this.get(index).copied().unwrap_or(0));
+            let high = u64::from(that.get(index).copied().unwrap_or(self.max));
+            let element = if low + 1 < high || index >= guard_max_len {
+                (low + high).div_ceil(2)
+            } else {
+                low
+            };
+            between.push(element as u32);
+            if this < &between[..] && &between[..] < that && element != 0 {
+                return Some(between);
+            }
+        }
+        None
+    }
+
+    /// Finds a key that sorts after a given key, or `&[]` for the start of the keyspace.
+    pub fn after(&self, key: &[u32]) -> Option<Vec<u32>> {
+        self.between(key, &[self.max])
+    }
+
+    /// Finds a key that sorts before a given key.
+    pub fn before(&self, key: &[u32]) -> Option<Vec<u32>> {
+        self.between(&[], key)
+    }
+
+    /// Encodes a key compactly, writing each element as an unsigned LEB128 varint, so that elements below 128
+    /// take a single byte.
+    ///
+    /// The bytes do not sort like the keys; decode them with [`SequenceBetween::decode`] before comparing.
+    pub fn encode(&self, key: &[u32]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(key.len());
+        for element in key {
+            let mut value = *element;
+            while value >= 0x80 {
+                bytes.push((value as u8 & 0x7f) | 0x80);
+                value >>= 7;
+            }
+            bytes.push(value as u8);
+        }
+        bytes
+    }
+
+    /// Decodes a key written by [`SequenceBetween::encode`].
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<u32>>` that contains the key, or `None` if the bytes are truncated, an element overflows
+    /// a `u32` or exceeds the maximum.
+    pub fn decode(&self, bytes: &[u8]) -> Option<Vec<u32>> {
+        let mut key = Vec::new();
+        let mut value: u64 = 0;
+        let mut shift = 0;
+        for byte in bytes {
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                if value > u64::from(self.max) {
+                    return None;
+                }
+                key.push(value as u32);
+                value = 0;
+                shift = 0;
+            } else {
+                shift += 7;
+                if shift > 28 {
+                    return None;
+                }
+            }
+        }
+        if shift != 0 {
+            return None;
+        }
+        Some(key)
+    }
+}
+
+/// Drops trailing zeros, which do not change where a key sorts.
+fn trim(key: &[u32]) -> &[u32] {
+    let end = key
+        .iter()
+        .rposition(|element| *element != 0)
+        .map_or(0, |position| position + 1);
+    &key[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SequenceBetween;
+
+    #[test]
+    fn test_sequence_between() {
+        let between = SequenceBetween::full();
+        assert_eq!(between.after(&[]).unwrap(), vec![1 << 31]);
+        assert_eq!(
+            between.between(&[u32::MAX - 1], &[u32::MAX]).unwrap(),
+            vec![u32::MAX - 1, 1 << 31]
+        );
+
+        let mut keys = vec![between.after(&[]).unwrap()];
+        for _ in 0..100 {
+            let key = between.after(keys.last().unwrap()).unwrap();
+            keys.push(key);
+        }
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| key.len() <= 4));
+
+        let between = SequenceBetween::new(9);
+        assert_eq!(between.between(&[1, 0, 0], &[2]).unwrap(), vec![1, 5]);
+        assert_eq!(between.before(&[0, 1]).unwrap(), vec![0, 0, 5]);
+        assert!(between.between(&[2], &[1]).is_none());
+        assert!(between.between(&[1], &[10]).is_none());
+        assert!(!between.valid(&[]));
+    }
+
+    #[test]
+    fn test_sequence_encoding() {
+        let between = SequenceBetween::full();
+        let key = vec![0, 127, 128, 300, u32::MAX];
+        let bytes = between.encode(&key);
+        assert_eq!(bytes.len(), 1 + 1 + 2 + 2 + 5);
+        assert_eq!(between.decode(&bytes).unwrap(), key);
+
+        assert!(between.decode(&[0x80]).is_none());
+        assert!(between
+            .decode(&[0xff, 0xff, 0xff, 0xff, 0xff, 0x01])
+            .is_none());
+        assert!(SequenceBetween::new(99).decode(&[100]).is_none());
+    }
+}
@@ -0,0 +1,234 @@
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::{strategy, Between, Key};
+
+impl Serialize for Key {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Deserializes a `Key`, rejecting strings with characters outside the default alphabet.
+///
+/// Use [`KeySeed`] to validate against a custom alphabet instead.
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DefaultKeyVisitor)
+    }
+}
+
+/// Visits a `Key` of the default alphabet.
+struct DefaultKeyVisitor;
+
+impl<'de> Visitor<'de> for DefaultKeyVisitor {
+    type Value = Key;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-empty string of characters from the default alphabet")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Key::parse_default(value).map_err(E::custom)
+    }
+}
+
+/// A [`DeserializeSeed`] that deserializes a `Key` validated against the alphabet of a given `Between`.
+#[derive(Debug, Clone, Copy)]
+pub struct KeySeed<'a> {
+    between: &'a Between,
+}
+
+impl<'a> KeySeed<'a> {
+    /// Creates a new `KeySeed` validating keys against the alphabet of `between`.
+    pub fn new(between: &'a Between) -> Self {
+        KeySeed { between }
+    }
+}
+
+impl<'de, 'a> DeserializeSeed<'de> for KeySeed<'a> {
+    type Value = Key;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de, 'a> Visitor<'de> for KeySeed<'a> {
+    type Value = Key;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a non-empty string of characters from the alphabet")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Key::parse(value, self.between).map_err(E::custom)
+    }
+}
+
+/// The configuration of a `Between`: its alphabet and options.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Between", deny_unknown_fields)]
+struct Config {
+    chars: String,
+    #[serde(default = "default_strategy")]
+    strategy: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_len: Option<usize>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    forbidden: Vec<String>,
+}
+
+fn default_strategy() -> String {
+    "Bisect".to_string()
+}
+
+/// Serializes a `Between` as its configuration: the alphabet as a string, the name of its strategy, its
+/// maximum length and its forbidden substrings.
+///
+/// Fails for custom strategies, which have no [`Strategy::name`](crate::Strategy::name).
+impl Serialize for Between {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let strategy = self.strategy.name().ok_or_else(|| {
+            ser::Error::custom(format!(
+                "strategy {:?} has no name and cannot be serialized",
+                self.strategy
+            ))
+        })?;
+        Config {
+            chars: self.alphabet.chars.iter().collect(),
+            strategy: strategy.to_string(),
+            max_len: self.max_len,
+            forbidden: self.forbidden.to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a `Between` from its configuration, rebuilding an instance identical to the serialized one.
+///
+/// Only `chars` is required. Characters may come in any order, and the strategy defaults to `"Bisect"`.
+impl<'de> Deserialize<'de> for Between {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let config = Config::deserialize(deserializer)?;
+        let mut chars: Vec<char> = config.chars.chars().collect();
+        chars.sort_unstable();
+        chars.dedup();
+        if chars.len() < 2 {
+            return Err(de::Error::custom(
+                "expect chars to have at least two distinct characters",
+            ));
+        }
+        let strategy = strategy::named(&config.strategy)
+            .ok_or_else(|| de::Error::custom(format!("unknown strategy {:?}", config.strategy)))?;
+        let mut between = Between::new(chars).with_forbidden(config.forbidden);
+        between.strategy = strategy;
+        between.max_len = config.max_len;
+        Ok(between)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeSeed;
+
+    use crate::{Between, Key, KeySeed, PreferLeft, Strategy};
+
+    #[test]
+    fn test_key_round_trip() {
+        let between = Between::init();
+        let key = Key::new("AV", &between).unwrap();
+
+        let json = serde_json::to_string(&key).unwrap();
+        assert_eq!(json, "\"AV\"");
+        assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+    }
+
+    #[test]
+    fn test_key_rejects_invalid_characters() {
+        let error = serde_json::from_str::<Key>("\"a$b\"").unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("invalid character '$' at index 1"));
+
+        assert!(serde_json::from_str::<Key>("\"\"").is_err());
+        assert!(serde_json::from_str::<Key>("42").is_err());
+    }
+
+    #[test]
+    fn test_key_seed_uses_custom_alphabet() {
+        let between = Between::new(vec!['a', 'b', 'c']);
+
+        let mut deserializer = serde_json::Deserializer::from_str("\"abc\"");
+        let key = KeySeed::new(&between)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(key.as_str(), "abc");
+
+        let mut deserializer = serde_json::Deserializer::from_str("\"abd\"");
+        assert!(KeySeed::new(&between)
+            .deserialize(&mut deserializer)
+            .is_err());
+    }
+
+    #[test]
+    fn test_between_round_trip() {
+        let between = Between::base62()
+            .with_strategy(PreferLeft)
+            .with_max_len(12)
+            .with_forbidden(["xx"]);
+
+        let json = serde_json::to_string(&between).unwrap();
+        let decoded: Between = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.chars(), between.chars());
+        assert_eq!(decoded.strategy().name(), Some("PreferLeft"));
+        assert_eq!(decoded.max_len(), Some(12));
+        assert_eq!(decoded.forbidden(), ["xx"]);
+        assert_eq!(decoded.between("a", "b"), between.between("a", "b"));
+
+        let decoded: Between = serde_json::from_str(r#"{"chars":"cab"}"#).unwrap();
+        assert_eq!(decoded.chars(), &vec!['a', 'b', 'c']);
+        assert_eq!(decoded.strategy().name(), Some("Bisect"));
+        assert_eq!(decoded.max_len(), None);
+    }
+
+    #[test]
+    fn test_between_rejects_invalid_config() {
+        assert!(serde_json::from_str::<Between>(r#"{"chars":"aa"}"#).is_err());
+        assert!(serde_json::from_str::<Between>(r#"{"chars":"ab","strategy":"Nope"}"#).is_err());
+        assert!(serde_json::from_str::<Between>(r#"{"chars":"ab","extra":1}"#).is_err());
+
+        #[derive(Debug)]
+        struct Custom;
+        impl Strategy for Custom {
+            fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+                between.shortest_between(this, that)
+            }
+        }
+        assert!(serde_json::to_string(&Between::init().with_strategy(Custom)).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Finds the shortest string strictly between two given strings.
+    ///
+    /// [`Between::between`] works character by character and can return a string longer than necessary.
+    /// This instead finds the shortest length at which any string fits in the gap, and is guaranteed that no
+    /// shorter string exists. When several strings of that length fit, the one closest to the middle of the
+    /// gap is returned, preferring the lower one when two are equally close.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`].
+    pub fn shortest_between<S, T>(&self, this: S, that: T) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        let (len, low, high) = self.gap_holding(&this, &that, &BigUint::from(1u32));
+        // Every candidate has exactly 'len' characters, as one with trailing low characters would be shorter.
+        let value = (low + high) / 2u32;
+        Some(self.key_from_indices(&self.unscaled(&value, len)))
+    }
+
+    /// Computes the length of the shortest string strictly between two given strings, without generating it.
+    ///
+    /// This is the length of [`Between::shortest_between`], and lets callers notice that a gap is getting
+    /// narrow (and keys inserted into it long) before writing anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<usize>` that contains the length, counted in characters, or `None` if the bounds are
+    /// invalid under the same rules as [`Between::between`].
+    pub fn min_between_len<S, T>(&self, this: S, that: T) -> Option<usize>
+    where
+        S: AsKey,
+        T: AsKey,
+    {
+        let (this, that) = self.bounds(&this.as_key(), &that.as_key())?;
+        let (len, _, _) = self.gap_holding(&this, &that, &BigUint::from(1u32));
+        Some(len)
+    }
+
+    /// Shortens a string as much as possible while keeping it between its neighbors.
+    ///
+    /// Long-lived lists accumulate keys that are longer than necessary. This returns the shortest string that
+    /// still sorts between `prev` and `next` (see [`Between::shortest_between`]), or `key` itself if no shorter
+    /// string fits, so keys are only rewritten when that actually helps.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The string to shorten.
+    /// * `prev` - The string before `key`, or an empty string if `key` is first.
+    /// * `next` - The string after `key`, or an empty string if `key` is last.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the shortened string, or `None` if `key` does not sort strictly between
+    /// its neighbors or any of the strings are invalid.
+    pub fn shorten<K, S, T>(&self, key: K, prev: S, next: T) -> Option<String>
+    where
+        K: AsKey,
+        S: AsKey,
+        T: AsKey,
+    {
+        let key = key.as_key();
+        let mut next = next.as_key();
+        if next.is_empty() {
+            next.to_mut().push(self.high);
+        }
+        let (prev, next) = self.bounds(&prev.as_key(), &next)?;
+        let indices = self.indices(key.trim_end_matches(self.low))?;
+        if indices <= prev || indices >= next || !self.valid(&key) {
+            return None;
+        }
+
+        let (len, low, high) = self.gap_holding(&prev, &next, &BigUint::from(1u32));
+        if key.chars().count() <= len {
+            return Some(key.into_owned());
+        }
+        Some(self.key_from_indices(&self.unscaled(&((low + high) / 2u32), len)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    /// Lists every string of at most `len` characters over `chars` that does not end in the first one.
+    fn all_keys(chars: &[char], len: usize) -> Vec<String> {
+        let mut keys = vec![String::new()];
+        let mut last = vec![String::new()];
+        for _ in 0..len {
+            last = last
+                .iter()
+                .flat_map(|prefix| chars.iter().map(move |c| format!("{}{}", prefix, c)))
+                .collect();
+            keys.extend(last.iter().filter(|key| !key.ends_with(chars[0])).cloned());
+        }
+        keys
+    }
+
+    #[test]
+    fn test_shortest_between() {
+        let between = Between::init();
+
+        assert_eq!(between.shortest_between("A", "C").unwrap(), "B");
+        assert_eq!(between.shortest_between("A", "D").unwrap(), "B");
+        assert_eq!(between.shortest_between("A", "B").unwrap(), "AV");
+        assert_eq!(between.shortest_between("Az", "B").unwrap(), "A~");
+        // A prefix of 'that' sorts before it.
+        assert_eq!(between.shortest_between("Az", "B0").unwrap(), "B");
+        assert_eq!(between.shortest_between("", "!0").unwrap(), "!!V");
+
+        assert!(between.shortest_between("B", "A").is_none());
+        assert!(between.shortest_between("A", "A!").is_none());
+    }
+
+    #[test]
+    fn test_min_between_len() {
+        let between = Between::init();
+
+        assert_eq!(between.min_between_len("A", "C"), Some(1));
+        assert_eq!(between.min_between_len("A", "B"), Some(2));
+        assert_eq!(between.min_between_len("A~~~", "B"), Some(5));
+        assert_eq!(between.min_between_len("", "!!!0"), Some(5));
+        assert_eq!(between.min_between_len("B", "A"), None);
+
+        for (this, that) in [("A", "B"), ("Az", "B0"), ("!", "!!!!0"), ("", "~")] {
+            let key = between.shortest_between(this, that).unwrap();
+            assert_eq!(between.min_between_len(this, that), Some(key.len()));
+        }
+    }
+
+    #[test]
+    fn test_shorten() {
+        let between = Between::init();
+
+        assert_eq!(between.shorten("AVVVV", "A", "C").unwrap(), "B");
+        assert_eq!(between.shorten("A~~~V", "A~~", "").unwrap(), "_");
+        assert_eq!(between.shorten("!!!V", "", "A").unwrap(), "4");
+        // Already as short as possible, so unchanged even though "AV" is nearer the middle.
+        assert_eq!(between.shorten("A0", "A", "B").unwrap(), "A0");
+
+        assert!(between.shorten("D", "A", "C").is_none());
+        assert!(between.shorten("A", "A", "C").is_none());
+        assert!(between.shorten("B$", "A", "C").is_none());
+        assert!(between.shorten("B", "C", "A").is_none());
+    }
+
+    #[test]
+    fn test_shortest_between_is_shortest() {
+        let chars = vec!['a', 'b', 'c'];
+        let between = Between::new(chars.clone());
+        let keys = all_keys(&chars, 3);
+        let candidates = all_keys(&chars, 5);
+
+        for this in &keys {
+            for that in keys.iter().filter(|that| !that.is_empty() && this < *that) {
+                let key = between
+                    .shortest_between(this.as_str(), that.as_str())
+                    .unwrap();
+                assert!(this < &key && &key < that);
+                assert!(!key.ends_with('a'));
+                // No string strictly between the bounds is shorter.
+                assert!(!candidates
+                    .iter()
+                    .any(|other| this < other && other < that && other.len() < key.len()));
+                // Never longer than what 'between' finds.
+                let heuristic = between.between(this.as_str(), that.as_str()).unwrap();
+                assert!(key.len() <= heuristic.len());
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::prelude::*;
+use crate::seeded::mix;
+use crate::Between;
+
+/// Where a simulated workload inserts each new item.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Workload {
+    /// Every item is appended to the end of the list.
+    Append,
+    /// Every item is inserted at the front of the list.
+    Prepend,
+    /// Every item is inserted at the same relative position of the list, from 0 (the front) to 1 (the end).
+    Hotspot(f64),
+    /// Every item is inserted at a pseudo-random position, derived from the given seed.
+    Random(u64),
+}
+
+/// The outcome of a simulated workload, produced by [`Between::simulate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulationReport {
+    /// The number of items inserted.
+    pub inserted: usize,
+    /// The length of each inserted key, in insertion order, counted in characters.
+    pub lengths: Vec<usize>,
+    /// The length of the longest key.
+    pub max_len: usize,
+    /// The mean length of all keys.
+    pub mean_len: f64,
+    /// Whether the workload stopped early because no key could be generated, for example because of
+    /// [`Between::with_max_len`].
+    pub exhausted: bool,
+}
+
+impl Between {
+    /// Runs a workload of `operations` inserts into an initially empty list, and reports how long the keys get.
+    ///
+    /// Key length growth depends on where items are inserted, on the alphabet and on the
+    /// [`Strategy`](crate::Strategy). Keys are generated with [`Between::between`], so this instance's strategy
+    /// and maximum length apply, and running a workload before committing to a schema shows how long keys get.
+    ///
+    /// # Arguments
+    ///
+    /// * `workload` - Where each item is inserted.
+    /// * `operations` - The number of items to insert.
+    ///
+    /// # Returns
+    ///
+    /// A `SimulationReport` describing the key lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use between::{Between, Workload};
+    ///
+    /// let report = Between::init().simulate(Workload::Append, 1000);
+    /// assert_eq!(report.inserted, 1000);
+    /// assert!(report.max_len > 100);
+    /// ```
+    pub fn simulate(&self, workload: Workload, operations: usize) -> SimulationReport {
+        let high = self.high.to_string();
+        let mut keys: Vec<String> = Vec::new();
+        let mut lengths = Vec::with_capacity(operations);
+        let mut exhausted = false;
+
+        for operation in 0..operations {
+            let index = match workload {
+                Workload::Append => keys.len(),
+                Workload::Prepend => 0,
+                Workload::Hotspot(position) => {
+                    ((keys.len() as f64 * position.clamp(0.0, 1.0)) as usize).min(keys.len())
+                }
+                Workload::Random(seed) => {
+                    (mix(seed.wrapping_add(operation as u64)) % (keys.len() as u64 + 1)) as usize
+                }
+            };
+            let prev = if index == 0 {
+                ""
+            } else {
+                keys[index - 1].as_str()
+            };
+            let next = keys.get(index).map_or(high.as_str(), String::as_str);
+            match self.between(prev, next) {
+                Some(key) => {
+                    lengths.push(key.chars().count());
+                    keys.insert(index, key);
+                }
+                None => {
+                    exhausted = true;
+                    break;
+                }
+            }
+        }
+
+        let total: usize = keys.iter().map(|key| key.chars().count()).sum();
+        SimulationReport {
+            inserted: keys.len(),
+            max_len: lengths.iter().copied().max().unwrap_or(0),
+            mean_len: if keys.is_empty() {
+                0.0
+            } else {
+                total as f64 / keys.len() as f64
+            },
+            lengths,
+            exhausted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, PreferLeft, Workload};
+
+    #[test]
+    fn test_simulate_workloads() {
+        let between = Between::init();
+
+        let append = between.simulate(Workload::Append, 500);
+        assert_eq!(append.inserted, 500);
+        assert_eq!(append.lengths.len(), 500);
+        assert_eq!(append.max_len, 84);
+        assert!(!append.exhausted);
+
+        let prepend = between.simulate(Workload::Prepend, 500);
+        assert_eq!(prepend.inserted, 500);
+
+        // Random inserts spread out, so keys stay far shorter than with a single hotspot.
+        let random = between.simulate(Workload::Random(7), 500);
+        let hotspot = between.simulate(Workload::Hotspot(0.5), 500);
+        assert!(random.max_len < 10);
+        assert!(random.max_len < hotspot.max_len);
+        assert_eq!(between.simulate(Workload::Random(7), 500), random);
+    }
+
+    #[test]
+    fn test_simulate_strategy_and_max_len() {
+        // Appending with 'PreferLeft' grows much slower than bisecting.
+        let between = Between::init().with_strategy(PreferLeft);
+        assert!(between.simulate(Workload::Append, 500).max_len <= 9);
+
+        let between = Between::init().with_max_len(2);
+        let report = between.simulate(Workload::Hotspot(0.5), 500);
+        assert!(report.exhausted);
+        assert!(report.inserted < 500);
+        assert!(report.max_len <= 2);
+
+        assert_eq!(Between::init().simulate(Workload::Append, 0).mean_len, 0.0);
+    }
+}
@@ -0,0 +1,168 @@
+use core::error::Error;
+use core::fmt;
+
+use crate::Between;
+
+/// An error returned when a key cannot be written into a caller-provided buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// The bounds were invalid: not in ascending order, or containing characters outside the alphabet.
+    InvalidBounds,
+    /// The key is longer than the buffer, in bytes.
+    BufferTooSmall,
+}
+
+impl fmt::Display for SliceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::InvalidBounds => f.write_str("invalid bounds"),
+            SliceError::BufferTooSmall => f.write_str("buffer too small for the key"),
+        }
+    }
+}
+
+impl Error for SliceError {}
+
+impl Between {
+    /// Finds a string between two given strings by bisecting them, writing it as UTF-8 into a caller-provided
+    /// byte buffer without allocating.
+    ///
+    /// This is the core of the default [`Bisect`](crate::Bisect) strategy, for targets where allocating is not
+    /// an option. The configured strategy, maximum length and forbidden substrings are not applied; bound the
+    /// key length with the size of the buffer instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `out` - The buffer to write the key into, starting at its first byte.
+    ///
+    /// # Returns
+    ///
+    /// The length of the key in bytes, or a [`SliceError`] if the bounds are invalid under the same rules as
+    /// [`Between::between`] or the key does not fit in `out`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use between::{Between, SliceError};
+    ///
+    /// let between = Between::init();
+    /// let mut buffer = [0; 8];
+    /// let len = between.between_to_slice("A", "B", &mut buffer).unwrap();
+    /// assert_eq!(&buffer[..len], "AV".as_bytes());
+    ///
+    /// let mut tiny = [0; 1];
+    /// assert_eq!(
+    ///     between.between_to_slice("A", "B", &mut tiny),
+    ///     Err(SliceError::BufferTooSmall)
+    /// );
+    /// ```
+    pub fn between_to_slice(
+        &self,
+        this: &str,
+        that: &str,
+        out: &mut [u8],
+    ) -> Result<usize, SliceError> {
+        let this = this.trim_end_matches(self.low);
+        let that = that.trim_end_matches(self.low);
+        if this >= that
+            || that.is_empty()
+            || !this
+                .chars()
+                .chain(that.chars())
+                .all(|c| self.position(c).is_some())
+        {
+            return Err(SliceError::InvalidBounds);
+        }
+
+        // The same walk as `bisect_positions`, reading positions off the bounds as it goes and comparing the
+        // key written so far as bytes, which sort like the characters they encode.
+        let base = self.alphabet.chars.len();
+        let (this_len, that_len) = (this.chars().count(), that.chars().count());
+        let guard_max_len = this_len.max(that_len);
+        let (mut this_chars, mut that_chars) = (this.chars(), that.chars());
+        let mut written = 0;
+        for index in 0..=this_len + that_len {
+            let this_position = this_chars.next().map_or(0, |c| self.position(c).unwrap());
+            let that_position = that_chars
+                .next()
+                .map_or(base - 1, |c| self.position(c).unwrap());
+            let position = if this_position + 1 < that_position || index >= guard_max_len {
+                self.midpoint(this_position, that_position)
+            } else {
+                this_position
+            };
+
+            let c = self.alphabet.chars[position];
+            let end = written + c.len_utf8();
+            let slot = out
+                .get_mut(written..end)
+                .ok_or(SliceError::BufferTooSmall)?;
+            c.encode_utf8(slot);
+            written = end;
+
+            let between = &out[..written];
+            if this.as_bytes() < between && between < that.as_bytes() && position != 0 {
+                return Ok(written);
+            }
+        }
+        Err(SliceError::InvalidBounds)
+    }
+
+    /// Finds a string after a given string, writing it into `out`. See [`Between::between_to_slice`].
+    pub fn after_to_slice(&self, this: &str, out: &mut [u8]) -> Result<usize, SliceError> {
+        let mut high = [0; 4];
+        self.between_to_slice(this, self.high.encode_utf8(&mut high), out)
+    }
+
+    /// Finds a string before a given string, writing it into `out`. See [`Between::between_to_slice`].
+    pub fn before_to_slice(&self, that: &str, out: &mut [u8]) -> Result<usize, SliceError> {
+        let mut low = [0; 4];
+        self.between_to_slice(self.low.encode_utf8(&mut low), that, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, SliceError};
+
+    #[test]
+    fn test_between_to_slice_matches_between() {
+        for between in [Between::init(), Between::greek(), Between::cjk()] {
+            let keys: Vec<String> = between.after_iter("").take(30).collect();
+            let mut buffer = [0; 256];
+            for this in &keys {
+                for that in keys.iter().filter(|that| this < *that) {
+                    let len = between.between_to_slice(this, that, &mut buffer).unwrap();
+                    let key = std::str::from_utf8(&buffer[..len]).unwrap();
+                    assert_eq!(Some(key), between.between(this, that).as_deref());
+                }
+            }
+            let len = between.before_to_slice(&keys[0], &mut buffer).unwrap();
+            assert_eq!(
+                std::str::from_utf8(&buffer[..len]).ok(),
+                between.before(&keys[0]).as_deref()
+            );
+        }
+    }
+
+    #[test]
+    fn test_between_to_slice_errors() {
+        let between = Between::init();
+        let mut buffer = [0; 2];
+        assert_eq!(
+            between.between_to_slice("B", "A", &mut buffer),
+            Err(SliceError::InvalidBounds)
+        );
+        assert_eq!(
+            between.between_to_slice("A", "é", &mut buffer),
+            Err(SliceError::InvalidBounds)
+        );
+        assert_eq!(
+            between.between_to_slice("AAA", "AAB", &mut buffer),
+            Err(SliceError::BufferTooSmall)
+        );
+        assert_eq!(between.after_to_slice("A", &mut buffer), Ok(1));
+    }
+}
@@ -0,0 +1,107 @@
+use sqlx::database::Database;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Decode, Encode, Type};
+
+use crate::Key;
+
+/// Stores a `Key` in whatever column type stores a `String`, `TEXT` on every database.
+impl<DB: Database> Type<DB> for Key
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for Key
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        <String as Encode<'q, DB>>::encode_by_ref(&self.0, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <String as Encode<'q, DB>>::size_hint(&self.0)
+    }
+}
+
+/// Decodes a `Key` as-is. Use [`Key::validate`] to check it against an alphabet.
+impl<'r, DB: Database> Decode<'r, DB> for Key
+where
+    String: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        <String as Decode<'r, DB>>::decode(value).map(Key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx::sqlite::{Sqlite, SqliteConnection};
+    use sqlx::{Connection, Type};
+
+    use crate::{Between, Key};
+
+    #[tokio::test]
+    async fn test_round_trip_in_order() {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE items (id INTEGER PRIMARY KEY, position TEXT NOT NULL)")
+            .execute(&mut connection)
+            .await
+            .unwrap();
+
+        let between = Between::base10();
+        let keys: Vec<Key> = between
+            .spread(5)
+            .into_iter()
+            .map(|key| Key::new(key, &between).unwrap())
+            .collect();
+        for (id, key) in keys.iter().rev().enumerate() {
+            sqlx::query("INSERT INTO items (id, position) VALUES (?, ?)")
+                .bind(id as i64)
+                .bind(key)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let read: Vec<Key> = sqlx::query_scalar("SELECT position FROM items ORDER BY position")
+            .fetch_all(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(read, keys);
+
+        let first: i64 = sqlx::query_scalar("SELECT id FROM items WHERE position = ?")
+            .bind(&keys[0])
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(first, 4);
+
+        // Keys are decoded as-is, whatever their alphabet.
+        let key: Key = sqlx::query_scalar("SELECT '4$'")
+            .fetch_one(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(key.as_str(), "4$");
+        assert!(key.validate(&between).is_err());
+    }
+
+    #[test]
+    fn test_type_matches_string() {
+        assert_eq!(
+            <Key as Type<Sqlite>>::type_info(),
+            <String as Type<Sqlite>>::type_info()
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use num_bigint::BigUint;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+impl Between {
+    /// Returns the value of `before_string` scaled to `len` digits, and the exclusive limit for stepping after it.
+    fn after_steps(&self, before_string: &str, len: usize) -> Option<(BigUint, BigUint)> {
+        if len == 0 {
+            return None;
+        }
+        let before_string = before_string.trim_end_matches(self.low);
+        let start = self.scaled(&self.indices(before_string)?, len);
+        let limit = self.scaled(&[self.alphabet.chars.len() - 1], len);
+        Some((start, limit))
+    }
+
+    /// Returns the value of `after_string` scaled to `len` digits, rounded up, for stepping before it.
+    fn before_steps(&self, after_string: &str, len: usize) -> Option<BigUint> {
+        let after_string = after_string.trim_end_matches(self.low);
+        if len == 0 || after_string.is_empty() {
+            return None;
+        }
+        Some(self.scaled_ceil(&self.indices(after_string)?, len))
+    }
+
+    /// Finds the string `n` steps after a given string, where a step is one unit of the `len`-th character.
+    ///
+    /// Stepping works like counting: the `len`-th character is incremented `n` times, carrying into earlier
+    /// characters as needed. Unlike calling [`Between::after`] `n` times, the result is computed directly and
+    /// never grows longer than `len` characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `before_string` - The string to step from.
+    /// * `n` - The number of steps, starting from 1.
+    /// * `len` - The granularity of a step, as the position of the character being counted.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the string `n` steps after `before_string`, or `None` if it would
+    /// not sort before the highest character, or if `before_string` is invalid, `n` is zero or `len` is zero.
+    pub fn nth_after<S>(&self, before_string: S, n: usize, len: usize) -> Option<String>
+    where
+        S: AsKey,
+    {
+        let (start, limit) = self.after_steps(&before_string.as_key(), len)?;
+        let value = start + n;
+        if n == 0 || value >= limit {
+            return None;
+        }
+        Some(self.key_from_indices(&self.unscaled(&value, len)))
+    }
+
+    /// Finds the `n` consecutive strings after a given string, at the granularity of the `len`-th character.
+    ///
+    /// The `i`-th string (counting from 1) is [`Between::nth_after`] `before_string` by `i` steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `before_string` - The string to step from.
+    /// * `n` - The number of strings to generate.
+    /// * `len` - The granularity of a step, as the position of the character being counted.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the strings in ascending order, or `None` if they do not all
+    /// sort before the highest character, or if `before_string` is invalid or `len` is zero.
+    pub fn n_after<S>(&self, before_string: S, n: usize, len: usize) -> Option<Vec<String>>
+    where
+        S: AsKey,
+    {
+        let (start, limit) = self.after_steps(&before_string.as_key(), len)?;
+        if &start + n >= limit {
+            return None;
+        }
+        let keys = (1..=n)
+            .map(|step| self.key_from_indices(&self.unscaled(&(&start + step), len)))
+            .collect();
+        Some(keys)
+    }
+
+    /// Finds the string `n` steps before a given string, where a step is one unit of the `len`-th character.
+    ///
+    /// This is the mirror image of [`Between::nth_after`].
+    ///
+    /// # Arguments
+    ///
+    /// * `after_string` - The string to step from.
+    /// * `n` - The number of steps, starting from 1.
+    /// * `len` - The granularity of a step, as the position of the character being counted.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the string `n` steps before `after_string`, or `None` if it would
+    /// not sort after the lowest character, or if `after_string` is invalid, `n` is zero or `len` is zero.
+    pub fn nth_before<S>(&self, after_string: S, n: usize, len: usize) -> Option<String>
+    where
+        S: AsKey,
+    {
+        let start = self.before_steps(&after_string.as_key(), len)?;
+        if n == 0 || start <= BigUint::from(n) {
+            return None;
+        }
+        Some(self.key_from_indices(&self.unscaled(&(start - n), len)))
+    }
+
+    /// Finds the `n` consecutive strings before a given string, at the granularity of the `len`-th character.
+    ///
+    /// The `i`-th string (counting from 1) is [`Between::nth_before`] `after_string` by `i` steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `after_string` - The string to step from.
+    /// * `n` - The number of strings to generate.
+    /// * `len` - The granularity of a step, as the position of the character being counted.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<String>>` that contains the strings in descending order, or `None` if they do not all
+    /// sort after the lowest character, or if `after_string` is invalid or `len` is zero.
+    pub fn n_before<S>(&self, after_string: S, n: usize, len: usize) -> Option<Vec<String>>
+    where
+        S: AsKey,
+    {
+        let start = self.before_steps(&after_string.as_key(), len)?;
+        if start <= BigUint::from(n) {
+            return None;
+        }
+        let keys = (1..=n)
+            .map(|step| self.key_from_indices(&self.unscaled(&(&start - step), len)))
+            .collect();
+        Some(keys)
+    }
+
+    /// Finds the closest string after a given string that is no longer than it.
+    ///
+    /// Where [`Between::after`] jumps halfway towards the highest character (`after("!!!!")` is `"V"`), this
+    /// returns the immediate successor at the same length (`succ_min("!!!!")` is `"!!!0"`), packing keys as
+    /// tightly as possible. It is [`Between::nth_after`] by one step at the granularity of the string's length.
+    ///
+    /// # Arguments
+    ///
+    /// * `before_string` - The string to find a successor for.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the successor string, or `None` if every string of at most the same
+    /// length that sorts after `before_string` also sorts after the highest character.
+    pub fn succ_min<S>(&self, before_string: S) -> Option<String>
+    where
+        S: AsKey,
+    {
+        let before_string = before_string.as_key();
+        let len = before_string.chars().count();
+        self.nth_after(&before_string, 1, len)
+    }
+
+    /// Finds the closest string before a given string that is no longer than it.
+    ///
+    /// This is the mirror image of [`Between::succ_min`].
+    ///
+    /// # Arguments
+    ///
+    /// * `after_string` - The string to find a predecessor for.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the predecessor string, or `None` if no string of at most the same
+    /// length sorts between the lowest character and `after_string`.
+    pub fn pred_min<S>(&self, after_string: S) -> Option<String>
+    where
+        S: AsKey,
+    {
+        let after_string = after_string.as_key();
+        let len = after_string.chars().count();
+        self.nth_before(&after_string, 1, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_nth_after() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.nth_after("1", 1, 1).unwrap(), "2");
+        assert_eq!(between.nth_after("1", 3, 2).unwrap(), "13");
+        assert_eq!(between.nth_after("15", 7, 2).unwrap(), "22");
+        assert_eq!(between.nth_after("", 5, 1).unwrap(), "5");
+        // Steps finer than the string itself start from the string.
+        assert_eq!(between.nth_after("1234", 1, 2).unwrap(), "13");
+
+        assert_eq!(between.n_after("18", 3, 2).unwrap(), vec!["19", "2", "21"]);
+        assert!(between.n_after("18", 0, 2).unwrap().is_empty());
+        assert!(between.nth_after("18", 0, 2).is_none());
+
+        // "9" is the highest character, so nothing may reach it.
+        assert_eq!(between.nth_after("8", 9, 2).unwrap(), "89");
+        assert!(between.nth_after("8", 10, 2).is_none());
+        assert!(between.nth_after("1", 1, 0).is_none());
+        assert!(between.nth_after("1a", 1, 2).is_none());
+    }
+
+    #[test]
+    fn test_nth_before() {
+        let between = Between::new("0123456789".chars().collect());
+
+        assert_eq!(between.nth_before("5", 1, 1).unwrap(), "4");
+        assert_eq!(between.nth_before("5", 3, 2).unwrap(), "47");
+        // Steps coarser than the string start from the string rounded up.
+        assert_eq!(between.nth_before("1234", 1, 2).unwrap(), "12");
+
+        assert_eq!(between.n_before("21", 3, 2).unwrap(), vec!["2", "19", "18"]);
+
+        // "0" is the lowest character, so nothing may reach it.
+        assert_eq!(between.nth_before("1", 9, 2).unwrap(), "01");
+        assert!(between.nth_before("1", 10, 2).is_none());
+        assert!(between.nth_before("", 1, 2).is_none());
+    }
+
+    #[test]
+    fn test_steps_are_consistent() {
+        let between = Between::init();
+
+        let keys = between.n_after("A", 100, 3).unwrap();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        for (i, key) in keys.iter().enumerate() {
+            assert_eq!(&between.nth_after("A", i + 1, 3).unwrap(), key);
+            assert!(key.len() <= 3);
+        }
+    }
+
+    #[test]
+    fn test_succ_min_and_pred_min() {
+        let between = Between::init();
+
+        assert_eq!(between.succ_min("!!!!").unwrap(), "!!!0");
+        assert_eq!(between.succ_min("A").unwrap(), "B");
+        assert_eq!(between.succ_min("Az").unwrap(), "A~");
+        assert_eq!(between.succ_min("A~").unwrap(), "B");
+        // Nothing of one character sorts after the highest character.
+        assert!(between.succ_min("~").is_none());
+        assert!(between.succ_min("~~").is_none());
+        // Strings with characters outside the alphabet have no successor.
+        assert!(between.succ_min("}").is_none());
+        assert!(between.succ_min("").is_none());
+
+        assert_eq!(between.pred_min("B").unwrap(), "A");
+        assert_eq!(between.pred_min("A!").unwrap(), "9~");
+        assert!(between.pred_min("!!!0").is_none());
+        assert!(between.pred_min("0").is_none());
+    }
+}
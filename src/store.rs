@@ -0,0 +1,192 @@
+use core::ops::Bound;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// Ends a table prefix segment. It sorts before the escaped `0x00` byte, so a segment sorts before every
+/// longer segment it is a prefix of, and two different segments never produce overlapping keys.
+const SEGMENT_END: [u8; 2] = [0x00, 0x01];
+
+/// Stands for a `0x00` byte inside a table prefix segment.
+const ESCAPED_ZERO: [u8; 2] = [0x00, 0xFF];
+
+/// An adapter between string keys and the byte keys of ordered key-value stores such as sled and RocksDB,
+/// which iterate in byte-wise (`memcmp`) order. Created with [`Between::store_keys`].
+///
+/// Keys are encoded with [`Between::encode_key`], which preserves their order, after an optional table
+/// prefix. The prefix is made of segments added with [`StoreKeys::table`], such as a table name followed by
+/// a list id. Segments are escaped and terminated, so the keys of two tables never interleave even when one
+/// table name is a prefix of another, and iterating [`StoreKeys::bounds`] visits exactly one list, in order.
+///
+/// # Example
+///
+/// ```
+/// use between::Between;
+///
+/// let list = Between::init().store_keys().table("items").table(42u64.to_be_bytes());
+/// let first = list.between(None, None).unwrap();
+/// let second = list.between(Some(&first), None).unwrap();
+/// assert!(first.starts_with(list.prefix()) && first < second);
+/// assert_eq!(list.decode(&first), Between::init().after(""));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StoreKeys {
+    between: Between,
+    prefix: Vec<u8>,
+}
+
+impl Between {
+    /// Creates an adapter that converts keys of this alphabet to and from byte keys for ordered key-value
+    /// stores. See [`StoreKeys`].
+    pub fn store_keys(self) -> StoreKeys {
+        StoreKeys {
+            between: self,
+            prefix: Vec::new(),
+        }
+    }
+}
+
+impl StoreKeys {
+    /// Appends a segment to the table prefix of the byte keys.
+    ///
+    /// Prefixes compare segment by segment, in byte-wise order, so fixed-width big-endian integers such as
+    /// `id.to_be_bytes()` make segments that sort numerically. The range of a table includes the tables
+    /// nested in it, so store keys either in a table or in its nested tables, not both.
+    ///
+    /// # Arguments
+    ///
+    /// * `segment` - The segment, any bytes.
+    pub fn table<T>(mut self, segment: T) -> Self
+    where
+        T: AsRef<[u8]>,
+    {
+        for byte in segment.as_ref() {
+            match byte {
+                0x00 => self.prefix.extend_from_slice(&ESCAPED_ZERO),
+                byte => self.prefix.push(*byte),
+            }
+        }
+        self.prefix.extend_from_slice(&SEGMENT_END);
+        self
+    }
+
+    /// Returns the table prefix every byte key starts with, empty if no segment was added.
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Returns the `Between` instance whose keys are converted.
+    pub fn inner(&self) -> &Between {
+        &self.between
+    }
+
+    /// Converts a string key into a byte key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<u8>>` that contains the prefix followed by the encoded key, or `None` if the key
+    /// contains characters outside the alphabet.
+    pub fn encode(&self, key: &str) -> Option<Vec<u8>> {
+        let mut bytes = self.prefix.clone();
+        bytes.extend(self.between.encode_key(key)?);
+        Some(bytes)
+    }
+
+    /// Converts a byte key produced by [`StoreKeys::encode`] back into a string key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the key, or `None` if `bytes` does not start with the prefix or is
+    /// not a valid encoding for the alphabet.
+    pub fn decode(&self, bytes: &[u8]) -> Option<String> {
+        self.between
+            .decode_key(bytes.strip_prefix(self.prefix.as_slice())?)
+    }
+
+    /// Finds a byte key that sorts between two byte keys of this table, such as the neighbors of an
+    /// insertion point found by iterating the store.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The lower neighbor, or `None` for the start of the table.
+    /// * `that` - The upper neighbor, or `None` for the end of the table.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<u8>>` that contains the new byte key, or `None` if a neighbor is not a byte key of
+    /// this table or [`Between::between`] finds no key between them.
+    pub fn between(&self, this: Option<&[u8]>, that: Option<&[u8]>) -> Option<Vec<u8>> {
+        let this = match this {
+            Some(this) => self.decode(this)?,
+            None => String::new(),
+        };
+        let that = match that {
+            Some(that) => self.decode(that)?,
+            None => self.between.high().to_string(),
+        };
+        self.encode(&self.between.between(this, that)?)
+    }
+
+    /// Returns the range of byte keys of this table, for range scans and iterator bounds.
+    ///
+    /// The range starts at the prefix and ends before the first byte string that sorts after every key with
+    /// the prefix. Without a prefix, it covers the whole store.
+    pub fn bounds(&self) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        match self.prefix.split_last() {
+            None => (Bound::Unbounded, Bound::Unbounded),
+            Some((last, init)) => {
+                // The prefix ends in `SEGMENT_END`, whose last byte is never 0xFF.
+                let mut end = init.to_vec();
+                end.push(last + 1);
+                (Bound::Included(self.prefix.clone()), Bound::Excluded(end))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ops::RangeBounds;
+
+    use crate::Between;
+
+    #[test]
+    fn test_byte_order_matches_key_order() {
+        let keys = Between::init().store_keys().table("items").table([0, 7]);
+        let strings: Vec<String> = Between::init().after_iter("").take(200).collect();
+        let bytes: Vec<Vec<u8>> = strings
+            .iter()
+            .map(|key| keys.encode(key).unwrap())
+            .collect();
+        assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+        for (key, bytes) in strings.iter().zip(&bytes) {
+            assert_eq!(keys.decode(bytes).as_ref(), Some(key));
+            assert!(keys.bounds().contains(bytes));
+        }
+
+        let new = keys.between(Some(&bytes[0]), Some(&bytes[1])).unwrap();
+        assert!(bytes[0] < new && new < bytes[1]);
+        assert!(keys.between(Some(&bytes[1]), Some(&bytes[0])).is_none());
+        assert!(keys.decode(b"other").is_none());
+    }
+
+    #[test]
+    fn test_tables_do_not_interleave() {
+        let between = Between::init();
+        let tables = [
+            between.clone().store_keys().table("a"),
+            between.clone().store_keys().table("a\0"),
+            between.clone().store_keys().table("ab"),
+            between.clone().store_keys().table("b"),
+        ];
+        let (low, high) = ("!", "~~~~~~~~");
+        for (index, table) in tables.iter().enumerate() {
+            let (first, last) = (table.encode(low).unwrap(), table.encode(high).unwrap());
+            for other in &tables[index + 1..] {
+                assert!(last < other.encode(low).unwrap());
+                assert!(!other.bounds().contains(&first) && !other.bounds().contains(&last));
+            }
+        }
+        assert!(between.store_keys().bounds().contains(&vec![0xFF]));
+    }
+}
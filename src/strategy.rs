@@ -0,0 +1,239 @@
+use alloc::sync::Arc;
+use core::fmt::Debug;
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::prelude::*;
+use crate::{AsKey, Between};
+
+/// Decides where in a gap a new string lands.
+///
+/// Every strategy returns a string strictly between `this` and `that`, or `None` if the bounds are invalid
+/// under the same rules as [`Between::between`]. Strategies differ only in which of the many possible strings
+/// they pick, which lets different workloads tune how quickly keys grow. A strategy is selected per instance
+/// with [`Between::with_strategy`], or per call with [`Between::between_with`].
+pub trait Strategy: Debug + Send + Sync {
+    /// Finds a string between `this` and `that` using the given alphabet.
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String>;
+
+    /// Finds a string between `this` and `that` like [`Strategy::between`], appending it to `out`.
+    ///
+    /// Returns `false` and leaves `out` untouched if there is no such string. The default implementation
+    /// appends the result of [`Strategy::between`]; strategies that can build keys in place override it.
+    fn between_into(&self, between: &Between, this: &str, that: &str, out: &mut String) -> bool {
+        match self.between(between, this, that) {
+            Some(key) => {
+                out.push_str(&key);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the name a configuration refers to this strategy by, or `None` for custom strategies, which
+    /// cannot be stored in one. The built-in strategies are named after their types, e.g. `"Bisect"`.
+    fn name(&self) -> Option<&'static str> {
+        None
+    }
+}
+
+/// Bisects the gap character by character. This is the default strategy.
+///
+/// Suits lists where inserts land uniformly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Bisect;
+
+impl Strategy for Bisect {
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+        between.bisect(this, that)
+    }
+
+    fn between_into(&self, between: &Between, this: &str, that: &str, out: &mut String) -> bool {
+        between.bisect_into(this, that, out)
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("Bisect")
+    }
+}
+
+/// Picks the shortest possible string, nearest the middle of the gap. See [`Between::shortest_between`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreferShort;
+
+impl Strategy for PreferShort {
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+        between.shortest_between(this, that)
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("PreferShort")
+    }
+}
+
+/// Picks the lowest string of the shortest possible length, leaving the rest of the gap free above it.
+///
+/// Suits append-heavy lists: appending repeatedly walks through every string of a length before growing
+/// longer, instead of halving the remaining room each time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreferLeft;
+
+impl Strategy for PreferLeft {
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+        let (this, that) = between.bounds(this, that)?;
+        let (len, low, _) = between.gap_holding(&this, &that, &BigUint::one());
+        Some(between.key_from_indices(&between.unscaled(&(low + 1u32), len)))
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("PreferLeft")
+    }
+}
+
+/// Picks the highest string of the shortest possible length, leaving the rest of the gap free below it.
+///
+/// This is the mirror image of [`PreferLeft`], and suits lists where items are mostly inserted at the front.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PreferRight;
+
+impl Strategy for PreferRight {
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+        let (this, that) = between.bounds(this, that)?;
+        let (len, _, high) = between.gap_holding(&this, &that, &BigUint::one());
+        Some(between.key_from_indices(&between.unscaled(&(high - 1u32), len)))
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("PreferRight")
+    }
+}
+
+/// Picks a random string in the gap using the thread-local random number generator. See
+/// [`Between::between_jittered`].
+///
+/// Suits collaborative lists, where concurrent inserts at the same position should not collide.
+#[cfg(feature = "rand")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Randomized;
+
+#[cfg(feature = "rand")]
+impl Strategy for Randomized {
+    fn between(&self, between: &Between, this: &str, that: &str) -> Option<String> {
+        between.between_jittered(this, that, &mut rand::thread_rng())
+    }
+
+    fn name(&self) -> Option<&'static str> {
+        Some("Randomized")
+    }
+}
+
+/// Returns the built-in strategy with the given [`Strategy::name`].
+pub(crate) fn named(name: &str) -> Option<Arc<dyn Strategy>> {
+    let strategy: Arc<dyn Strategy> = match name {
+        "Bisect" => Arc::new(Bisect),
+        "PreferShort" => Arc::new(PreferShort),
+        "PreferLeft" => Arc::new(PreferLeft),
+        "PreferRight" => Arc::new(PreferRight),
+        "Mudder" => Arc::new(crate::Mudder),
+        #[cfg(feature = "rand")]
+        "Randomized" => Arc::new(Randomized),
+        _ => return None,
+    };
+    Some(strategy)
+}
+
+impl Between {
+    /// Finds a string between two given strings using the given strategy instead of this instance's one.
+    ///
+    /// Like [`Between::between`], the string stays within the maximum length and avoids forbidden substrings.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first string.
+    /// * `that` - The second string.
+    /// * `strategy` - The strategy deciding where in the gap the string lands.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if the bounds are invalid
+    /// under the same rules as [`Between::between`].
+    pub fn between_with<S, T, St>(&self, this: S, that: T, strategy: &St) -> Option<String>
+    where
+        S: AsKey,
+        T: AsKey,
+        St: Strategy + ?Sized,
+    {
+        let (this, that) = (this.as_key(), that.as_key());
+        self.between_using(strategy, &this, &that)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, Bisect, PreferLeft, PreferRight, PreferShort};
+
+    #[test]
+    fn test_strategies() {
+        let between = Between::init();
+
+        assert_eq!(between.between_with("A", "D", &Bisect).unwrap(), "C");
+        assert_eq!(between.between_with("A", "D", &PreferShort).unwrap(), "B");
+        assert_eq!(between.between_with("A", "D", &PreferLeft).unwrap(), "B");
+        assert_eq!(between.between_with("A", "D", &PreferRight).unwrap(), "C");
+        assert_eq!(between.between_with("A", "B", &PreferLeft).unwrap(), "A0");
+        assert_eq!(between.between_with("A", "B", &PreferRight).unwrap(), "A~");
+
+        for strategy in [
+            &PreferShort as &dyn crate::Strategy,
+            &PreferLeft,
+            &PreferRight,
+        ] {
+            assert!(between.between_with("B", "A", strategy).is_none());
+            assert!(between.between_with("A", "A!", strategy).is_none());
+            assert!(between.between_with("A", "$", strategy).is_none());
+        }
+    }
+
+    #[test]
+    fn test_with_strategy() {
+        let between = Between::init().with_strategy(PreferLeft);
+
+        // 'after' and 'before' follow the instance's strategy.
+        assert_eq!(between.after("A").unwrap(), "B");
+        assert_eq!(between.before("A").unwrap(), "0");
+        assert_eq!(between.between_with("A", "D", &Bisect).unwrap(), "C");
+
+        // Appending with 'PreferLeft' grows far slower than bisecting.
+        let keys: Vec<String> = between.after_iter("").take(500).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys.iter().all(|key| key.len() <= 9));
+
+        let between = Between::init().with_strategy(PreferRight);
+        let keys: Vec<String> = between.before_iter("~").take(500).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] > pair[1]));
+        assert!(keys.iter().all(|key| key.len() <= 9));
+    }
+
+    #[test]
+    fn test_between_with_limits() {
+        // The maximum length and forbidden substrings apply whatever the strategy.
+        let between = Between::init().with_max_len(2);
+        assert_eq!(between.between_with("Az", "B", &Bisect).unwrap(), "A~");
+        assert!(between.between_with("A~", "B", &PreferLeft).is_none());
+
+        let between = Between::init().with_forbidden(["C"]);
+        let key = between.between_with("A", "D", &Bisect).unwrap();
+        assert!("A" < key.as_str() && key.as_str() < "D" && !key.contains('C'));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_randomized() {
+        let between = Between::init().with_strategy(crate::Randomized);
+
+        let key = between.between("A", "B").unwrap();
+        assert!("A" < key.as_str() && key.as_str() < "B");
+        assert!(between.between("B", "A").is_none());
+    }
+}
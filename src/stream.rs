@@ -0,0 +1,112 @@
+use core::str;
+
+use smallvec::SmallVec;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// A key collected from an iterator of characters, kept inline unless it is longer than 64 bytes.
+type KeyBuffer = SmallVec<[u8; 64]>;
+
+impl Between {
+    /// Checks if a string, given as an iterator of characters, is valid, without collecting it into a
+    /// `String`.
+    ///
+    /// This suits text structures such as ropes or gap buffers, which can iterate over a key's characters
+    /// but not borrow it as a `&str`. Keys compare in the order of their characters, so such keys can also be
+    /// compared without collecting them, with [`Iterator::cmp`].
+    ///
+    /// # Arguments
+    ///
+    /// * `chars` - The characters of the string to validate.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the string is valid under the same rules as [`Between::valid`], `false` otherwise.
+    pub fn valid_iter<I>(&self, chars: I) -> bool
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut empty = true;
+        for c in chars {
+            if self.position(c).is_none() {
+                return false;
+            }
+            empty = false;
+        }
+        !empty
+    }
+
+    /// Finds a string that is lexicographically between two given strings, each given as an iterator of
+    /// characters.
+    ///
+    /// Returns the same string [`Between::between`] does. Bounds of up to 64 bytes are collected on the
+    /// stack, so callers holding keys in ropes or gap buffers need not build a `String` for each of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The characters of the first string.
+    /// * `that` - The characters of the second string.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if not.
+    pub fn between_chars<I, J>(&self, this: I, that: J) -> Option<String>
+    where
+        I: IntoIterator<Item = char>,
+        J: IntoIterator<Item = char>,
+    {
+        let this = collect(this);
+        let that = collect(that);
+        self.between(as_str(&this), as_str(&that))
+    }
+}
+
+fn collect<I>(chars: I) -> KeyBuffer
+where
+    I: IntoIterator<Item = char>,
+{
+    let mut buffer = KeyBuffer::new();
+    for c in chars {
+        buffer.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+    }
+    buffer
+}
+
+fn as_str(buffer: &KeyBuffer) -> &str {
+    // The buffer holds whole characters encoded as UTF-8.
+    str::from_utf8(buffer).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_valid_iter() {
+        let between = Between::init();
+        assert!(between.valid_iter("Hello".chars()));
+        assert!(!between.valid_iter("Hello world".chars()));
+        assert!(!between.valid_iter(std::iter::empty()));
+        assert_eq!(between.valid_iter("αβ".chars()), between.valid("αβ"));
+    }
+
+    #[test]
+    fn test_between_chars() {
+        for between in [Between::init(), Between::greek()] {
+            let keys: Vec<String> = between.after_iter("").take(20).collect();
+            let long = keys[3].repeat(30);
+            for (this, that) in keys
+                .iter()
+                .zip(keys.iter().skip(1))
+                .chain([(&long, &keys[4])])
+            {
+                assert_eq!(
+                    between.between_chars(this.chars(), that.chars()),
+                    between.between(this, that)
+                );
+            }
+            assert!(between.between_chars("".chars(), "".chars()).is_none());
+        }
+    }
+}
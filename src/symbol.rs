@@ -0,0 +1,60 @@
+use core::hash::Hash;
+
+/// A symbol keys can be made of, for [`Between<S>`](crate::Between).
+///
+/// Implemented for `char` and the unsigned integers. Any other ordered type can be used by implementing this
+/// trait with its default method:
+///
+/// ```
+/// use between::{Between, Symbol};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// impl Symbol for Suit {}
+///
+/// let between = Between::new(vec![Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]);
+/// assert_eq!(between.after_symbols(&[]).unwrap(), vec![Suit::Hearts]);
+/// ```
+pub trait Symbol: Ord + Copy + Hash {
+    /// Returns the symbol as a byte, if it fits in one.
+    ///
+    /// Alphabets whose symbols all fit, such as ASCII ones, look symbols up in a 256-entry table instead of
+    /// hashing them.
+    fn byte(self) -> Option<u8> {
+        None
+    }
+}
+
+impl Symbol for char {
+    fn byte(self) -> Option<u8> {
+        if (self as u32) < 256 {
+            Some(self as u8)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_symbol_for_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl Symbol for $ty {
+                fn byte(self) -> Option<u8> {
+                    if (self as u128) < 256 {
+                        Some(self as u8)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_symbol_for_unsigned!(u8, u16, u32, u64, u128, usize);
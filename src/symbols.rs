@@ -0,0 +1,80 @@
+use alloc::collections::BTreeMap;
+
+use crate::prelude::*;
+use crate::{bisect_positions, AlphabetError, Positions};
+
+// Helpers for alphabets whose symbols are strings rather than single characters.
+//
+// A key is a sequence of symbols, written by concatenating them. Symbols are sorted as strings, and since no
+// symbol is a prefix of another, keys compare as strings exactly like their sequences of symbol positions do.
+
+/// A sorted, prefix-free set of string symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Symbols {
+    symbols: Vec<String>,
+    lookup: BTreeMap<String, usize>,
+}
+
+impl Symbols {
+    /// Sorts and deduplicates symbols, checking there are at least two and that none is a prefix of another.
+    pub(crate) fn new(symbols: Vec<String>) -> Result<Self, AlphabetError> {
+        let mut symbols = symbols;
+        symbols.sort_unstable();
+        symbols.dedup();
+        if symbols.len() < 2 {
+            return Err(AlphabetError::TooFewSymbols {
+                count: symbols.len(),
+            });
+        }
+        // In sorted order, a symbol that is a prefix of others comes right before one of them.
+        if let Some(pair) = symbols
+            .windows(2)
+            .find(|pair| pair[1].starts_with(&pair[0]))
+        {
+            return Err(AlphabetError::AmbiguousPrefix {
+                prefix: pair[0].clone(),
+                symbol: pair[1].clone(),
+            });
+        }
+        let lookup = symbols
+            .iter()
+            .enumerate()
+            .map(|(index, symbol)| (symbol.clone(), index))
+            .collect();
+        Ok(Symbols { symbols, lookup })
+    }
+
+    /// Returns the symbols, in ascending order.
+    pub(crate) fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Returns the position of a symbol.
+    pub(crate) fn position(&self, symbol: &str) -> Option<usize> {
+        self.lookup.get(symbol).copied()
+    }
+
+    /// Builds a key from symbol positions.
+    pub(crate) fn key(&self, indices: &[usize]) -> String {
+        indices
+            .iter()
+            .map(|index| self.symbols[*index].as_str())
+            .collect()
+    }
+
+    /// Finds the positions of a key between two others, given as symbol positions.
+    ///
+    /// Trailing low symbols are ignored, and `None` is returned unless `this` sorts before `that`.
+    pub(crate) fn between(&self, this: &[usize], that: &[usize]) -> Option<Positions> {
+        bisect_positions(self.symbols.len(), trim(this), trim(that))
+    }
+}
+
+/// Drops trailing low symbols, which do not change where a key sorts.
+fn trim(indices: &[usize]) -> &[usize] {
+    let end = indices
+        .iter()
+        .rposition(|index| *index != 0)
+        .map_or(0, |position| position + 1);
+    &indices[..end]
+}
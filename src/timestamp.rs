@@ -0,0 +1,231 @@
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use num_traits::ToPrimitive;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// The number of bits of a timestamp, as in ULIDs, which lasts until the year 10889.
+const TIMESTAMP_BITS: u32 = 48;
+
+/// A handle that generates keys starting with a millisecond timestamp, so keys are roughly ordered by
+/// creation time while any two keys can still have keys inserted between them. Created with
+/// [`Between::with_timestamps`].
+///
+/// Like a ULID, a key starts with a fixed-width timestamp written in the alphabet, followed by a suffix
+/// generated with [`Between::between`].
+///
+/// # Example
+///
+/// ```
+/// use between::Between;
+///
+/// let keys = Between::init().with_timestamps();
+/// let first = keys.next_key(1_700_000_000_000, None).unwrap();
+/// let second = keys.next_key(1_700_000_000_000, Some(&first)).unwrap();
+/// let later = keys.next_key(1_700_000_000_001, Some(&second)).unwrap();
+/// assert!(first < second && second < later);
+/// assert_eq!(keys.timestamp_of(&second), Some(1_700_000_000_000));
+///
+/// let tween = keys.between(&first, &second).unwrap();
+/// assert!(first < tween && tween < second);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Timestamped {
+    between: Between,
+    width: usize,
+}
+
+impl Between {
+    /// Creates a handle that generates keys starting with a millisecond timestamp.
+    pub fn with_timestamps(self) -> Timestamped {
+        let width = self.fixed_width(TIMESTAMP_BITS);
+        Timestamped {
+            between: self,
+            width,
+        }
+    }
+}
+
+impl Timestamped {
+    /// Returns the `Between` instance used to generate suffixes.
+    pub fn inner(&self) -> &Between {
+        &self.between
+    }
+
+    /// Returns the number of characters the timestamp takes at the start of every key.
+    pub fn timestamp_len(&self) -> usize {
+        self.width
+    }
+
+    /// Generates a key for the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `millis` - The time, in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the key, or `None` if the time does not fit in 48 bits or the key,
+    /// timestamp included, would exceed the maximum length.
+    pub fn key_at(&self, millis: u64) -> Option<String> {
+        let prefix = self.prefix(millis)?;
+        let suffix = self.between.after("")?;
+        let key = prefix + &suffix;
+        if self
+            .between
+            .max_len()
+            .is_some_and(|max_len| key.chars().count() > max_len)
+        {
+            return None;
+        }
+        Some(key)
+    }
+
+    /// Generates a key for the given time that sorts after the last key generated, like a monotonic ULID
+    /// generator.
+    ///
+    /// If `last` has the same or a later timestamp, for example because several keys are generated in the
+    /// same millisecond or the clock went backwards, the new key keeps the timestamp of `last` and sorts right
+    /// after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `millis` - The time, in milliseconds since the Unix epoch.
+    /// * `last` - The last key generated, if any.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the key, or `None` if the time does not fit in 48 bits, `last` is not
+    /// a valid key, or the key would exceed the maximum length.
+    pub fn next_key(&self, millis: u64, last: Option<&str>) -> Option<String> {
+        let last = match last {
+            Some(last) => last,
+            None => return self.key_at(millis),
+        };
+        let last_millis = self.timestamp_of(last)?;
+        if last_millis < millis {
+            return self.key_at(millis);
+        }
+        // Stay below the first key of the next millisecond.
+        let next = self
+            .prefix(last_millis + 1)
+            .unwrap_or_else(|| self.between.high().to_string());
+        self.between.between(last, next)
+    }
+
+    /// Generates a key for the current time that sorts after the last key generated. See
+    /// [`Timestamped::next_key`].
+    #[cfg(feature = "std")]
+    pub fn now(&self, last: Option<&str>) -> Option<String> {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_millis();
+        self.next_key(u64::try_from(millis).ok()?, last)
+    }
+
+    /// Finds a key between two given keys. The new key keeps the timestamp of `this`, which lies between
+    /// theirs.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between string if possible, or `None` if either bound does not
+    /// start with a timestamp or the bounds are invalid under the same rules as [`Between::between`].
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        self.timestamp_of(this)?;
+        self.timestamp_of(that)?;
+        self.between.between_in_field(this, that, self.width)
+    }
+
+    /// Returns the timestamp at the start of a key, in milliseconds since the Unix epoch.
+    pub fn timestamp_of(&self, key: &str) -> Option<u64> {
+        let indices = self.between.indices(key)?;
+        if indices.len() < self.width {
+            return None;
+        }
+        self.between.scaled(&indices, self.width).to_u64()
+    }
+
+    /// Writes a timestamp as fixed-width digits.
+    fn prefix(&self, millis: u64) -> Option<String> {
+        if millis >> TIMESTAMP_BITS != 0 {
+            return None;
+        }
+        Some(self.between.fixed_digits(millis, self.width))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_timestamped() {
+        let keys = Between::init().with_timestamps();
+        assert_eq!(keys.timestamp_len(), 8);
+
+        let mut last = keys.key_at(1_000).unwrap();
+        assert_eq!(keys.timestamp_of(&last), Some(1_000));
+        // Many keys in the same millisecond keep its timestamp and stay in order.
+        for _ in 0..100 {
+            let next = keys.next_key(1_000, Some(&last)).unwrap();
+            assert!(last < next);
+            assert_eq!(keys.timestamp_of(&next), Some(1_000));
+            last = next;
+        }
+        // A clock going backwards keeps keys in order.
+        let next = keys.next_key(999, Some(&last)).unwrap();
+        assert!(last < next);
+
+        let later = keys.next_key(1_001, Some(&next)).unwrap();
+        assert_eq!(keys.timestamp_of(&later), Some(1_001));
+        assert!(next < later && later < keys.key_at(1_002).unwrap());
+        assert!(keys.now(Some(&later)).unwrap() > later);
+    }
+
+    #[test]
+    fn test_between_different_timestamps() {
+        let keys = Between::init().with_timestamps();
+        let first = keys.key_at(1_000).unwrap();
+        let last = keys.key_at(5_000_000_000).unwrap();
+
+        let mut tween = keys.between(&first, &last).unwrap();
+        assert!(first < tween && tween < last);
+        assert_eq!(keys.timestamp_of(&tween), Some(1_000));
+        for _ in 0..50 {
+            let next = keys.between(&tween, &last).unwrap();
+            assert!(tween < next && next < last);
+            assert_eq!(keys.timestamp_of(&next), Some(1_000));
+            tween = next;
+        }
+
+        assert!(keys.between(&last, &first).is_none());
+        assert!(keys.between("", &first).is_none());
+    }
+
+    #[test]
+    fn test_timestamped_invalid() {
+        let keys = Between::init().with_timestamps();
+
+        assert!(keys.key_at(1 << 48).is_none());
+        assert!(keys.key_at((1 << 48) - 1).is_some());
+        assert!(keys.next_key(1, Some("$")).is_none());
+        assert!(keys.timestamp_of("A").is_none());
+    }
+
+    #[test]
+    fn test_timestamped_max_len() {
+        // The limit covers the whole key, timestamp included.
+        let keys = Between::init().with_max_len(3).with_timestamps();
+        assert!(keys.key_at(1_000).is_none());
+
+        let keys = Between::init().with_max_len(9).with_timestamps();
+        let key = keys.key_at(1_000).unwrap();
+        assert_eq!(key.len(), 9);
+        assert!(keys.next_key(1_001, Some(&key)).unwrap().len() <= 9);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::prelude::*;
+use crate::symbols::Symbols;
+use crate::AlphabetError;
+
+/// Generates keys from an alphabet of multi-character tokens, such as digraphs or words, for keys people can
+/// read.
+///
+/// No token may be a prefix of another, so every key splits into tokens in exactly one way and keys sort as
+/// plain strings the way their tokens do.
+///
+/// Keys are found by bisecting, like the default [`Bisect`](crate::Bisect) strategy.
+///
+/// # Example
+///
+/// ```
+/// use between::TokenBetween;
+///
+/// let between = TokenBetween::new(vec!["ant", "bee", "cat", "dog", "elk"]).unwrap();
+/// assert_eq!(between.between("bee", "dog").unwrap(), "cat");
+/// assert_eq!(between.between("bee", "cat").unwrap(), "beecat");
+/// assert_eq!(between.split("beecat").unwrap(), vec!["bee", "cat"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenBetween {
+    symbols: Symbols,
+    max_token_len: usize,
+}
+
+impl TokenBetween {
+    /// Creates a new `TokenBetween` instance with a given set of tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The tokens to generate keys from, in any order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result<TokenBetween, AlphabetError>` that contains the instance, or an error if there are fewer than
+    /// two distinct tokens or a token (including the empty one) is a prefix of another.
+    pub fn new<S>(tokens: Vec<S>) -> Result<Self, AlphabetError>
+    where
+        S: Into<String>,
+    {
+        let symbols = Symbols::new(tokens.into_iter().map(Into::into).collect())?;
+        let max_token_len = symbols.symbols().iter().map(String::len).max().unwrap_or(0);
+        Ok(TokenBetween {
+            symbols,
+            max_token_len,
+        })
+    }
+
+    /// Returns the tokens of the alphabet, in ascending order.
+    pub fn tokens(&self) -> &[String] {
+        self.symbols.symbols()
+    }
+
+    /// Returns the lowest token.
+    pub fn low(&self) -> &str {
+        &self.tokens()[0]
+    }
+
+    /// Returns the highest token.
+    pub fn high(&self) -> &str {
+        &self.tokens()[self.tokens().len() - 1]
+    }
+
+    /// Splits a key into its tokens.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<Vec<&str>>` that contains the tokens of the key, or `None` if it is not made of tokens of
+    /// the alphabet.
+    pub fn split<'a>(&self, key: &'a str) -> Option<Vec<&'a str>> {
+        let mut tokens = Vec::new();
+        let mut rest = key;
+        while !rest.is_empty() {
+            // At most one token matches, since none is a prefix of another.
+            let len = (1..=self.max_token_len.min(rest.len()))
+                .filter(|len| rest.is_char_boundary(*len))
+                .find(|len| self.symbols.position(&rest[..*len]).is_some())?;
+            tokens.push(&rest[..len]);
+            rest = &rest[len..];
+        }
+        Some(tokens)
+    }
+
+    /// Checks if a key is non-empty and made only of tokens of the alphabet.
+    pub fn valid(&self, key: &str) -> bool {
+        !key.is_empty() && self.split(key).is_some()
+    }
+
+    /// Finds a key that sorts between two given keys.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `""` for the start of the keyspace.
+    /// * `that` - The second key.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between key if possible, or `None` if a key is invalid or `this`
+    /// does not sort before `that`.
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        let this = self.positions(this)?;
+        let that = self.positions(that)?;
+        let between = self.symbols.between(&this, &that)?;
+        Some(self.symbols.key(&between))
+    }
+
+    /// Finds a key that sorts after a given key, or `""` for the start of the keyspace.
+    pub fn after(&self, key: &str) -> Option<String> {
+        self.between(key, self.high())
+    }
+
+    /// Finds a key that sorts before a given key.
+    pub fn before(&self, key: &str) -> Option<String> {
+        self.between(self.low(), key)
+    }
+
+    /// Returns the token positions of a key.
+    fn positions(&self, key: &str) -> Option<Vec<usize>> {
+        self.split(key)?
+            .into_iter()
+            .map(|token| self.symbols.position(token))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlphabetError, TokenBetween};
+
+    #[test]
+    fn test_token_between() {
+        let between = TokenBetween::new(vec!["ba", "ab", "aa"]).unwrap();
+        assert_eq!(between.tokens(), ["aa", "ab", "ba"]);
+
+        let mut keys = vec![between.after("").unwrap()];
+        for _ in 0..30 {
+            let key = between.after(keys.last().unwrap()).unwrap();
+            keys.push(key);
+        }
+        for pair in keys.windows(2) {
+            let key = between.between(&pair[0], &pair[1]).unwrap();
+            assert!(pair[0] < key && key < pair[1]);
+            assert!(between.valid(&key));
+        }
+        assert_eq!(between.before("ab").unwrap(), "aaab");
+
+        assert!(between.split("aab").is_none());
+        assert!(!between.valid(""));
+        assert!(between.between("ba", "ab").is_none());
+    }
+
+    #[test]
+    fn test_token_alphabet_errors() {
+        assert_eq!(
+            TokenBetween::new(vec!["a", "ab", "b"]).unwrap_err(),
+            AlphabetError::AmbiguousPrefix {
+                prefix: "a".to_string(),
+                symbol: "ab".to_string()
+            }
+        );
+        assert!(matches!(
+            TokenBetween::new(vec!["", "a"]).unwrap_err(),
+            AlphabetError::AmbiguousPrefix { .. }
+        ));
+        assert_eq!(
+            TokenBetween::new(vec!["aa", "aa"]).unwrap_err(),
+            AlphabetError::TooFewSymbols { count: 1 }
+        );
+    }
+}
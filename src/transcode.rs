@@ -0,0 +1,125 @@
+use crate::prelude::*;
+use crate::Between;
+
+/// The most characters of the source alphabet converted together.
+///
+/// Longer chunks convert long keys more compactly, but pad short keys more.
+const MAX_CHUNK: usize = 3;
+
+impl Between {
+    /// Re-encodes a key from one alphabet to another, preserving order, so stored keys can be migrated to a
+    /// new alphabet without sorting the data again.
+    ///
+    /// The key is split into chunks of a few characters, and each chunk is written as a fixed number of
+    /// characters of the target alphabet, so that keys compare after the conversion exactly as they did
+    /// before. The chunk size is the one, up to 3 characters, that keeps converted keys shortest, and depends
+    /// only on the sizes of the two alphabets. For example, keys of the 94-character
+    /// [`Between::ascii_printable`] alphabet become 4 [`Between::base62`] characters for every 3 characters.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to convert.
+    /// * `from` - The alphabet of `key`.
+    /// * `to` - The alphabet to convert it to.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the converted key, or `None` if `key` is not valid in `from` or
+    /// consists only of its lowest character, which sorts as the empty string and has no converted form.
+    pub fn transcode(key: &str, from: &Between, to: &Between) -> Option<String> {
+        if !from.valid(key) {
+            return None;
+        }
+        let (chunk, width) = chunking(from.alphabet.chars.len(), to.alphabet.chars.len());
+        let source = from.indices(key.trim_end_matches(from.low))?;
+        if source.is_empty() {
+            return None;
+        }
+
+        let source_base = from.alphabet.chars.len() as u128;
+        let target_base = to.alphabet.chars.len() as u128;
+        let mut target = Vec::with_capacity(source.len().div_ceil(chunk) * width);
+        for digits in source.chunks(chunk) {
+            // Pad the last chunk with low characters, which do not change where the key sorts.
+            let mut value = (0..chunk).fold(0u128, |value, position| {
+                value * source_base + digits.get(position).copied().unwrap_or(0) as u128
+            });
+            let start = target.len();
+            target.resize(start + width, 0);
+            for position in (start..start + width).rev() {
+                target[position] = (value % target_base) as usize;
+                value /= target_base;
+            }
+        }
+        Some(to.key_from_indices(&target))
+    }
+}
+
+/// Returns how many source characters to convert at a time, and how many target characters they take.
+fn chunking(from: usize, to: usize) -> (usize, usize) {
+    let width_of = |chunk: usize| {
+        let limit = (from as u128).pow(chunk as u32);
+        let mut width = 1;
+        while (to as u128).pow(width as u32) < limit {
+            width += 1;
+        }
+        width
+    };
+    (1..=MAX_CHUNK)
+        .map(|chunk| (chunk, width_of(chunk)))
+        .min_by(|(a, a_width), (b, b_width)| (a_width * b).cmp(&(b_width * a)))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Between;
+
+    #[test]
+    fn test_transcode_preserves_order() {
+        let alphabets = [
+            Between::ascii_printable(),
+            Between::init(),
+            Between::base62(),
+            Between::base10(),
+            Between::new(vec!['a', 'b']),
+        ];
+        for from in &alphabets {
+            let mut keys: Vec<String> = from.after_iter("").take(30).collect();
+            let tweens: Vec<String> = keys
+                .windows(2)
+                .map(|pair| from.between(pair[0].as_str(), pair[1].as_str()).unwrap())
+                .collect();
+            keys.extend(tweens);
+            keys.sort();
+            for to in &alphabets {
+                let converted: Vec<String> = keys
+                    .iter()
+                    .map(|key| Between::transcode(key, from, to).unwrap())
+                    .collect();
+                assert!(converted.windows(2).all(|pair| pair[0] < pair[1]));
+                assert!(converted.iter().all(|key| to.valid(key.as_str())));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transcode_lengths() {
+        let from = Between::ascii_printable();
+        let to = Between::base62();
+        assert_eq!(Between::transcode("~~~", &from, &to).unwrap().len(), 4);
+        assert!(Between::transcode("~~~~", &from, &to).unwrap().len() <= 8);
+        // A larger alphabet never makes keys longer.
+        assert_eq!(Between::transcode("abc", &to, &from).unwrap().len(), 3);
+        assert_eq!(
+            Between::transcode("V", &Between::init(), &Between::init()).unwrap(),
+            "V"
+        );
+
+        assert!(Between::transcode("", &from, &to).is_none());
+        assert!(Between::transcode(" ", &from, &to).is_none());
+        // Keys of only the lowest character sort as the empty string.
+        assert!(Between::transcode("!", &Between::init(), &to).is_none());
+        assert!(Between::transcode("!!", &Between::init(), &to).is_none());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::Between;
+
+/// Where a key is carried, for [`Between::is_transport_safe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transport {
+    /// A URL path segment or query parameter, which must survive without percent-encoding.
+    Url,
+    /// A file name, which must be stored unchanged on Linux, macOS and Windows file systems.
+    Filename,
+}
+
+/// The names Windows reserves for devices, whatever their case or extension.
+const WINDOWS_DEVICE_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// The longest file name most file systems accept, in bytes.
+const MAX_FILENAME_LEN: usize = 255;
+
+impl Between {
+    /// Initializes a `Between` instance with the 65 characters URLs carry without percent-encoding,
+    /// `-0-9A-Z_a-z~`.
+    ///
+    /// These are the unreserved characters of RFC 3986 except `.`, which is left out so that no key can be
+    /// the dot segment `.` or `..`, which URL path normalization removes.
+    pub fn url_safe() -> Self {
+        Between::new(
+            ('0'..='9')
+                .chain('A'..='Z')
+                .chain('a'..='z')
+                .chain(['-', '_', '~'])
+                .collect(),
+        )
+    }
+
+    /// Initializes a `Between` instance with the 38 characters file systems store unchanged, `-0-9_a-z`.
+    ///
+    /// Uppercase letters are left out because file systems that ignore case would treat keys differing only
+    /// in case as the same name.
+    pub fn filename_safe() -> Self {
+        Between::new(('0'..='9').chain('a'..='z').chain(['-', '_']).collect())
+    }
+
+    /// Checks if a key, generated with any alphabet, survives a transport unchanged.
+    ///
+    /// For [`Transport::Url`], every character must be unreserved in RFC 3986, and the key must not be the dot
+    /// segment `.` or `..`. For [`Transport::Filename`], the key must be printable ASCII without the characters
+    /// Windows forbids (`<>:"/\|?*`), must not end in a dot, be `.` or `..`, or be a Windows device name such
+    /// as `CON` or `nul.txt`, and must be at most 255 bytes long. Keys differing only in case may still collide
+    /// on file systems that ignore case.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to check.
+    /// * `transport` - Where the key is carried.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the key is non-empty and survives the transport unchanged.
+    pub fn is_transport_safe(key: &str, transport: Transport) -> bool {
+        if key.is_empty() {
+            return false;
+        }
+        match transport {
+            Transport::Url => {
+                key.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~'))
+                    && key != "."
+                    && key != ".."
+            }
+            Transport::Filename => {
+                let stem = key.split('.').next().unwrap_or("");
+                key.len() <= MAX_FILENAME_LEN
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_graphic() && !"<>:\"/\\|?*".contains(c))
+                    && !key.ends_with('.')
+                    && !WINDOWS_DEVICE_NAMES
+                        .iter()
+                        .any(|name| stem.eq_ignore_ascii_case(name))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, Transport};
+
+    #[test]
+    fn test_safe_presets() {
+        for (between, transport) in [
+            (Between::url_safe(), Transport::Url),
+            (Between::filename_safe(), Transport::Filename),
+        ] {
+            let keys: Vec<String> = between.after_iter("").take(200).collect();
+            assert!(keys
+                .iter()
+                .all(|key| Between::is_transport_safe(key, transport)));
+        }
+        assert_eq!(Between::url_safe().chars().len(), 65);
+        assert_eq!(Between::url_safe().between("-", "0").unwrap(), "-V");
+        assert_eq!(Between::filename_safe().chars().len(), 38);
+    }
+
+    #[test]
+    fn test_is_transport_safe() {
+        assert!(Between::is_transport_safe("aZ9-._~", Transport::Url));
+        assert!(!Between::is_transport_safe("a!", Transport::Url));
+        assert!(!Between::is_transport_safe("a b", Transport::Url));
+        assert!(!Between::is_transport_safe("", Transport::Url));
+        assert!(!Between::is_transport_safe(".", Transport::Url));
+        assert!(!Between::is_transport_safe("..", Transport::Url));
+        assert!(Between::is_transport_safe("...", Transport::Url));
+
+        assert!(Between::is_transport_safe("V!~", Transport::Filename));
+        assert!(!Between::is_transport_safe("a:b", Transport::Filename));
+        assert!(!Between::is_transport_safe("a.", Transport::Filename));
+        assert!(!Between::is_transport_safe("..", Transport::Filename));
+        assert!(!Between::is_transport_safe("nul", Transport::Filename));
+        assert!(!Between::is_transport_safe("Com1.txt", Transport::Filename));
+        assert!(Between::is_transport_safe("com10", Transport::Filename));
+        assert!(!Between::is_transport_safe("é", Transport::Filename));
+        assert!(!Between::is_transport_safe(
+            &"a".repeat(256),
+            Transport::Filename
+        ));
+    }
+}
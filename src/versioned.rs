@@ -0,0 +1,264 @@
+use alloc::collections::BTreeMap;
+use core::error::Error;
+use core::fmt;
+
+use crate::prelude::*;
+use crate::Between;
+
+/// An error returned by [`Versioned`] when a version cannot be added or a key cannot be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionError {
+    /// The version is already registered.
+    DuplicateVersion {
+        /// The version symbol.
+        version: char,
+    },
+    /// The key is empty.
+    Empty,
+    /// The key starts with a version symbol that is not registered.
+    UnknownVersion {
+        /// The version symbol.
+        version: char,
+    },
+    /// The rest of the key is not valid in the alphabet of its version.
+    InvalidKey {
+        /// The version symbol.
+        version: char,
+    },
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VersionError::DuplicateVersion { version } => {
+                write!(f, "version {:?} is already registered", version)
+            }
+            VersionError::Empty => f.write_str("empty key"),
+            VersionError::UnknownVersion { version } => write!(f, "unknown version {:?}", version),
+            VersionError::InvalidKey { version } => {
+                write!(f, "key is not valid for version {:?}", version)
+            }
+        }
+    }
+}
+
+impl Error for VersionError {}
+
+/// Generates keys that start with a version symbol, so the key scheme (its alphabet, strategy or maximum
+/// length) can evolve without breaking keys already stored.
+///
+/// Each version has its own `Between` instance, and each key is read with the instance of its version. Keys
+/// sort by version first, so every key of one version sorts before every key of a version with a higher
+/// symbol. New keys use the current version, the one added last, whenever it fits between their
+/// neighbors; between two keys of an older version, they keep that version.
+///
+/// # Example
+///
+/// ```
+/// use between::{Between, Versioned};
+///
+/// let v1 = Versioned::new('1', Between::base10());
+/// let old = v1.after("").unwrap();
+/// assert_eq!(old, "15");
+///
+/// // Move to a larger alphabet. New keys use it, and old keys still parse.
+/// let v2 = v1.add_version('2', Between::base62()).unwrap();
+/// let new = v2.after(&old).unwrap();
+/// assert_eq!(new, "2V");
+/// assert_eq!(v2.parse(&old).unwrap(), ('1', "5"));
+/// assert_eq!(v2.between("15", "16").unwrap(), "155");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Versioned {
+    versions: BTreeMap<char, Between>,
+    current: char,
+}
+
+impl Versioned {
+    /// Creates a key scheme with a single version, which is the current one.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The symbol written at the start of every key of this version.
+    /// * `between` - The instance generating the rest of the keys.
+    pub fn new(version: char, between: Between) -> Self {
+        let mut versions = BTreeMap::new();
+        versions.insert(version, between);
+        Versioned {
+            versions,
+            current: version,
+        }
+    }
+
+    /// Adds a version, which becomes the current one.
+    ///
+    /// # Returns
+    ///
+    /// The key scheme with the new version, or a [`VersionError::DuplicateVersion`] if the symbol is already
+    /// registered.
+    pub fn add_version(mut self, version: char, between: Between) -> Result<Self, VersionError> {
+        if self.versions.contains_key(&version) {
+            return Err(VersionError::DuplicateVersion { version });
+        }
+        self.versions.insert(version, between);
+        self.current = version;
+        Ok(self)
+    }
+
+    /// Returns the version symbol new keys use when they can.
+    pub fn current(&self) -> char {
+        self.current
+    }
+
+    /// Returns the `Between` instance of a version, if it is registered.
+    pub fn version(&self, version: char) -> Option<&Between> {
+        self.versions.get(&version)
+    }
+
+    /// Returns the registered version symbols, in ascending order.
+    pub fn versions(&self) -> impl Iterator<Item = char> + '_ {
+        self.versions.keys().copied()
+    }
+
+    /// Splits a key into its version symbol and the rest, validated with the alphabet of that version.
+    ///
+    /// # Returns
+    ///
+    /// The version symbol and the rest of the key, or a [`VersionError`] if the key is empty, its version is
+    /// unknown, or the rest is not valid for its version.
+    pub fn parse<'a>(&self, key: &'a str) -> Result<(char, &'a str), VersionError> {
+        let version = key.chars().next().ok_or(VersionError::Empty)?;
+        let between = self
+            .versions
+            .get(&version)
+            .ok_or(VersionError::UnknownVersion { version })?;
+        let body = &key[version.len_utf8()..];
+        if !between.valid(body) {
+            return Err(VersionError::InvalidKey { version });
+        }
+        Ok((version, body))
+    }
+
+    /// Finds a key between two given keys.
+    ///
+    /// The new key uses the current version if it fits between the bounds, and otherwise the version of the
+    /// nearest bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `this` - The first key, or `""` for the start of the keyspace.
+    /// * `that` - The second key, or `""` for the end of the keyspace.
+    ///
+    /// # Returns
+    ///
+    /// An `Option<String>` that contains the between key if possible, or `None` if a key fails to parse or
+    /// `this` does not sort before `that`.
+    pub fn between(&self, this: &str, that: &str) -> Option<String> {
+        // Bounds of different versions are only compared through the version dispatch below, which would
+        // otherwise accept them in either order.
+        if !this.is_empty() && !that.is_empty() && this >= that {
+            return None;
+        }
+        let lower = if this.is_empty() {
+            None
+        } else {
+            Some(self.parse(this).ok()?)
+        };
+        let upper = if that.is_empty() {
+            None
+        } else {
+            Some(self.parse(that).ok()?)
+        };
+
+        let mut version = self.current;
+        if let Some((upper_version, _)) = upper {
+            version = version.min(upper_version);
+        }
+        if let Some((lower_version, _)) = lower {
+            version = version.max(lower_version);
+        }
+        let between = &self.versions[&version];
+        let low = lower
+            .filter(|(lower_version, _)| *lower_version == version)
+            .map_or("", |(_, body)| body);
+        let body = match upper.filter(|(upper_version, _)| *upper_version == version) {
+            Some((_, body)) => between.between(low, body)?,
+            None => between.after(low)?,
+        };
+        let mut key = String::with_capacity(version.len_utf8() + body.len());
+        key.push(version);
+        key.push_str(&body);
+        Some(key)
+    }
+
+    /// Finds a key that sorts after a given key, or `""` for the start of the keyspace.
+    pub fn after(&self, key: &str) -> Option<String> {
+        self.between(key, "")
+    }
+
+    /// Finds a key that sorts before a given key.
+    pub fn before(&self, key: &str) -> Option<String> {
+        self.between("", key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Between, VersionError, Versioned};
+
+    #[test]
+    fn test_versioned_keys() {
+        let v1 = Versioned::new('a', Between::base10());
+        let mut keys: Vec<String> = vec![v1.after("").unwrap()];
+        for _ in 0..10 {
+            keys.push(v1.after(keys.last().unwrap()).unwrap());
+        }
+
+        let v2 = v1
+            .add_version('b', Between::base36().with_max_len(6))
+            .unwrap();
+        assert_eq!(v2.current(), 'b');
+        assert_eq!(v2.versions().collect::<String>(), "ab");
+        for _ in 0..10 {
+            keys.push(v2.after(keys.last().unwrap()).unwrap());
+        }
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(keys[..11].iter().all(|key| key.starts_with('a')));
+        assert!(keys[11..].iter().all(|key| key.starts_with('b')));
+
+        // Between old keys, new keys keep the old version; before the first key too.
+        let key = v2.between(&keys[3], &keys[4]).unwrap();
+        assert!(key.starts_with('a') && keys[3] < key && key < keys[4]);
+        assert!(v2.before(&keys[0]).unwrap().starts_with('a'));
+        // Between versions, new keys use the current one.
+        let key = v2.between(&keys[10], &keys[11]).unwrap();
+        assert!(key.starts_with('b') && keys[10] < key && key < keys[11]);
+        assert!(v2.between(&keys[4], &keys[3]).is_none());
+        assert!(v2.between(&keys[11], &keys[10]).is_none());
+        assert!(v2.between("bi", "a5").is_none());
+        assert!(v2.between(&keys[3], &keys[3]).is_none());
+    }
+
+    #[test]
+    fn test_versioned_errors() {
+        let versioned = Versioned::new('1', Between::base10());
+        assert_eq!(versioned.parse("15").unwrap(), ('1', "5"));
+        assert_eq!(versioned.parse(""), Err(VersionError::Empty));
+        assert_eq!(
+            versioned.parse("25"),
+            Err(VersionError::UnknownVersion { version: '2' })
+        );
+        assert_eq!(
+            versioned.parse("1a"),
+            Err(VersionError::InvalidKey { version: '1' })
+        );
+        assert_eq!(
+            versioned.parse("1"),
+            Err(VersionError::InvalidKey { version: '1' })
+        );
+        assert!(matches!(
+            versioned.add_version('1', Between::base62()),
+            Err(VersionError::DuplicateVersion { version: '1' })
+        ));
+    }
+}